@@ -48,6 +48,9 @@ pub trait Api {
     include_mempool: Option<bool>,
   ) -> Result<Option<GetTxOutResult>, jsonrpc_core::Error>;
 
+  #[rpc(name = "getmempoolentry")]
+  fn get_mempool_entry(&self, txid: Txid) -> Result<GetMempoolEntryResult, jsonrpc_core::Error>;
+
   #[rpc(name = "getwalletinfo")]
   fn get_wallet_info(&self) -> Result<GetWalletInfoResult, jsonrpc_core::Error>;
 
@@ -139,6 +142,12 @@ pub trait Api {
     address_type: Option<bitcoincore_rpc::json::AddressType>,
   ) -> Result<Address, jsonrpc_core::Error>;
 
+  #[rpc(name = "getaddressinfo")]
+  fn get_address_info(
+    &self,
+    address: Address<NetworkUnchecked>,
+  ) -> Result<Value, jsonrpc_core::Error>;
+
   #[rpc(name = "getdescriptorinfo")]
   fn get_descriptor_info(
     &self,
@@ -194,7 +203,7 @@ pub trait Api {
     &self,
     psbt: String,
     sign: Option<bool>,
-    sighash_type: Option<()>,
+    sighash_type: Option<String>,
     bip32derivs: Option<bool>,
   ) -> Result<WalletProcessPsbtResult, jsonrpc_core::Error>;
 
@@ -203,5 +212,5 @@ pub trait Api {
     &self,
     psbt: String,
     extract: Option<bool>,
-  ) -> Result<FinalizePsbtResult, jsonrpc_core::Error>;
+  ) -> Result<Value, jsonrpc_core::Error>;
 }
@@ -1,7 +1,11 @@
 use {
   super::*,
   base64::Engine,
-  bitcoin::{consensus::Decodable, psbt::Psbt, Witness},
+  bitcoin::{
+    consensus::Decodable,
+    psbt::{raw::ProprietaryKey, Psbt},
+    Witness,
+  },
   bitcoincore_rpc::json::StringOrStringArray,
 };
 
@@ -241,15 +245,114 @@ impl Api for Server {
     )
   }
 
+  fn get_mempool_entry(&self, txid: Txid) -> Result<GetMempoolEntryResult, jsonrpc_core::Error> {
+    let state = self.state();
+
+    let Some(tx) = state.mempool.iter().find(|tx| tx.compute_txid() == txid) else {
+      return Err(jsonrpc_core::Error::new(
+        jsonrpc_core::types::error::ErrorCode::ServerError(-5),
+      ));
+    };
+
+    let mut descendants = vec![tx];
+
+    let mut i = 0;
+
+    while i < descendants.len() {
+      let parent = descendants[i].compute_txid();
+
+      for tx in &state.mempool {
+        if tx
+          .input
+          .iter()
+          .any(|input| input.previous_output.txid == parent)
+          && !descendants.contains(&tx)
+        {
+          descendants.push(tx);
+        }
+      }
+
+      i += 1;
+    }
+
+    let fee = state.fee(tx).unwrap_or_default();
+
+    Ok(GetMempoolEntryResult {
+      vsize: tx.vsize().try_into().unwrap(),
+      weight: Some(tx.weight().to_wu()),
+      time: 0,
+      height: (state.hashes.len() - 1).try_into().unwrap(),
+      descendant_count: descendants.len().try_into().unwrap(),
+      descendant_size: descendants
+        .iter()
+        .map(|tx| u64::try_from(tx.vsize()).unwrap())
+        .sum(),
+      ancestor_count: 1,
+      ancestor_size: tx.vsize().try_into().unwrap(),
+      wtxid: Txid::from_raw_hash(tx.compute_wtxid().to_raw_hash()),
+      fees: GetMempoolEntryResultFees {
+        base: fee,
+        modified: fee,
+        ancestor: fee,
+        descendant: descendants
+          .iter()
+          .map(|tx| state.fee(tx).unwrap_or_default())
+          .sum(),
+      },
+      depends: Vec::new(),
+      spent_by: descendants[1..]
+        .iter()
+        .filter(|descendant| {
+          descendant
+            .input
+            .iter()
+            .any(|input| input.previous_output.txid == txid)
+        })
+        .map(|descendant| descendant.compute_txid())
+        .collect(),
+      bip125_replaceable: tx.is_explicitly_rbf(),
+      unbroadcast: None,
+    })
+  }
+
   fn get_tx_out(
     &self,
     txid: Txid,
     vout: u32,
-    _include_mempool: Option<bool>,
+    include_mempool: Option<bool>,
   ) -> Result<Option<GetTxOutResult>, jsonrpc_core::Error> {
     let state = self.state();
 
-    let Some(value) = state.utxos.get(&OutPoint { txid, vout }) else {
+    let include_mempool = include_mempool.unwrap_or(true);
+
+    if include_mempool
+      && state.mempool.iter().any(|tx| {
+        tx.input
+          .iter()
+          .any(|input| input.previous_output == OutPoint { txid, vout })
+      })
+    {
+      return Ok(None);
+    }
+
+    let mempool_value = include_mempool
+      .then(|| {
+        state
+          .mempool
+          .iter()
+          .find(|tx| tx.compute_txid() == txid)?
+          .output
+          .get(usize::try_from(vout).unwrap())
+          .map(|output| output.value)
+      })
+      .flatten();
+
+    let Some(value) = state
+      .utxos
+      .get(&OutPoint { txid, vout })
+      .copied()
+      .or(mempool_value)
+    else {
       return Ok(None);
     };
 
@@ -266,7 +369,7 @@ impl Api for Server {
     Ok(Some(GetTxOutResult {
       bestblock: BlockHash::all_zeros(),
       coinbase: false,
-      confirmations: confirmations.unwrap().try_into().unwrap(),
+      confirmations: confirmations.unwrap_or_default().try_into().unwrap(),
       script_pub_key: GetRawTransactionResultVoutScriptPubKey {
         asm: String::new(),
         hex: Vec::new(),
@@ -275,12 +378,16 @@ impl Api for Server {
         addresses: Vec::new(),
         address: None,
       },
-      value: *value,
+      value,
     }))
   }
 
   fn get_wallet_info(&self) -> Result<GetWalletInfoResult, jsonrpc_core::Error> {
-    if let Some(wallet_name) = self.state().loaded_wallets.first().cloned() {
+    let wallet_name = self.state().loaded_wallets.first().cloned();
+
+    if let Some(wallet_name) = wallet_name {
+      let private_keys_enabled = !self.state().watch_only_wallets.contains(&wallet_name);
+
       Ok(GetWalletInfoResult {
         avoid_reuse: None,
         balance: Amount::from_sat(0),
@@ -290,7 +397,7 @@ impl Api for Server {
         keypool_size: 0,
         keypool_size_hd_internal: 0,
         pay_tx_fee: Amount::from_sat(0),
-        private_keys_enabled: true,
+        private_keys_enabled,
         scanning: None,
         tx_count: 0,
         unconfirmed_balance: Amount::from_sat(0),
@@ -340,12 +447,16 @@ impl Api for Server {
   fn create_wallet(
     &self,
     name: String,
-    _disable_private_keys: Option<bool>,
+    disable_private_keys: Option<bool>,
     _blank: Option<bool>,
     _passphrase: Option<String>,
     _avoid_reuse: Option<bool>,
   ) -> Result<LoadWalletResult, jsonrpc_core::Error> {
-    self.state().wallets.insert(name.clone());
+    let mut state = self.state();
+    state.wallets.insert(name.clone());
+    if disable_private_keys.unwrap_or_default() {
+      state.watch_only_wallets.insert(name.clone());
+    }
     Ok(LoadWalletResult {
       name,
       warning: None,
@@ -491,7 +602,7 @@ impl Api for Server {
     Ok(
       serde_json::to_value(SignRawTransactionResult {
         hex: serialize(&transaction),
-        complete: true,
+        complete: self.state().signature_threshold() <= 1,
         errors: None,
       })
       .unwrap(),
@@ -503,6 +614,16 @@ impl Api for Server {
 
     let mut state = self.state.lock().unwrap();
 
+    if let Some(limit) = state.broadcast_limit.as_mut() {
+      if *limit == 0 {
+        return Err(jsonrpc_core::Error::invalid_params(
+          "broadcast limit reached",
+        ));
+      }
+
+      *limit -= 1;
+    }
+
     for tx_in in &tx.input {
       if let Some(lock_time) = tx_in.sequence.to_relative_lock_time() {
         match lock_time {
@@ -524,6 +645,15 @@ impl Api for Server {
       }
     }
 
+    // replace transactions spending the same inputs
+    state.mempool.retain(|mempool_tx| {
+      !mempool_tx.input.iter().any(|mempool_tx_in| {
+        tx.input
+          .iter()
+          .any(|tx_in| tx_in.previous_output == mempool_tx_in.previous_output)
+      })
+    });
+
     state.mempool.push(tx.clone());
 
     Ok(tx.compute_txid().to_string())
@@ -607,7 +737,11 @@ impl Api for Server {
   ) -> Result<Value, jsonrpc_core::Error> {
     let state = self.state();
 
-    let Some(tx) = state.transactions.get(&txid) else {
+    let Some(tx) = state
+      .transactions
+      .get(&txid)
+      .or_else(|| state.mempool.iter().find(|tx| tx.compute_txid() == txid))
+    else {
       return Err(jsonrpc_core::Error::new(
         jsonrpc_core::types::error::ErrorCode::ServerError(-8),
       ));
@@ -628,7 +762,7 @@ impl Api for Server {
       serde_json::to_value(GetTransactionResult {
         info: WalletTxInfo {
           txid,
-          confirmations: confirmations.unwrap().try_into().unwrap(),
+          confirmations: confirmations.unwrap_or_default().try_into().unwrap(),
           time: 0,
           timereceived: 0,
           blockhash: None,
@@ -639,7 +773,7 @@ impl Api for Server {
           bip125_replaceable: Bip125Replaceable::Unknown,
         },
         amount: SignedAmount::from_sat(0),
-        fee: None,
+        fee: state.fee(tx).map(|fee| -fee.to_signed().unwrap()),
         details: Vec::new(),
         hex: serialize(tx),
       })
@@ -665,8 +799,13 @@ impl Api for Server {
 
     let blockhash = tx_height.map(|tx_height| state.hashes[usize::try_from(*tx_height).unwrap()]);
 
+    let transaction = state
+      .transactions
+      .get(&txid)
+      .or_else(|| state.mempool.iter().find(|tx| tx.compute_txid() == txid));
+
     if verbose.unwrap_or(false) {
-      match state.transactions.get(&txid) {
+      match transaction {
         Some(transaction) => Ok(
           serde_json::to_value(GetRawTransactionResult {
             in_active_chain: Some(true),
@@ -705,7 +844,7 @@ impl Api for Server {
         None => Err(Self::not_found()),
       }
     } else {
-      match state.transactions.get(&txid) {
+      match transaction {
         Some(tx) => Ok(Value::String(hex::encode(serialize(tx)))),
         None => Err(Self::not_found()),
       }
@@ -730,8 +869,14 @@ impl Api for Server {
 
     let mut unspent = Vec::new();
 
+    let spent = state
+      .mempool
+      .iter()
+      .flat_map(|tx| tx.input.iter().map(|tx_in| tx_in.previous_output))
+      .collect::<BTreeSet<OutPoint>>();
+
     for (outpoint, &amount) in &state.utxos {
-      if state.locked.contains(outpoint) {
+      if state.locked.contains(outpoint) || spent.contains(outpoint) {
         continue;
       }
 
@@ -786,6 +931,22 @@ impl Api for Server {
     Ok(self.state().new_address(true))
   }
 
+  fn get_address_info(
+    &self,
+    address: Address<NetworkUnchecked>,
+  ) -> Result<Value, jsonrpc_core::Error> {
+    let state = self.state();
+
+    let address = address.require_network(state.network).unwrap();
+
+    Ok(serde_json::json!({
+      "address": address.to_string(),
+      "scriptPubKey": address.script_pubkey().to_hex_string(),
+      "ismine": state.is_wallet_address(&address),
+      "labels": [],
+    }))
+  }
+
   fn get_descriptor_info(
     &self,
     desc: String,
@@ -872,20 +1033,28 @@ impl Api for Server {
     unlock: bool,
     outputs: Vec<JsonOutPoint>,
   ) -> Result<bool, jsonrpc_core::Error> {
-    assert!(!unlock);
-
     let mut state = self.state();
 
     if state.fail_lock_unspent {
       return Ok(false);
     }
 
+    assert!(
+      !(unlock && outputs.is_empty()),
+      "unlocking all outputs not supported"
+    );
+
     for output in outputs {
       let output = OutPoint {
         vout: output.vout,
         txid: output.txid,
       };
-      assert!(state.locked.insert(output));
+
+      if unlock {
+        assert!(state.locked.remove(&output));
+      } else {
+        assert!(state.locked.insert(output));
+      }
     }
 
     Ok(true)
@@ -950,12 +1119,19 @@ impl Api for Server {
     &self,
     psbt: String,
     sign: Option<bool>,
-    sighash_type: Option<()>,
+    sighash_type: Option<String>,
     bip32derivs: Option<bool>,
   ) -> Result<WalletProcessPsbtResult, jsonrpc_core::Error> {
-    assert!(sighash_type.is_none());
     assert!(bip32derivs.is_none());
 
+    // schnorr signatures using a sighash type other than the default have the
+    // sighash type appended
+    let signature = match sighash_type.as_deref() {
+      None | Some("DEFAULT") => vec![0; 64],
+      Some("SINGLE|ANYONECANPAY") => [[0; 64].as_slice(), &[0x83]].concat(),
+      Some(sighash_type) => panic!("unsupported sighash type: {sighash_type}"),
+    };
+
     let mut psbt = Psbt::deserialize(
       &base64::engine::general_purpose::STANDARD
         .decode(psbt)
@@ -964,28 +1140,56 @@ impl Api for Server {
     .unwrap();
 
     for (i, txin) in psbt.unsigned_tx.input.iter().enumerate() {
-      psbt.inputs[i].witness_utxo = Some(
-        self
-          .state()
-          .transactions
-          .get(&txin.previous_output.txid)
-          .unwrap()
-          .output[txin.previous_output.vout as usize]
-          .clone(),
-      );
+      if let Some(transaction) = self.state().transactions.get(&txin.previous_output.txid) {
+        psbt.inputs[i].witness_utxo =
+          Some(transaction.output[txin.previous_output.vout as usize].clone());
+      }
     }
 
+    let (watch_only, threshold) = {
+      let state = self.state();
+      (
+        state
+          .loaded_wallets
+          .iter()
+          .any(|wallet| state.watch_only_wallets.contains(wallet)),
+        state.signature_threshold(),
+      )
+    };
+
     if let Some(sign) = sign {
-      if sign {
+      if sign && !watch_only {
         for input in psbt.inputs.iter_mut() {
-          input.final_script_witness = Some(Witness::from_slice(&[&[0; 64]]));
+          if input.final_script_witness.is_some() {
+            continue;
+          }
+
+          // each call adds one partial signature, until the threshold is met
+          let signatures = input.proprietary.len();
+
+          input.proprietary.insert(
+            ProprietaryKey {
+              prefix: b"mockcore".to_vec(),
+              subtype: 0,
+              key: signatures.to_le_bytes().to_vec(),
+            },
+            vec![0; 64],
+          );
+
+          if signatures + 1 >= threshold {
+            input.final_script_witness = Some(Witness::from_slice(&[&signature]));
+          }
         }
       }
     }
 
     Ok(WalletProcessPsbtResult {
+      complete: sign.unwrap_or_default()
+        && psbt
+          .inputs
+          .iter()
+          .all(|input| input.final_script_witness.is_some()),
       psbt: base64::engine::general_purpose::STANDARD.encode(psbt.serialize()),
-      complete: false,
     })
   }
 
@@ -993,25 +1197,43 @@ impl Api for Server {
     &self,
     psbt: String,
     _extract: Option<bool>,
-  ) -> Result<FinalizePsbtResult, jsonrpc_core::Error> {
-    let mut transaction = Psbt::deserialize(
+  ) -> Result<Value, jsonrpc_core::Error> {
+    let encoded = psbt;
+
+    let psbt = Psbt::deserialize(
       &base64::engine::general_purpose::STANDARD
-        .decode(psbt)
+        .decode(&encoded)
         .unwrap(),
     )
-    .unwrap()
-    .unsigned_tx;
+    .unwrap();
 
-    for input in &mut transaction.input {
-      if input.witness.is_empty() {
+    let mut transaction = psbt.unsigned_tx;
+
+    let mut complete = true;
+
+    for (input, psbt_input) in transaction.input.iter_mut().zip(psbt.inputs) {
+      if let Some(witness) = psbt_input.final_script_witness {
+        input.witness = witness;
+      } else if !psbt_input.proprietary.is_empty() {
+        complete = false;
+      } else if input.witness.is_empty() {
         input.witness = Witness::from_slice(&[&[0; 64]]);
       }
     }
 
-    Ok(FinalizePsbtResult {
-      psbt: None,
-      hex: Some(serialize(&transaction)),
-      complete: true,
-    })
+    // `FinalizePsbtResult` serializes a missing `hex` as `null`, which it
+    // then fails to deserialize, so incomplete results are built by hand
+    if !complete {
+      return Ok(serde_json::json!({ "psbt": encoded, "complete": false }));
+    }
+
+    Ok(
+      serde_json::to_value(FinalizePsbtResult {
+        psbt: None,
+        hex: Some(serialize(&transaction)),
+        complete,
+      })
+      .unwrap(),
+    )
   }
 }
@@ -10,6 +10,8 @@ use {
 #[derive(Debug)]
 pub struct State {
   pub blocks: BTreeMap<BlockHash, Block>,
+  /// Number of transactions `sendrawtransaction` accepts before failing
+  pub broadcast_limit: Option<usize>,
   pub descriptors: Vec<String>,
   pub fail_lock_unspent: bool,
   pub hashes: Vec<BlockHash>,
@@ -22,6 +24,7 @@ pub struct State {
   pub txid_to_block_height: BTreeMap<Txid, u32>,
   pub utxos: BTreeMap<OutPoint, Amount>,
   pub version: usize,
+  pub watch_only_wallets: BTreeSet<String>,
   pub receive_addresses: Vec<Address>,
   pub change_addresses: Vec<Address>,
   pub wallets: BTreeSet<String>,
@@ -39,6 +42,7 @@ impl State {
 
     Self {
       blocks,
+      broadcast_limit: None,
       change_addresses: Vec::new(),
       descriptors: Vec::new(),
       fail_lock_unspent,
@@ -54,6 +58,7 @@ impl State {
       utxos: BTreeMap::new(),
       version,
       wallets: BTreeSet::new(),
+      watch_only_wallets: BTreeSet::new(),
     }
   }
 
@@ -71,6 +76,22 @@ impl State {
     address
   }
 
+  /// Number of signatures required to spend wallet outputs, taken from the
+  /// threshold of any imported `multi` or `sortedmulti` descriptor.
+  pub(crate) fn signature_threshold(&self) -> usize {
+    self
+      .descriptors
+      .iter()
+      .filter_map(|descriptor| {
+        let (_, rest) = descriptor
+          .split_once("multi(")
+          .or_else(|| descriptor.split_once("multi_a("))?;
+        rest.split(',').next()?.parse().ok()
+      })
+      .max()
+      .unwrap_or(1)
+  }
+
   pub fn is_wallet_address(&self, address: &Address) -> bool {
     self.receive_addresses.contains(address) || self.change_addresses.contains(address)
   }
@@ -281,6 +302,38 @@ impl State {
     0
   }
 
+  /// Fee paid by `tx`, which, like Bitcoin Core, is only known if the wallet
+  /// funded it.
+  pub(crate) fn fee(&self, tx: &Transaction) -> Option<Amount> {
+    if tx.is_coinbase() {
+      return None;
+    }
+
+    let mut funded = false;
+    let mut input_value = Amount::ZERO;
+
+    for input in &tx.input {
+      let prevout = self
+        .transactions
+        .get(&input.previous_output.txid)
+        .or_else(|| {
+          self
+            .mempool
+            .iter()
+            .find(|tx| tx.compute_txid() == input.previous_output.txid)
+        })?
+        .output
+        .get(usize::try_from(input.previous_output.vout).unwrap())?;
+
+      funded |= Address::from_script(&prevout.script_pubkey, self.network)
+        .is_ok_and(|address| self.is_wallet_address(&address));
+
+      input_value += prevout.value;
+    }
+
+    funded.then(|| input_value - tx.output.iter().map(|output| output.value).sum())
+  }
+
   pub(crate) fn get_locked(&self) -> BTreeSet<OutPoint> {
     self.locked.clone()
   }
@@ -6,7 +6,8 @@ use {
 pub use crate::{
   subcommand::decode::RawOutput as Decode,
   templates::{
-    BlocksHtml as Blocks, RuneHtml as Rune, RunesHtml as Runes, StatusHtml as Status,
+    rune_activity::MintProgress, BlocksHtml as Blocks, RuneActivityHtml as RuneActivity,
+    RuneHtml as Rune, RunesBlockHtml as RunesBlock, RunesHtml as Runes, StatusHtml as Status,
     TransactionHtml as Transaction,
   },
 };
@@ -149,7 +150,7 @@ pub struct Inscriptions {
   pub page_index: u32,
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 pub struct Output {
   pub address: Option<Address<NetworkUnchecked>>,
   pub indexed: bool,
@@ -2,7 +2,8 @@ use {
   self::{
     entry::{
       Entry, HeaderValue, InscriptionEntry, InscriptionEntryValue, InscriptionIdValue,
-      OutPointValue, RuneEntryValue, RuneIdValue, SatPointValue, SatRange, TxidValue,
+      OutPointValue, RuneActivityValue, RuneEntryValue, RuneIdValue, SatPointValue, SatRange,
+      TxidValue,
     },
     event::Event,
     lot::Lot,
@@ -36,7 +37,7 @@ use {
   },
 };
 
-pub use self::entry::RuneEntry;
+pub use self::entry::{RuneActivity, RuneActivityKind, RuneEntry};
 
 pub(crate) mod entry;
 pub mod event;
@@ -50,8 +51,15 @@ mod utxo_entry;
 #[cfg(test)]
 pub(crate) mod testing;
 
-const SCHEMA_VERSION: u64 = 29;
+const SCHEMA_VERSION: u64 = 30;
 
+/// Transfers of at least 1/LARGE_TRANSFER_DIVISOR, that is 1%, of a rune's
+/// supply are recorded in the rune's activity timeline. Changing this changes
+/// what is indexed, so `SCHEMA_VERSION` must be bumped along with it.
+const LARGE_TRANSFER_DIVISOR: u128 = 100;
+
+define_multimap_table! { HEIGHT_TO_RUNE_ACTIVITY, u32, (RuneActivityValue, RuneIdValue) }
+define_multimap_table! { RUNE_ID_TO_ACTIVITY, RuneIdValue, RuneActivityValue }
 define_multimap_table! { SAT_TO_SEQUENCE_NUMBER, u64, u32 }
 define_multimap_table! { SEQUENCE_NUMBER_TO_CHILDREN, u32, u32 }
 define_multimap_table! { SCRIPT_PUBKEY_TO_OUTPOINT, &[u8], OutPointValue }
@@ -304,6 +312,8 @@ impl Index {
 
         tx.set_durability(durability);
 
+        tx.open_multimap_table(HEIGHT_TO_RUNE_ACTIVITY)?;
+        tx.open_multimap_table(RUNE_ID_TO_ACTIVITY)?;
         tx.open_multimap_table(SAT_TO_SEQUENCE_NUMBER)?;
         tx.open_multimap_table(SCRIPT_PUBKEY_TO_OUTPOINT)?;
         tx.open_multimap_table(SEQUENCE_NUMBER_TO_CHILDREN)?;
@@ -401,6 +411,21 @@ impl Index {
 
           tx.open_table(TRANSACTION_ID_TO_RUNE)?
             .insert(&etching.store(), rune.store())?;
+
+          let activity = RuneActivity {
+            amount: 0,
+            height: 1,
+            kind: RuneActivityKind::Etching,
+            output: None,
+            transaction: 0,
+            txid: etching,
+          };
+
+          tx.open_multimap_table(RUNE_ID_TO_ACTIVITY)?
+            .insert(id.store(), activity.store())?;
+
+          tx.open_multimap_table(HEIGHT_TO_RUNE_ACTIVITY)?
+            .insert(activity.height, (activity.store(), id.store()))?;
         }
 
         tx.commit()?;
@@ -992,6 +1017,93 @@ impl Index {
     Ok((entries, more))
   }
 
+  pub fn get_rune_activity_paginated(
+    &self,
+    id: RuneId,
+    page_size: usize,
+    page_index: usize,
+  ) -> Result<(Vec<RuneActivity>, bool)> {
+    let mut activity = self
+      .database
+      .begin_read()?
+      .open_multimap_table(RUNE_ID_TO_ACTIVITY)?
+      .get(id.store())?
+      .skip(page_index.saturating_mul(page_size))
+      .take(page_size.saturating_add(1))
+      .map(|result| result.map(|entry| RuneActivity::load(entry.value())))
+      .collect::<Result<Vec<RuneActivity>, StorageError>>()?;
+
+    let more = activity.len() > page_size;
+
+    if more {
+      activity.pop();
+    }
+
+    Ok((activity, more))
+  }
+
+  pub fn get_rune_mints_per_block(&self, id: RuneId) -> Result<BTreeMap<u32, u64>> {
+    let mut mints = BTreeMap::new();
+
+    for result in self
+      .database
+      .begin_read()?
+      .open_multimap_table(RUNE_ID_TO_ACTIVITY)?
+      .get(id.store())?
+    {
+      let activity = RuneActivity::load(result?.value());
+
+      if activity.kind == RuneActivityKind::Mint {
+        *mints.entry(activity.height).or_default() += 1;
+      }
+    }
+
+    Ok(mints)
+  }
+
+  /// Etchings, mints, and burns, including those by cenotaphs and by
+  /// allocations to OP_RETURN outputs, in the block at `height`, in the order
+  /// in which they occurred.
+  pub fn get_rune_activity_in_block(
+    &self,
+    height: u32,
+  ) -> Result<Vec<(RuneId, RuneEntry, RuneActivity)>> {
+    let rtx = self.database.begin_read()?;
+
+    let height_to_rune_activity = rtx.open_multimap_table(HEIGHT_TO_RUNE_ACTIVITY)?;
+    let rune_id_to_rune_entry = rtx.open_table(RUNE_ID_TO_RUNE_ENTRY)?;
+
+    let mut activity = Vec::new();
+
+    for result in height_to_rune_activity.get(height)? {
+      let (value, id) = result?.value();
+
+      let id = RuneId::load(id);
+
+      let entry = RuneEntry::load(rune_id_to_rune_entry.get(id.store())?.unwrap().value());
+
+      activity.push((id, entry, RuneActivity::load(value)));
+    }
+
+    Ok(activity)
+  }
+
+  pub fn get_feed_runes(&self, n: usize) -> Result<Vec<(RuneId, RuneEntry)>> {
+    Ok(
+      self
+        .database
+        .begin_read()?
+        .open_table(RUNE_ID_TO_RUNE_ENTRY)?
+        .iter()?
+        .rev()
+        .take(n)
+        .flat_map(|result| {
+          result.map(|(id, entry)| (RuneId::load(id.value()), RuneEntry::load(entry.value())))
+        })
+        .collect(),
+    )
+  }
+
   pub fn encode_rune_balance(id: RuneId, balance: u128, buffer: &mut Vec<u8>) {
     varint::encode_to_vec(id.block.into(), buffer);
     varint::encode_to_vec(id.tx.into(), buffer);
@@ -6668,6 +6780,170 @@ mod tests {
     );
   }
 
+  #[test]
+  fn rune_activity_is_recorded() {
+    const RUNE: u128 = 99246114928149462;
+
+    let context = Context::builder().arg("--index-runes").build();
+
+    let (txid0, id) = context.etch(
+      Runestone {
+        etching: Some(Etching {
+          rune: Some(Rune(RUNE)),
+          terms: Some(Terms {
+            amount: Some(1000),
+            cap: Some(100),
+            ..default()
+          }),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+    );
+
+    let txid1 = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 0, 0, Witness::new())],
+      op_return: Some(
+        Runestone {
+          mint: Some(id),
+          ..default()
+        }
+        .encipher(),
+      ),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    let txid2 = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(9, 1, 0, Witness::new())],
+      outputs: 2,
+      op_return: Some(
+        Runestone {
+          edicts: vec![Edict {
+            id,
+            amount: 5,
+            output: 1,
+          }],
+          ..default()
+        }
+        .encipher(),
+      ),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    let txid3 = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(10, 1, 0, Witness::new())],
+      op_return: Some(
+        Runestone {
+          edicts: vec![Edict {
+            id,
+            amount: 111,
+            output: 0,
+          }],
+          ..default()
+        }
+        .encipher(),
+      ),
+      op_return_index: Some(0),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    let activity = |height, transaction, kind, output, amount, txid| RuneActivity {
+      amount,
+      height,
+      kind,
+      output,
+      transaction,
+      txid,
+    };
+
+    pretty_assert_eq!(
+      context
+        .index
+        .get_rune_activity_paginated(id, 100, 0)
+        .unwrap(),
+      (
+        vec![
+          activity(8, 1, RuneActivityKind::Etching, None, 0, txid0),
+          activity(9, 1, RuneActivityKind::Mint, None, 1000, txid1),
+          activity(10, 1, RuneActivityKind::Transfer, Some(0), 995, txid2),
+          activity(11, 1, RuneActivityKind::Transfer, Some(1), 884, txid3),
+          activity(11, 1, RuneActivityKind::Burn, None, 111, txid3),
+        ],
+        false
+      )
+    );
+
+    assert_eq!(
+      context.index.get_rune_activity_paginated(id, 2, 1).unwrap(),
+      (
+        vec![
+          activity(10, 1, RuneActivityKind::Transfer, Some(0), 995, txid2),
+          activity(11, 1, RuneActivityKind::Transfer, Some(1), 884, txid3),
+        ],
+        true
+      )
+    );
+
+    assert_eq!(
+      context.index.get_rune_mints_per_block(id).unwrap(),
+      [(9, 1)].into()
+    );
+
+    assert_eq!(
+      context
+        .index
+        .get_rune_activity_in_block(11)
+        .unwrap()
+        .into_iter()
+        .map(|(id, _entry, activity)| (id, activity))
+        .collect::<Vec<(RuneId, RuneActivity)>>(),
+      [(
+        id,
+        activity(11, 1, RuneActivityKind::Burn, None, 111, txid3)
+      )],
+    );
+
+    // cenotaph which does not name the rune it burns
+    let txid4 = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(11, 1, 1, Witness::new())],
+      op_return: Some(
+        Runestone {
+          edicts: vec![Edict {
+            id: RuneId { block: 1, tx: 0 },
+            amount: 1,
+            output: 5,
+          }],
+          ..default()
+        }
+        .encipher(),
+      ),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    assert_eq!(
+      context
+        .index
+        .get_rune_activity_in_block(12)
+        .unwrap()
+        .into_iter()
+        .map(|(id, _entry, activity)| (id, activity))
+        .collect::<Vec<(RuneId, RuneActivity)>>(),
+      [(
+        id,
+        activity(12, 1, RuneActivityKind::Burn, None, 884, txid4)
+      )],
+    );
+  }
+
   #[test]
   fn assert_schema_statistic_key_is_zero() {
     // other schema statistic keys may chenge when the schema changes, but for
@@ -287,6 +287,95 @@ impl Entry for RuneId {
   }
 }
 
+#[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RuneActivityKind {
+  Etching,
+  Mint,
+  Transfer,
+  Burn,
+}
+
+impl Display for RuneActivityKind {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        Self::Etching => "etching",
+        Self::Mint => "mint",
+        Self::Transfer => "transfer",
+        Self::Burn => "burn",
+      }
+    )
+  }
+}
+
+impl From<RuneActivityKind> for u8 {
+  fn from(kind: RuneActivityKind) -> Self {
+    kind as u8
+  }
+}
+
+impl TryFrom<u8> for RuneActivityKind {
+  type Error = u8;
+
+  fn try_from(n: u8) -> Result<Self, u8> {
+    match n {
+      0 => Ok(Self::Etching),
+      1 => Ok(Self::Mint),
+      2 => Ok(Self::Transfer),
+      3 => Ok(Self::Burn),
+      n => Err(n),
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
+pub struct RuneActivity {
+  pub amount: u128,
+  pub height: u32,
+  pub kind: RuneActivityKind,
+  pub output: Option<u32>,
+  pub transaction: u32,
+  pub txid: Txid,
+}
+
+pub(super) type RuneActivityValue = (
+  u32,         // height
+  u32,         // transaction
+  u8,          // kind
+  Option<u32>, // output
+  u128,        // amount
+  TxidValue,   // txid
+);
+
+impl Entry for RuneActivity {
+  type Value = RuneActivityValue;
+
+  fn load((height, transaction, kind, output, amount, txid): RuneActivityValue) -> Self {
+    Self {
+      amount,
+      height,
+      kind: kind.try_into().unwrap(),
+      output,
+      transaction,
+      txid: Txid::load(txid),
+    }
+  }
+
+  fn store(self) -> Self::Value {
+    (
+      self.height,
+      self.transaction,
+      self.kind.into(),
+      self.output,
+      self.amount,
+      self.txid.store(),
+    )
+  }
+}
+
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct InscriptionEntry {
   pub charms: u16,
@@ -618,6 +707,24 @@ mod tests {
     assert_eq!(RuneId { block: 1, tx: 2 }, RuneId::load((1, 2)),);
   }
 
+  #[test]
+  fn rune_activity_entry() {
+    let activity = RuneActivity {
+      amount: 3,
+      height: 1,
+      kind: RuneActivityKind::Transfer,
+      output: Some(4),
+      transaction: 2,
+      txid: Txid::from_byte_array([5; 32]),
+    };
+
+    let value = (1, 2, 2, Some(4), 3, [5; 32]);
+
+    assert_eq!(activity.store(), value);
+
+    assert_eq!(RuneActivity::load(value), activity);
+  }
+
   #[test]
   fn header() {
     let expected = [
@@ -347,7 +347,9 @@ impl<'index> Updater<'index> {
     }
 
     if self.index.index_runes && self.height >= self.index.settings.first_rune_height() {
+      let mut height_to_rune_activity = wtx.open_multimap_table(HEIGHT_TO_RUNE_ACTIVITY)?;
       let mut outpoint_to_rune_balances = wtx.open_table(OUTPOINT_TO_RUNE_BALANCES)?;
+      let mut rune_id_to_activity = wtx.open_multimap_table(RUNE_ID_TO_ACTIVITY)?;
       let mut rune_id_to_rune_entry = wtx.open_table(RUNE_ID_TO_RUNE_ENTRY)?;
       let mut rune_to_rune_id = wtx.open_table(RUNE_TO_RUNE_ID)?;
       let mut sequence_number_to_rune_id = wtx.open_table(SEQUENCE_NUMBER_TO_RUNE_ID)?;
@@ -364,6 +366,8 @@ impl<'index> Updater<'index> {
         burned: HashMap::new(),
         client: &self.index.client,
         height: self.height,
+        height_to_activity: &mut height_to_rune_activity,
+        id_to_activity: &mut rune_id_to_activity,
         id_to_entry: &mut rune_id_to_rune_entry,
         inscription_id_to_sequence_number: &mut inscription_id_to_sequence_number,
         minimum: Rune::minimum_at_height(
@@ -6,6 +6,8 @@ pub(super) struct RuneUpdater<'a, 'tx, 'client> {
   pub(super) client: &'client Client,
   pub(super) event_sender: Option<&'a mpsc::Sender<Event>>,
   pub(super) height: u32,
+  pub(super) height_to_activity: &'a mut MultimapTable<'tx, u32, (RuneActivityValue, RuneIdValue)>,
+  pub(super) id_to_activity: &'a mut MultimapTable<'tx, RuneIdValue, RuneActivityValue>,
   pub(super) id_to_entry: &'a mut Table<'tx, RuneIdValue, RuneEntryValue>,
   pub(super) inscription_id_to_sequence_number: &'a Table<'tx, InscriptionIdValue, u32>,
   pub(super) minimum: Rune,
@@ -25,11 +27,23 @@ impl<'a, 'tx, 'client> RuneUpdater<'a, 'tx, 'client> {
 
     let mut allocated: Vec<HashMap<RuneId, Lot>> = vec![HashMap::new(); tx.output.len()];
 
+    // runes minted or etched by this transaction, whose allocations are not
+    // recorded as transfers
+    let mut issued = Vec::new();
+
+    // supplies of runes transferred by this transaction, so each rune's entry
+    // is loaded at most once
+    let mut supplies = HashMap::new();
+
     if let Some(artifact) = &artifact {
       if let Some(id) = artifact.mint() {
         if let Some(amount) = self.mint(id)? {
           *unallocated.entry(id).or_default() += amount;
 
+          issued.push(id);
+
+          self.record_activity(id, RuneActivityKind::Mint, tx_index, txid, None, amount.n())?;
+
           if let Some(sender) = self.event_sender {
             sender.blocking_send(Event::RuneMinted {
               block_height: self.height,
@@ -43,6 +57,10 @@ impl<'a, 'tx, 'client> RuneUpdater<'a, 'tx, 'client> {
 
       let etched = self.etched(tx_index, tx, artifact)?;
 
+      if let Some((id, ..)) = etched {
+        issued.push(id);
+      }
+
       if let Artifact::Runestone(runestone) = artifact {
         if let Some((id, ..)) = etched {
           *unallocated.entry(id).or_default() +=
@@ -198,6 +216,17 @@ impl<'a, 'tx, 'client> RuneUpdater<'a, 'tx, 'client> {
       for (id, balance) in balances {
         Index::encode_rune_balance(id, balance.n(), &mut buffer);
 
+        if !issued.contains(&id) && self.is_large_transfer(&mut supplies, id, balance)? {
+          self.record_activity(
+            id,
+            RuneActivityKind::Transfer,
+            tx_index,
+            txid,
+            Some(outpoint.vout),
+            balance.n(),
+          )?;
+        }
+
         if let Some(sender) = self.event_sender {
           sender.blocking_send(Event::RuneTransferred {
             outpoint,
@@ -218,6 +247,8 @@ impl<'a, 'tx, 'client> RuneUpdater<'a, 'tx, 'client> {
     for (id, amount) in burned {
       *self.burned.entry(id).or_default() += amount;
 
+      self.record_activity(id, RuneActivityKind::Burn, tx_index, txid, None, amount.n())?;
+
       if let Some(sender) = self.event_sender {
         sender.blocking_send(Event::RuneBurned {
           block_height: self.height,
@@ -308,6 +339,15 @@ impl<'a, 'tx, 'client> RuneUpdater<'a, 'tx, 'client> {
 
     self.id_to_entry.insert(id.store(), entry.store())?;
 
+    self.record_activity(
+      id,
+      RuneActivityKind::Etching,
+      id.tx,
+      txid,
+      None,
+      entry.premine,
+    )?;
+
     if let Some(sender) = self.event_sender {
       sender.blocking_send(Event::RuneEtched {
         block_height: self.height,
@@ -399,6 +439,54 @@ impl<'a, 'tx, 'client> RuneUpdater<'a, 'tx, 'client> {
     Ok(Some(Lot(amount)))
   }
 
+  fn is_large_transfer(
+    &self,
+    supplies: &mut HashMap<RuneId, u128>,
+    id: RuneId,
+    amount: Lot,
+  ) -> Result<bool> {
+    let supply = match supplies.get(&id) {
+      Some(supply) => *supply,
+      None => {
+        let supply = RuneEntry::load(self.id_to_entry.get(&id.store())?.unwrap().value()).supply();
+        supplies.insert(id, supply);
+        supply
+      }
+    };
+
+    Ok(amount.n().saturating_mul(LARGE_TRANSFER_DIVISOR) >= supply)
+  }
+
+  fn record_activity(
+    &mut self,
+    id: RuneId,
+    kind: RuneActivityKind,
+    transaction: u32,
+    txid: Txid,
+    output: Option<u32>,
+    amount: u128,
+  ) -> Result {
+    let activity = RuneActivity {
+      amount,
+      height: self.height,
+      kind,
+      output,
+      transaction,
+      txid,
+    };
+
+    self.id_to_activity.insert(id.store(), activity.store())?;
+
+    // large transfers are only shown in the rune's own timeline
+    if kind != RuneActivityKind::Transfer {
+      self
+        .height_to_activity
+        .insert(self.height, (activity.store(), id.store()))?;
+    }
+
+    Ok(())
+  }
+
   fn tx_commits_to_rune(&self, tx: &Transaction, rune: Rune) -> Result<bool> {
     let commitment = rune.commitment();
 
@@ -0,0 +1,124 @@
+use {
+  super::*,
+  brotli::enc::{
+    backward_references::BrotliEncoderMode, writer::CompressorWriter, BrotliEncoderParams,
+  },
+  flate2::{read::GzDecoder, write::GzEncoder},
+  io::Write,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum Compression {
+  Brotli,
+  Gzip,
+  Zstd,
+}
+
+impl Compression {
+  pub fn content_encoding(self) -> &'static str {
+    match self {
+      Self::Brotli => "br",
+      Self::Gzip => "gzip",
+      Self::Zstd => "zstd",
+    }
+  }
+
+  pub fn from_content_encoding(content_encoding: &str) -> Option<Self> {
+    match content_encoding {
+      "br" => Some(Self::Brotli),
+      "gzip" => Some(Self::Gzip),
+      "zstd" => Some(Self::Zstd),
+      _ => None,
+    }
+  }
+
+  pub(crate) fn compress(self, body: &[u8], mode: BrotliEncoderMode) -> Result<Vec<u8>> {
+    let mut compressed = Vec::new();
+
+    match self {
+      Self::Brotli => {
+        CompressorWriter::with_params(
+          &mut compressed,
+          body.len(),
+          &BrotliEncoderParams {
+            lgblock: 24,
+            lgwin: 24,
+            mode,
+            quality: 11,
+            size_hint: body.len(),
+            ..default()
+          },
+        )
+        .write_all(body)?;
+      }
+      Self::Gzip => {
+        let mut encoder = GzEncoder::new(&mut compressed, flate2::Compression::best());
+        encoder.write_all(body)?;
+        encoder.finish()?;
+      }
+      Self::Zstd => {
+        compressed = zstd::encode_all(body, zstd::zstd_safe::max_c_level())?;
+      }
+    }
+
+    ensure!(
+      self.decompress(&compressed)? == body,
+      "decompression roundtrip failed"
+    );
+
+    Ok(compressed)
+  }
+
+  pub fn decompress(self, body: &[u8]) -> io::Result<Vec<u8>> {
+    let mut decompressed = Vec::new();
+
+    match self {
+      Self::Brotli => {
+        brotli::Decompressor::new(body, 4096).read_to_end(&mut decompressed)?;
+      }
+      Self::Gzip => {
+        GzDecoder::new(body).read_to_end(&mut decompressed)?;
+      }
+      Self::Zstd => {
+        decompressed = zstd::decode_all(body)?;
+      }
+    }
+
+    Ok(decompressed)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, brotli::enc::backward_references::BrotliEncoderMode::BROTLI_MODE_TEXT};
+
+  #[test]
+  fn content_encoding() {
+    for compression in [Compression::Brotli, Compression::Gzip, Compression::Zstd] {
+      assert_eq!(
+        Compression::from_content_encoding(compression.content_encoding()),
+        Some(compression),
+      );
+    }
+
+    assert_eq!(Compression::from_content_encoding("deflate"), None);
+  }
+
+  #[test]
+  fn round_trip() {
+    let body = "foo".repeat(1000);
+
+    for compression in [Compression::Brotli, Compression::Gzip, Compression::Zstd] {
+      let compressed = compression
+        .compress(body.as_bytes(), BROTLI_MODE_TEXT)
+        .unwrap();
+
+      assert!(compressed.len() < body.len());
+
+      assert_eq!(
+        compression.decompress(&compressed).unwrap(),
+        body.as_bytes()
+      );
+    }
+  }
+}
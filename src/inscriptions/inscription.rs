@@ -1,12 +1,4 @@
-use {
-  super::*,
-  anyhow::ensure,
-  bitcoin::blockdata::opcodes,
-  brotli::enc::{writer::CompressorWriter, BrotliEncoderParams},
-  http::header::HeaderValue,
-  io::Write,
-  std::str,
-};
+use {super::*, bitcoin::blockdata::opcodes, http::header::HeaderValue, std::str};
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Eq, Default)]
 pub struct Inscription {
@@ -27,7 +19,7 @@ pub struct Inscription {
 impl Inscription {
   pub fn new(
     chain: Chain,
-    compress: bool,
+    compress: Option<Compression>,
     delegate: Option<InscriptionId>,
     metadata: Option<Vec<u8>>,
     metaprotocol: Option<String>,
@@ -43,36 +35,14 @@ impl Inscription {
 
       let content_type = Media::content_type_for_path(path)?.0;
 
-      let (body, content_encoding) = if compress {
-        let compression_mode = Media::content_type_for_path(path)?.1;
-        let mut compressed = Vec::new();
-
-        {
-          CompressorWriter::with_params(
-            &mut compressed,
-            body.len(),
-            &BrotliEncoderParams {
-              lgblock: 24,
-              lgwin: 24,
-              mode: compression_mode,
-              quality: 11,
-              size_hint: body.len(),
-              ..default()
-            },
-          )
-          .write_all(&body)?;
-
-          let mut decompressor = brotli::Decompressor::new(compressed.as_slice(), compressed.len());
-
-          let mut decompressed = Vec::new();
-
-          decompressor.read_to_end(&mut decompressed)?;
-
-          ensure!(decompressed == body, "decompression roundtrip failed");
-        }
+      let (body, content_encoding) = if let Some(compression) = compress {
+        let compressed = compression.compress(&body, Media::content_type_for_path(path)?.1)?;
 
         if compressed.len() < body.len() {
-          (compressed, Some("br".as_bytes().to_vec()))
+          (
+            compressed,
+            Some(compression.content_encoding().as_bytes().to_vec()),
+          )
         } else {
           (body, None)
         }
@@ -771,7 +741,7 @@ mod tests {
 
     let inscription = Inscription::new(
       Chain::Mainnet,
-      false,
+      None,
       None,
       None,
       None,
@@ -786,7 +756,7 @@ mod tests {
 
     let inscription = Inscription::new(
       Chain::Mainnet,
-      false,
+      None,
       None,
       None,
       None,
@@ -801,7 +771,7 @@ mod tests {
 
     let inscription = Inscription::new(
       Chain::Mainnet,
-      false,
+      None,
       None,
       None,
       None,
@@ -816,7 +786,7 @@ mod tests {
 
     let inscription = Inscription::new(
       Chain::Mainnet,
-      false,
+      None,
       None,
       None,
       None,
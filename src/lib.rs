@@ -44,7 +44,7 @@ use {
     script,
     transaction::Version,
     Amount, Block, Network, OutPoint, Script, ScriptBuf, Sequence, Transaction, TxIn, TxOut, Txid,
-    Witness,
+    Weight, Witness,
   },
   bitcoincore_rpc::{Client, RpcApi},
   chrono::{DateTime, TimeZone, Utc},
@@ -92,7 +92,7 @@ pub use self::{
   chain::Chain,
   fee_rate::FeeRate,
   index::{Index, RuneEntry},
-  inscriptions::{Envelope, Inscription, InscriptionId},
+  inscriptions::{Compression, Envelope, Inscription, InscriptionId},
   object::Object,
   options::Options,
   wallet::transaction_builder::{Target, TransactionBuilder},
@@ -136,7 +136,7 @@ const TARGET_POSTAGE: Amount = Amount::from_sat(10_000);
 
 static SHUTTING_DOWN: AtomicBool = AtomicBool::new(false);
 static LISTENERS: Mutex<Vec<axum_server::Handle>> = Mutex::new(Vec::new());
-static INDEXER: Mutex<Option<thread::JoinHandle<()>>> = Mutex::new(None);
+static INDEXERS: Mutex<Vec<thread::JoinHandle<()>>> = Mutex::new(Vec::new());
 
 #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
 fn fund_raw_transaction(
@@ -238,9 +238,14 @@ pub fn shut_down() {
 }
 
 fn gracefully_shut_down_indexer() {
-  if let Some(indexer) = INDEXER.lock().unwrap().take() {
+  let mut indexers = INDEXERS.lock().unwrap();
+
+  if !indexers.is_empty() {
     shut_down();
-    log::info!("Waiting for index thread to finish...");
+    log::info!("Waiting for index threads to finish...");
+  }
+
+  for indexer in indexers.drain(..) {
     if indexer.join().is_err() {
       log::warn!("Index thread panicked; join failed");
     }
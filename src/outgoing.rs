@@ -7,6 +7,7 @@ pub enum Outgoing {
   Rune { decimal: Decimal, rune: SpacedRune },
   Sat(Sat),
   SatPoint(SatPoint),
+  SatRange { start: Sat, end: Sat },
 }
 
 impl Display for Outgoing {
@@ -17,6 +18,7 @@ impl Display for Outgoing {
       Self::Rune { decimal, rune } => write!(f, "{decimal}:{rune}"),
       Self::Sat(sat) => write!(f, "{}", sat.name()),
       Self::SatPoint(satpoint) => satpoint.fmt(f),
+      Self::SatRange { start, end } => write!(f, "{start}-{end}"),
     }
   }
 }
@@ -61,6 +63,7 @@ impl FromStr for Outgoing {
         "
       )
       .unwrap();
+      static ref SAT_RANGE: Regex = Regex::new(r"^(\d+)-(\d+)$").unwrap();
     }
 
     if re::SAT_NAME.is_match(input) {
@@ -83,6 +86,15 @@ impl FromStr for Outgoing {
       Ok(Outgoing::Amount(
         input.parse().snafu_context(error::AmountParse { input })?,
       ))
+    } else if let Some(captures) = SAT_RANGE.captures(input) {
+      Ok(Self::SatRange {
+        start: captures[1]
+          .parse()
+          .snafu_context(error::SatParse { input })?,
+        end: captures[2]
+          .parse()
+          .snafu_context(error::SatParse { input })?,
+      })
     } else if let Some(captures) = RUNE.captures(input) {
       let decimal = captures[1]
         .parse::<Decimal>()
@@ -183,6 +195,14 @@ mod tests {
         decimal: "1.1".parse().unwrap(),
       },
     );
+
+    case(
+      "100-200",
+      Outgoing::SatRange {
+        start: Sat(100),
+        end: Sat(200),
+      },
+    );
   }
 
   #[test]
@@ -235,6 +255,14 @@ mod tests {
         decimal: "1.1".parse().unwrap(),
       },
     );
+
+    case(
+      "0-2099999997689999",
+      Outgoing::SatRange {
+        start: Sat(0),
+        end: Sat(2099999997689999),
+      },
+    );
   }
 
   #[test]
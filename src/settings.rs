@@ -25,6 +25,7 @@ pub struct Settings {
   index_transactions: bool,
   integration_test: bool,
   no_index_inscriptions: bool,
+  protect_rarity: Option<Rarity>,
   server_password: Option<String>,
   server_url: Option<String>,
   server_username: Option<String>,
@@ -138,6 +139,7 @@ impl Settings {
       index_transactions: self.index_transactions || source.index_transactions,
       integration_test: self.integration_test || source.integration_test,
       no_index_inscriptions: self.no_index_inscriptions || source.no_index_inscriptions,
+      protect_rarity: self.protect_rarity.or(source.protect_rarity),
       server_password: self.server_password.or(source.server_password),
       server_url: self.server_url.or(source.server_url),
       server_username: self.server_username.or(source.server_username),
@@ -173,6 +175,7 @@ impl Settings {
       index_transactions: options.index_transactions,
       integration_test: options.integration_test,
       no_index_inscriptions: options.no_index_inscriptions,
+      protect_rarity: None,
       server_password: options.server_password,
       server_url: None,
       server_username: options.server_username,
@@ -214,6 +217,14 @@ impl Settings {
         })
     };
 
+    let get_rarity = |key| {
+      env
+        .get(key)
+        .map(|rarity| rarity.parse::<Rarity>())
+        .transpose()
+        .map_err(|err| anyhow!("failed to parse environment variable ORD_{key} as rarity: {err}"))
+    };
+
     let get_u16 = |key| {
       env
         .get(key)
@@ -261,6 +272,7 @@ impl Settings {
       index_transactions: get_bool("INDEX_TRANSACTIONS"),
       integration_test: get_bool("INTEGRATION_TEST"),
       no_index_inscriptions: get_bool("NO_INDEX_INSCRIPTIONS"),
+      protect_rarity: get_rarity("PROTECT_RARITY")?,
       server_password: get_string("SERVER_PASSWORD"),
       server_url: get_string("SERVER_URL"),
       server_username: get_string("SERVER_USERNAME"),
@@ -291,6 +303,7 @@ impl Settings {
       index_transactions: false,
       integration_test: false,
       no_index_inscriptions: false,
+      protect_rarity: None,
       server_password: None,
       server_url: Some(server_url.into()),
       server_username: None,
@@ -365,6 +378,7 @@ impl Settings {
       index_transactions: self.index_transactions,
       integration_test: self.integration_test,
       no_index_inscriptions: self.no_index_inscriptions,
+      protect_rarity: self.protect_rarity,
       server_password: self.server_password,
       server_url: self.server_url,
       server_username: self.server_username,
@@ -573,6 +587,10 @@ impl Settings {
     self.bitcoin_rpc_limit.unwrap()
   }
 
+  pub fn protect_rarity(&self) -> Option<Rarity> {
+    self.protect_rarity
+  }
+
   pub fn server_url(&self) -> Option<&str> {
     self.server_url.as_deref()
   }
@@ -1022,6 +1040,7 @@ mod tests {
       ("INDEX_TRANSACTIONS", "1"),
       ("INTEGRATION_TEST", "1"),
       ("NO_INDEX_INSCRIPTIONS", "1"),
+      ("PROTECT_RARITY", "rare"),
       ("SERVER_PASSWORD", "server password"),
       ("SERVER_URL", "server url"),
       ("SERVER_USERNAME", "server username"),
@@ -1066,6 +1085,7 @@ mod tests {
         index_transactions: true,
         integration_test: true,
         no_index_inscriptions: true,
+        protect_rarity: Some(Rarity::Rare),
         server_password: Some("server password".into()),
         server_url: Some("server url".into()),
         server_username: Some("server username".into()),
@@ -1127,6 +1147,7 @@ mod tests {
         index_transactions: true,
         integration_test: true,
         no_index_inscriptions: true,
+        protect_rarity: None,
         server_password: Some("server password".into()),
         server_url: None,
         server_username: Some("server username".into()),
@@ -18,7 +18,7 @@ pub(crate) struct Env {
   directory: PathBuf,
   #[arg(
     long,
-    help = "Decompress encoded content. Currently only supports brotli. Be careful using this on production instances. A decompressed inscription may be arbitrarily large, making decompression a DoS vector."
+    help = "Decompress encoded content. Supports brotli, gzip, and zstd. Be careful using this on production instances. A decompressed inscription may be arbitrarily large, making decompression a DoS vector."
   )]
   pub(crate) decompress: bool,
   #[arg(
@@ -3,6 +3,7 @@ use {
     accept_encoding::AcceptEncoding,
     accept_json::AcceptJson,
     error::{OptionExt, ServerError, ServerResult},
+    virtual_host::VirtualHosts,
   },
   super::*,
   crate::templates::{
@@ -10,7 +11,8 @@ use {
     InputHtml, InscriptionHtml, InscriptionsBlockHtml, InscriptionsHtml, OutputHtml, PageContent,
     PageHtml, ParentsHtml, PreviewAudioHtml, PreviewCodeHtml, PreviewFontHtml, PreviewImageHtml,
     PreviewMarkdownHtml, PreviewModelHtml, PreviewPdfHtml, PreviewTextHtml, PreviewUnknownHtml,
-    PreviewVideoHtml, RareTxt, RuneHtml, RunesHtml, SatHtml, TransactionHtml,
+    PreviewVideoHtml, RareTxt, RuneActivityHtml, RuneHtml, RunesBlockHtml, RunesHtml, SatHtml,
+    TransactionHtml,
   },
   axum::{
     body,
@@ -18,10 +20,10 @@ use {
     http::{header, HeaderValue, StatusCode, Uri},
     response::{IntoResponse, Redirect, Response},
     routing::{get, post},
-    Router,
+    Router, ServiceExt,
   },
   axum_server::Handle,
-  brotli::Decompressor,
+  hyper::service::Service,
   rust_embed::RustEmbed,
   rustls_acme::{
     acme::{LETS_ENCRYPT_PRODUCTION_DIRECTORY, LETS_ENCRYPT_STAGING_DIRECTORY},
@@ -39,13 +41,19 @@ use {
   },
 };
 
-pub(crate) use server_config::ServerConfig;
+pub(crate) use {
+  proxy_cache::{ProxyCache, ProxyResponse},
+  server_config::ServerConfig,
+  virtual_host::VirtualHost,
+};
 
 mod accept_encoding;
 mod accept_json;
 mod error;
+mod proxy_cache;
 pub mod query;
 mod server_config;
+mod virtual_host;
 
 enum SpawnConfig {
   Https(AxumAcceptor),
@@ -81,7 +89,7 @@ pub struct Server {
   pub(crate) csp_origin: Option<String>,
   #[arg(
     long,
-    help = "Decompress encoded content. Currently only supports brotli. Be careful using this on production instances. A decompressed inscription may be arbitrarily large, making decompression a DoS vector."
+    help = "Decompress encoded content. Supports brotli, gzip, and zstd. Be careful using this on production instances. A decompressed inscription may be arbitrarily large, making decompression a DoS vector."
   )]
   pub(crate) decompress: bool,
   #[arg(long, help = "Disable JSON API.")]
@@ -114,186 +122,98 @@ pub struct Server {
     help = "Proxy `/content/INSCRIPTION_ID` and other recursive endpoints to `<PROXY>` if the inscription is not present on current chain."
   )]
   pub(crate) proxy: Option<Url>,
+  #[arg(
+    long,
+    requires = "proxy_cache_size",
+    help = "Store proxy cache in <PROXY_CACHE_DIR>. [default: <DATA_DIR>/proxy-cache]"
+  )]
+  pub(crate) proxy_cache_dir: Option<PathBuf>,
+  #[arg(
+    long,
+    default_value = "10m",
+    help = "Serve cached responses to recursive endpoints which may change, like `/r/inscription`, for at most <PROXY_CACHE_MAX_AGE>. Content and metadata never change and do not expire."
+  )]
+  pub(crate) proxy_cache_max_age: humantime::Duration,
+  #[arg(
+    long,
+    requires = "proxy",
+    help = "Cache up to <PROXY_CACHE_SIZE> bytes of responses from <PROXY> on disk, evicting the least recently used."
+  )]
+  pub(crate) proxy_cache_size: Option<u64>,
   #[arg(
     long,
     default_value = "5s",
     help = "Poll Bitcoin Core every <POLLING_INTERVAL>."
   )]
   pub(crate) polling_interval: humantime::Duration,
+  #[arg(
+    long,
+    value_name = "HOST=CONFIG",
+    help = "Serve the chain configured by config file <CONFIG> to requests for <HOST>. May be given multiple times. Each virtual host has its own index and settings, and shares this server's listeners and ACME certificate."
+  )]
+  pub(crate) virtual_host: Vec<VirtualHost>,
 }
 
 impl Server {
   pub fn run(self, settings: Settings, index: Arc<Index>, handle: Handle) -> SubcommandResult {
     Runtime::new()?.block_on(async {
-      let index_clone = index.clone();
       let integration_test = settings.integration_test();
 
-      let index_thread = thread::spawn(move || loop {
-        if SHUTTING_DOWN.load(atomic::Ordering::Relaxed) {
-          break;
-        }
+      self.spawn_index_thread(index.clone(), integration_test);
 
-        if !self.no_sync {
-          if let Err(error) = index_clone.update() {
-            log::warn!("Updating index: {error}");
-          }
-        }
+      let settings = Arc::new(settings);
+      let acme_domains = self.acme_domains()?;
 
-        thread::sleep(if integration_test {
-          Duration::from_millis(100)
-        } else {
-          self.polling_interval.into()
-        });
-      });
+      let proxy_cache = match self.proxy_cache_size {
+        Some(size) => Some(Arc::new(ProxyCache::open(
+          self
+            .proxy_cache_dir
+            .clone()
+            .unwrap_or_else(|| settings.data_dir().join("proxy-cache")),
+          size,
+          self.proxy_cache_max_age.into(),
+        )?)),
+        None => None,
+      };
 
-      INDEXER.lock().unwrap().replace(index_thread);
+      let router = self.router(
+        settings.clone(),
+        index,
+        acme_domains.first().cloned(),
+        proxy_cache.clone(),
+      );
 
-      let settings = Arc::new(settings);
-      let acme_domains = self.acme_domains()?;
+      let mut hosts = BTreeMap::new();
 
-      let server_config = Arc::new(ServerConfig {
-        chain: settings.chain(),
-        csp_origin: self.csp_origin.clone(),
-        decompress: self.decompress,
-        domain: acme_domains.first().cloned(),
-        index_sats: index.has_sat_index(),
-        json_api_enabled: !self.disable_json_api,
-        proxy: self.proxy.clone(),
-      });
+      for virtual_host in &self.virtual_host {
+        ensure!(
+          !hosts.contains_key(&virtual_host.host),
+          "duplicate virtual host `{}`",
+          virtual_host.host,
+        );
 
-      let router = Router::new()
-        .route("/", get(Self::home))
-        .route("/address/:address", get(Self::address))
-        .route("/block/:query", get(Self::block))
-        .route("/blockcount", get(Self::block_count))
-        .route("/blockhash", get(Self::block_hash))
-        .route("/blockhash/:height", get(Self::block_hash_from_height))
-        .route("/blockheight", get(Self::block_height))
-        .route("/blocks", get(Self::blocks))
-        .route("/blocktime", get(Self::block_time))
-        .route("/bounties", get(Self::bounties))
-        .route("/children/:inscription_id", get(Self::children))
-        .route(
-          "/children/:inscription_id/:page",
-          get(Self::children_paginated),
-        )
-        .route("/clock", get(Self::clock))
-        .route("/collections", get(Self::collections))
-        .route("/collections/:page", get(Self::collections_paginated))
-        .route("/content/:inscription_id", get(Self::content))
-        .route("/faq", get(Self::faq))
-        .route("/favicon.ico", get(Self::favicon))
-        .route("/feed.xml", get(Self::feed))
-        .route("/input/:block/:transaction/:input", get(Self::input))
-        .route("/inscription/:inscription_query", get(Self::inscription))
-        .route(
-          "/inscription/:inscription_query/:child",
-          get(Self::inscription_child),
-        )
-        .route("/inscriptions", get(Self::inscriptions))
-        .route("/inscriptions", post(Self::inscriptions_json))
-        .route("/inscriptions/:page", get(Self::inscriptions_paginated))
-        .route(
-          "/inscriptions/block/:height",
-          get(Self::inscriptions_in_block),
-        )
-        .route(
-          "/inscriptions/block/:height/:page",
-          get(Self::inscriptions_in_block_paginated),
-        )
-        .route("/install.sh", get(Self::install_script))
-        .route("/ordinal/:sat", get(Self::ordinal))
-        .route("/output/:output", get(Self::output))
-        .route("/outputs", post(Self::outputs))
-        .route("/parents/:inscription_id", get(Self::parents))
-        .route(
-          "/parents/:inscription_id/:page",
-          get(Self::parents_paginated),
-        )
-        .route("/preview/:inscription_id", get(Self::preview))
-        .route("/r/blockhash", get(Self::block_hash_json))
-        .route(
-          "/r/blockhash/:height",
-          get(Self::block_hash_from_height_json),
-        )
-        .route("/r/blockheight", get(Self::block_height))
-        .route("/r/blocktime", get(Self::block_time))
-        .route("/r/blockinfo/:query", get(Self::block_info))
-        .route(
-          "/r/inscription/:inscription_id",
-          get(Self::inscription_recursive),
-        )
-        .route("/r/children/:inscription_id", get(Self::children_recursive))
-        .route(
-          "/r/children/:inscription_id/:page",
-          get(Self::children_recursive_paginated),
-        )
-        .route(
-          "/r/children/:inscription_id/inscriptions",
-          get(Self::child_inscriptions_recursive),
-        )
-        .route(
-          "/r/children/:inscription_id/inscriptions/:page",
-          get(Self::child_inscriptions_recursive_paginated),
-        )
-        .route("/r/metadata/:inscription_id", get(Self::metadata))
-        .route("/r/parents/:inscription_id", get(Self::parents_recursive))
-        .route(
-          "/r/parents/:inscription_id/:page",
-          get(Self::parents_recursive_paginated),
-        )
-        .route("/r/sat/:sat_number", get(Self::sat_inscriptions))
-        .route(
-          "/r/sat/:sat_number/:page",
-          get(Self::sat_inscriptions_paginated),
-        )
-        .route(
-          "/r/sat/:sat_number/at/:index",
-          get(Self::sat_inscription_at_index),
-        )
-        .route("/rare.txt", get(Self::rare_txt))
-        .route("/rune/:rune", get(Self::rune))
-        .route("/runes", get(Self::runes))
-        .route("/runes/:page", get(Self::runes_paginated))
-        .route("/sat/:sat", get(Self::sat))
-        .route("/satpoint/:satpoint", get(Self::satpoint))
-        .route("/search", get(Self::search_by_query))
-        .route("/search/*query", get(Self::search_by_path))
-        .route("/static/*path", get(Self::static_asset))
-        .route("/status", get(Self::status))
-        .route("/tx/:txid", get(Self::transaction))
-        .route("/decode/:txid", get(Self::decode))
-        .route("/update", get(Self::update))
-        .fallback(Self::fallback)
-        .layer(Extension(index))
-        .layer(Extension(server_config.clone()))
-        .layer(Extension(settings.clone()))
-        .layer(SetResponseHeaderLayer::if_not_present(
-          header::CONTENT_SECURITY_POLICY,
-          HeaderValue::from_static("default-src 'self'"),
-        ))
-        .layer(SetResponseHeaderLayer::overriding(
-          header::STRICT_TRANSPORT_SECURITY,
-          HeaderValue::from_static("max-age=31536000; includeSubDomains; preload"),
-        ))
-        .layer(
-          CorsLayer::new()
-            .allow_methods([http::Method::GET])
-            .allow_origin(Any),
-        )
-        .layer(CompressionLayer::new())
-        .with_state(server_config.clone());
+        let settings = virtual_host
+          .settings()
+          .with_context(|| format!("failed to load settings for `{}`", virtual_host.host))?;
 
-      let router = if server_config.json_api_enabled {
-        router.layer(DefaultBodyLimit::disable())
-      } else {
-        router
-      };
+        let index = Arc::new(Index::open(&settings)?);
 
-      let router = if let Some((username, password)) = settings.credentials() {
-        router.layer(ValidateRequestHeaderLayer::basic(username, password))
-      } else {
-        router
+        self.spawn_index_thread(index.clone(), integration_test);
+
+        hosts.insert(
+          virtual_host.host.clone(),
+          self.router(
+            Arc::new(settings),
+            index,
+            Some(virtual_host.host.clone()),
+            proxy_cache.clone(),
+          ),
+        );
+      }
+
+      let router = VirtualHosts {
+        default: router,
+        hosts,
       };
 
       match (self.http_port(), self.https_port()) {
@@ -349,10 +269,197 @@ impl Server {
     })
   }
 
+  fn router(
+    &self,
+    settings: Arc<Settings>,
+    index: Arc<Index>,
+    domain: Option<String>,
+    proxy_cache: Option<Arc<ProxyCache>>,
+  ) -> Router {
+    let server_config = Arc::new(ServerConfig {
+      chain: settings.chain(),
+      csp_origin: self.csp_origin.clone(),
+      decompress: self.decompress,
+      domain,
+      index_runes: index.has_rune_index(),
+      index_sats: index.has_sat_index(),
+      json_api_enabled: !self.disable_json_api,
+      proxy: self.proxy.clone(),
+      proxy_cache,
+    });
+
+    let router = Router::new()
+      .route("/", get(Self::home))
+      .route("/address/:address", get(Self::address))
+      .route("/block/:query", get(Self::block))
+      .route("/blockcount", get(Self::block_count))
+      .route("/blockhash", get(Self::block_hash))
+      .route("/blockhash/:height", get(Self::block_hash_from_height))
+      .route("/blockheight", get(Self::block_height))
+      .route("/blocks", get(Self::blocks))
+      .route("/blocktime", get(Self::block_time))
+      .route("/bounties", get(Self::bounties))
+      .route("/children/:inscription_id", get(Self::children))
+      .route(
+        "/children/:inscription_id/:page",
+        get(Self::children_paginated),
+      )
+      .route("/clock", get(Self::clock))
+      .route("/collections", get(Self::collections))
+      .route("/collections/:page", get(Self::collections_paginated))
+      .route("/content/:inscription_id", get(Self::content))
+      .route("/faq", get(Self::faq))
+      .route("/favicon.ico", get(Self::favicon))
+      .route("/feed.xml", get(Self::feed))
+      .route("/input/:block/:transaction/:input", get(Self::input))
+      .route("/inscription/:inscription_query", get(Self::inscription))
+      .route(
+        "/inscription/:inscription_query/:child",
+        get(Self::inscription_child),
+      )
+      .route("/inscriptions", get(Self::inscriptions))
+      .route("/inscriptions", post(Self::inscriptions_json))
+      .route("/inscriptions/:page", get(Self::inscriptions_paginated))
+      .route(
+        "/inscriptions/block/:height",
+        get(Self::inscriptions_in_block),
+      )
+      .route(
+        "/inscriptions/block/:height/:page",
+        get(Self::inscriptions_in_block_paginated),
+      )
+      .route("/install.sh", get(Self::install_script))
+      .route("/ordinal/:sat", get(Self::ordinal))
+      .route("/output/:output", get(Self::output))
+      .route("/outputs", post(Self::outputs))
+      .route("/parents/:inscription_id", get(Self::parents))
+      .route(
+        "/parents/:inscription_id/:page",
+        get(Self::parents_paginated),
+      )
+      .route("/preview/:inscription_id", get(Self::preview))
+      .route("/r/blockhash", get(Self::block_hash_json))
+      .route(
+        "/r/blockhash/:height",
+        get(Self::block_hash_from_height_json),
+      )
+      .route("/r/blockheight", get(Self::block_height))
+      .route("/r/blocktime", get(Self::block_time))
+      .route("/r/blockinfo/:query", get(Self::block_info))
+      .route(
+        "/r/inscription/:inscription_id",
+        get(Self::inscription_recursive),
+      )
+      .route("/r/children/:inscription_id", get(Self::children_recursive))
+      .route(
+        "/r/children/:inscription_id/:page",
+        get(Self::children_recursive_paginated),
+      )
+      .route(
+        "/r/children/:inscription_id/inscriptions",
+        get(Self::child_inscriptions_recursive),
+      )
+      .route(
+        "/r/children/:inscription_id/inscriptions/:page",
+        get(Self::child_inscriptions_recursive_paginated),
+      )
+      .route("/r/metadata/:inscription_id", get(Self::metadata))
+      .route("/r/parents/:inscription_id", get(Self::parents_recursive))
+      .route(
+        "/r/parents/:inscription_id/:page",
+        get(Self::parents_recursive_paginated),
+      )
+      .route("/r/sat/:sat_number", get(Self::sat_inscriptions))
+      .route(
+        "/r/sat/:sat_number/:page",
+        get(Self::sat_inscriptions_paginated),
+      )
+      .route(
+        "/r/sat/:sat_number/at/:index",
+        get(Self::sat_inscription_at_index),
+      )
+      .route("/rare.txt", get(Self::rare_txt))
+      .route("/rune/:rune", get(Self::rune))
+      .route("/rune/:rune/activity", get(Self::rune_activity))
+      .route(
+        "/rune/:rune/activity/:page",
+        get(Self::rune_activity_paginated),
+      )
+      .route("/runes", get(Self::runes))
+      .route("/runes/:page", get(Self::runes_paginated))
+      .route("/runes/block/:height", get(Self::runes_in_block))
+      .route("/runes/feed.xml", get(Self::runes_feed))
+      .route("/sat/:sat", get(Self::sat))
+      .route("/satpoint/:satpoint", get(Self::satpoint))
+      .route("/search", get(Self::search_by_query))
+      .route("/search/*query", get(Self::search_by_path))
+      .route("/static/*path", get(Self::static_asset))
+      .route("/status", get(Self::status))
+      .route("/tx/:txid", get(Self::transaction))
+      .route("/decode/:txid", get(Self::decode))
+      .route("/update", get(Self::update))
+      .fallback(Self::fallback)
+      .layer(Extension(index))
+      .layer(Extension(server_config.clone()))
+      .layer(Extension(settings.clone()))
+      .layer(SetResponseHeaderLayer::if_not_present(
+        header::CONTENT_SECURITY_POLICY,
+        HeaderValue::from_static("default-src 'self'"),
+      ))
+      .layer(SetResponseHeaderLayer::overriding(
+        header::STRICT_TRANSPORT_SECURITY,
+        HeaderValue::from_static("max-age=31536000; includeSubDomains; preload"),
+      ))
+      .layer(
+        CorsLayer::new()
+          .allow_methods([http::Method::GET])
+          .allow_origin(Any),
+      )
+      .layer(CompressionLayer::new())
+      .with_state(server_config.clone());
+
+    let router = if server_config.json_api_enabled {
+      router.layer(DefaultBodyLimit::disable())
+    } else {
+      router
+    };
+
+    if let Some((username, password)) = settings.credentials() {
+      router.layer(ValidateRequestHeaderLayer::basic(username, password))
+    } else {
+      router
+    }
+  }
+
+  fn spawn_index_thread(&self, index: Arc<Index>, integration_test: bool) {
+    let no_sync = self.no_sync;
+    let polling_interval = self.polling_interval;
+
+    let index_thread = thread::spawn(move || loop {
+      if SHUTTING_DOWN.load(atomic::Ordering::Relaxed) {
+        break;
+      }
+
+      if !no_sync {
+        if let Err(error) = index.update() {
+          log::warn!("Updating index: {error}");
+        }
+      }
+
+      thread::sleep(if integration_test {
+        Duration::from_millis(100)
+      } else {
+        polling_interval.into()
+      });
+    });
+
+    INDEXERS.lock().unwrap().push(index_thread);
+  }
+
   fn spawn(
     &self,
     settings: &Settings,
-    router: Router,
+    router: VirtualHosts,
     handle: Handle,
     port: u16,
     config: SpawnConfig,
@@ -421,13 +528,19 @@ impl Server {
   }
 
   fn acme_domains(&self) -> Result<Vec<String>> {
-    if !self.acme_domain.is_empty() {
-      Ok(self.acme_domain.clone())
+    let mut domains = if !self.acme_domain.is_empty() {
+      self.acme_domain.clone()
     } else {
-      Ok(vec![
-        System::host_name().ok_or(anyhow!("no hostname found"))?
-      ])
+      vec![System::host_name().ok_or(anyhow!("no hostname found"))?]
+    };
+
+    for virtual_host in &self.virtual_host {
+      if !domains.contains(&virtual_host.host) {
+        domains.push(virtual_host.host.clone());
+      }
     }
+
+    Ok(domains)
   }
 
   fn http_port(&self) -> Option<u16> {
@@ -679,15 +792,7 @@ impl Server {
         ));
       }
 
-      let rune = match rune_query {
-        query::Rune::Spaced(spaced_rune) => spaced_rune.rune,
-        query::Rune::Id(rune_id) => index
-          .get_rune_by_id(rune_id)?
-          .ok_or_not_found(|| format!("rune {rune_id}"))?,
-        query::Rune::Number(number) => index
-          .get_rune_by_number(usize::try_from(number).unwrap())?
-          .ok_or_not_found(|| format!("rune number {number}"))?,
-      };
+      let rune = Self::rune_from_query(&index, rune_query)?;
 
       let (id, entry, parent) = index
         .rune(rune)?
@@ -718,6 +823,91 @@ impl Server {
     })
   }
 
+  fn rune_from_query(index: &Index, rune_query: query::Rune) -> ServerResult<Rune> {
+    Ok(match rune_query {
+      query::Rune::Spaced(spaced_rune) => spaced_rune.rune,
+      query::Rune::Id(rune_id) => index
+        .get_rune_by_id(rune_id)?
+        .ok_or_not_found(|| format!("rune {rune_id}"))?,
+      query::Rune::Number(number) => index
+        .get_rune_by_number(usize::try_from(number).unwrap())?
+        .ok_or_not_found(|| format!("rune number {number}"))?,
+    })
+  }
+
+  async fn rune_activity(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path(rune_query): Path<DeserializeFromStr<query::Rune>>,
+    accept_json: AcceptJson,
+  ) -> ServerResult {
+    Self::rune_activity_paginated(
+      Extension(server_config),
+      Extension(index),
+      Path((rune_query, 0)),
+      accept_json,
+    )
+    .await
+  }
+
+  async fn rune_activity_paginated(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path((DeserializeFromStr(rune_query), page_index)): Path<(
+      DeserializeFromStr<query::Rune>,
+      usize,
+    )>,
+    AcceptJson(accept_json): AcceptJson,
+  ) -> ServerResult {
+    task::block_in_place(|| {
+      if !index.has_rune_index() {
+        return Err(ServerError::NotFound(
+          "this server has no rune index".to_string(),
+        ));
+      }
+
+      let rune = Self::rune_from_query(&index, rune_query)?;
+
+      let (id, entry, _parent) = index
+        .rune(rune)?
+        .ok_or_not_found(|| format!("rune {rune}"))?;
+
+      let (activity, more) = index.get_rune_activity_paginated(id, 100, page_index)?;
+
+      let height = index.block_height()?.unwrap_or(Height(0)).n() + 1;
+
+      let end_timestamp = entry
+        .end()
+        .and_then(|end| u32::try_from(end).ok())
+        .map(|end| index.block_time(Height(end)))
+        .transpose()?
+        .and_then(|blocktime| u64::try_from(blocktime.unix_timestamp()).ok());
+
+      let progress = api::MintProgress::new(
+        &entry,
+        height.into(),
+        index.get_rune_mints_per_block(id)?,
+        end_timestamp,
+      );
+
+      let activity = RuneActivityHtml {
+        activity,
+        entry,
+        id,
+        more,
+        next: more.then_some(page_index + 1),
+        prev: page_index.checked_sub(1),
+        progress,
+      };
+
+      Ok(if accept_json {
+        Json(activity).into_response()
+      } else {
+        activity.page(server_config).into_response()
+      })
+    })
+  }
+
   async fn runes(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
@@ -766,6 +956,92 @@ impl Server {
     })
   }
 
+  async fn runes_in_block(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path(height): Path<u32>,
+    AcceptJson(accept_json): AcceptJson,
+  ) -> ServerResult {
+    task::block_in_place(|| {
+      if !index.has_rune_index() {
+        return Err(ServerError::NotFound(
+          "this server has no rune index".to_string(),
+        ));
+      }
+
+      let best_height = Self::index_height(&index)?;
+
+      if height > best_height.n() {
+        return Err(ServerError::NotFound(format!("block {height}")));
+      }
+
+      let runes_block = RunesBlockHtml::new(
+        height,
+        best_height.n(),
+        index.get_rune_activity_in_block(height)?,
+      );
+
+      Ok(if accept_json {
+        Json(runes_block).into_response()
+      } else {
+        runes_block.page(server_config).into_response()
+      })
+    })
+  }
+
+  async fn runes_feed(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+  ) -> ServerResult {
+    task::block_in_place(|| {
+      if !index.has_rune_index() {
+        return Err(ServerError::NotFound(
+          "this server has no rune index".to_string(),
+        ));
+      }
+
+      let mut builder = rss::ChannelBuilder::default();
+
+      let chain = server_config.chain;
+      match chain {
+        Chain::Mainnet => builder.title("Runes".to_string()),
+        _ => builder.title(format!("Runes – {chain:?}")),
+      };
+
+      builder.generator(Some("ord".to_string()));
+
+      for (_id, entry) in index.get_feed_runes(300)? {
+        let spaced_rune = entry.spaced_rune;
+
+        builder.item(
+          rss::ItemBuilder::default()
+            .title(Some(format!("Rune {spaced_rune}")))
+            .link(Some(format!("/rune/{spaced_rune}")))
+            .guid(Some(rss::Guid {
+              value: format!("/rune/{spaced_rune}"),
+              permalink: true,
+            }))
+            .pub_date(Some(timestamp(entry.timestamp).to_rfc2822()))
+            .build(),
+        );
+      }
+
+      Ok(
+        (
+          [
+            (header::CONTENT_TYPE, "application/rss+xml"),
+            (
+              header::CONTENT_SECURITY_POLICY,
+              "default-src 'unsafe-inline'",
+            ),
+          ],
+          builder.build().to_string(),
+        )
+          .into_response(),
+      )
+    })
+  }
+
   async fn home(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
@@ -997,7 +1273,11 @@ impl Server {
     task::block_in_place(|| {
       let Some(inscription) = index.get_inscription_by_id(inscription_id)? else {
         return if let Some(proxy) = server_config.proxy.as_ref() {
-          Self::proxy(proxy, &format!("r/metadata/{}", inscription_id))
+          Self::proxy(
+            &server_config,
+            proxy,
+            &format!("r/metadata/{}", inscription_id),
+          )
         } else {
           Err(ServerError::NotFound(format!(
             "inscription {} not found",
@@ -1022,7 +1302,11 @@ impl Server {
     task::block_in_place(|| {
       let Some(inscription) = index.get_inscription_by_id(inscription_id)? else {
         return if let Some(proxy) = server_config.proxy.as_ref() {
-          Self::proxy(proxy, &format!("r/inscription/{}", inscription_id))
+          Self::proxy(
+            &server_config,
+            proxy,
+            &format!("r/inscription/{}", inscription_id),
+          )
         } else {
           Err(ServerError::NotFound(format!(
             "inscription {} not found",
@@ -1401,27 +1685,68 @@ impl Server {
     Redirect::to("https://docs.ordinals.com/bounty/")
   }
 
-  fn proxy(proxy: &Url, path: &String) -> ServerResult<Response> {
-    let response = reqwest::blocking::Client::new()
-      .get(format!("{}{}", proxy, path))
-      .send()
-      .map_err(|err| anyhow!(err))?;
+  fn proxy(server_config: &ServerConfig, proxy: &Url, path: &str) -> ServerResult<Response> {
+    let url = format!("{proxy}{path}");
 
-    let mut headers = response.headers().clone();
+    let content_security_policy = HeaderValue::from_str(&format!(
+      "default-src 'self' {proxy} 'unsafe-eval' 'unsafe-inline' data: blob:"
+    ))
+    .map_err(|err| ServerError::Internal(Error::from(err)))?;
 
-    headers.insert(
-      header::CONTENT_SECURITY_POLICY,
-      HeaderValue::from_str(&format!(
-        "default-src 'self' {proxy} 'unsafe-eval' 'unsafe-inline' data: blob:"
-      ))
-      .map_err(|err| ServerError::Internal(Error::from(err)))?,
-    );
+    let Some(cache) = &server_config.proxy_cache else {
+      let response = reqwest::blocking::Client::new()
+        .get(url)
+        .send()
+        .map_err(|err| anyhow!(err))?;
+
+      let mut headers = response.headers().clone();
+
+      headers.insert(header::CONTENT_SECURITY_POLICY, content_security_policy);
+
+      return Ok(
+        (
+          response.status(),
+          headers,
+          response.bytes().map_err(|err| anyhow!(err))?,
+        )
+          .into_response(),
+      );
+    };
+
+    // content and metadata of an inscription never change
+    let immutable = path.starts_with("content/") || path.starts_with("r/metadata/");
+
+    let response = match cache.get(&url, immutable) {
+      Some(response) => response,
+      None => {
+        let response = ProxyResponse::fetch(&url)?;
+
+        if response.status == StatusCode::OK.as_u16() {
+          if let Err(err) = cache.insert(&url, &response) {
+            log::warn!("failed to cache proxy response for {url}: {err}");
+          }
+        }
+
+        response
+      }
+    };
+
+    let mut headers = HeaderMap::new();
+
+    for (name, value) in &response.headers {
+      headers.insert(
+        header::HeaderName::from_bytes(name.as_bytes()).map_err(|err| anyhow!(err))?,
+        HeaderValue::from_str(value).map_err(|err| anyhow!(err))?,
+      );
+    }
+
+    headers.insert(header::CONTENT_SECURITY_POLICY, content_security_policy);
 
     Ok(
       (
-        response.status(),
+        StatusCode::from_u16(response.status).map_err(|err| anyhow!(err))?,
         headers,
-        response.bytes().map_err(|err| anyhow!(err))?,
+        response.body,
       )
         .into_response(),
     )
@@ -1441,7 +1766,11 @@ impl Server {
 
       let Some(mut inscription) = index.get_inscription_by_id(inscription_id)? else {
         return if let Some(proxy) = server_config.proxy.as_ref() {
-          Self::proxy(proxy, &format!("content/{}", inscription_id))
+          Self::proxy(
+            &server_config,
+            proxy,
+            &format!("content/{}", inscription_id),
+          )
         } else {
           Err(ServerError::NotFound(format!(
             "inscription {} not found",
@@ -1507,15 +1836,18 @@ impl Server {
     if let Some(content_encoding) = inscription.content_encoding() {
       if accept_encoding.is_acceptable(&content_encoding) {
         headers.insert(header::CONTENT_ENCODING, content_encoding);
-      } else if server_config.decompress && content_encoding == "br" {
+      } else if let Some(compression) = content_encoding
+        .to_str()
+        .ok()
+        .and_then(Compression::from_content_encoding)
+        .filter(|_| server_config.decompress)
+      {
         let Some(body) = inscription.into_body() else {
           return Ok(None);
         };
 
-        let mut decompressed = Vec::new();
-
-        Decompressor::new(body.as_slice(), 4096)
-          .read_to_end(&mut decompressed)
+        let decompressed = compression
+          .decompress(&body)
           .map_err(|err| ServerError::Internal(err.into()))?;
 
         return Ok(Some((headers, decompressed)));
@@ -1808,7 +2140,11 @@ impl Server {
     task::block_in_place(|| {
       let Some(parent) = index.get_inscription_entry(parent)? else {
         return if let Some(proxy) = server_config.proxy.as_ref() {
-          Self::proxy(proxy, &format!("r/children/{}/{}", parent, page))
+          Self::proxy(
+            &server_config,
+            proxy,
+            &format!("r/children/{}/{}", parent, page),
+          )
         } else {
           Err(ServerError::NotFound(format!(
             "inscription {} not found",
@@ -3195,6 +3531,8 @@ mod tests {
   <dd><a class=monospace href=/tx/{txid}>{txid}</a></dd>
   <dt>parent</dt>
   <dd><a class=monospace href=/inscription/{txid}i0>{txid}i0</a></dd>
+  <dt>activity</dt>
+  <dd><a href=/rune/AAAAAAAAAAAAA/activity>activity</a></dd>
 </dl>
 .*"
       ),
@@ -3213,6 +3551,363 @@ mod tests {
     );
   }
 
+  #[test]
+  fn rune_activity_page() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_runes()
+      .build();
+
+    server.mine_blocks(1);
+
+    let rune = Rune(RUNE);
+
+    server.assert_response_regex(
+      format!("/rune/{rune}/activity"),
+      StatusCode::NOT_FOUND,
+      ".*",
+    );
+
+    let (txid, id) = server.etch(
+      Runestone {
+        edicts: vec![Edict {
+          id: RuneId::default(),
+          amount: 100,
+          output: 0,
+        }],
+        etching: Some(Etching {
+          rune: Some(rune),
+          premine: Some(100),
+          terms: Some(Terms {
+            amount: Some(10),
+            cap: Some(3),
+            height: (None, Some(20)),
+            offset: (None, None),
+          }),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+      None,
+    );
+
+    let mint = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(4, 0, 0, Witness::new())],
+      op_return: Some(
+        Runestone {
+          mint: Some(id),
+          ..default()
+        }
+        .encipher(),
+      ),
+      ..default()
+    });
+
+    server.mine_blocks(1);
+
+    let activity = server.get_json::<api::RuneActivity>(format!("/rune/{rune}/activity"));
+
+    assert!(activity.progress.end_timestamp.is_some());
+
+    pretty_assert_eq!(
+      activity,
+      api::RuneActivity {
+        activity: vec![
+          crate::index::RuneActivity {
+            amount: 100,
+            height: 8,
+            kind: crate::index::RuneActivityKind::Etching,
+            output: None,
+            transaction: 1,
+            txid,
+          },
+          crate::index::RuneActivity {
+            amount: 10,
+            height: 9,
+            kind: crate::index::RuneActivityKind::Mint,
+            output: None,
+            transaction: 1,
+            txid: mint,
+          },
+        ],
+        entry: server.index.rune(rune).unwrap().unwrap().1,
+        id,
+        more: false,
+        next: None,
+        prev: None,
+        progress: api::MintProgress {
+          cap: 3,
+          end: Some(20),
+          end_timestamp: activity.progress.end_timestamp,
+          mintable: true,
+          mints: 1,
+          mints_per_block: [(9, 1)].into(),
+          remaining: 2,
+          remaining_blocks: Some(10),
+          start: None,
+        },
+      }
+    );
+
+    server.assert_response_regex(
+      format!("/rune/{rune}/activity"),
+      StatusCode::OK,
+      format!(
+        ".*<title>Rune AAAAAAAAAAAAA Activity</title>.*
+<h1><a href=/rune/AAAAAAAAAAAAA>AAAAAAAAAAAAA</a> Activity</h1>
+<h2>Mint Progress</h2>
+<dl>
+  <dt>mints</dt>
+  <dd>1</dd>
+  <dt>cap</dt>
+  <dd>3</dd>
+  <dt>remaining</dt>
+  <dd>2</dd>
+.*
+<h2>Activity</h2>
+<table>
+.*
+    <td><a href=/block/8>8</a></td>
+    <td>etching</td>
+    <td>100\u{A0}¤</td>
+    <td class=monospace><a href=/tx/{txid}>{txid}</a></td>
+.*
+    <td><a href=/block/9>9</a></td>
+    <td>mint</td>
+    <td>10\u{A0}¤</td>
+    <td class=monospace><a href=/tx/{mint}>{mint}</a></td>
+.*"
+      ),
+    );
+  }
+
+  #[test]
+  fn runes_block_page() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_runes()
+      .build();
+
+    server.mine_blocks(1);
+
+    let rune = Rune(RUNE);
+
+    let (txid, id) = server.etch(
+      Runestone {
+        edicts: vec![Edict {
+          id: RuneId::default(),
+          amount: 100,
+          output: 0,
+        }],
+        etching: Some(Etching {
+          rune: Some(rune),
+          premine: Some(100),
+          terms: Some(Terms {
+            amount: Some(10),
+            cap: Some(3),
+            ..default()
+          }),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+      None,
+    );
+
+    let mint = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(4, 0, 0, Witness::new())],
+      op_return: Some(
+        Runestone {
+          mint: Some(id),
+          ..default()
+        }
+        .encipher(),
+      ),
+      ..default()
+    });
+
+    server.mine_blocks(1);
+
+    let entry = server.index.rune(rune).unwrap().unwrap().1;
+
+    pretty_assert_eq!(
+      server.get_json::<api::RunesBlock>("/runes/block/9"),
+      api::RunesBlock {
+        activity: vec![crate::templates::runes_block::RunesBlockActivity {
+          activity: crate::index::RuneActivity {
+            amount: 10,
+            height: 9,
+            kind: crate::index::RuneActivityKind::Mint,
+            output: None,
+            transaction: 1,
+            txid: mint,
+          },
+          divisibility: entry.divisibility,
+          id,
+          spaced_rune: entry.spaced_rune,
+          symbol: entry.symbol,
+        }],
+        height: 9,
+        next: None,
+        prev: Some(8),
+      }
+    );
+
+    server.assert_response_regex(
+      "/runes/block/8",
+      StatusCode::OK,
+      format!(
+        ".*<title>Runes in Block 8</title>.*
+<h1>Runes in <a href=/block/8>Block 8</a></h1>
+<table>
+.*
+  <tr>
+    <td><a href=/rune/{rune}>{rune}</a></td>
+    <td>etching</td>
+    <td>100\u{A0}¤</td>
+    <td class=monospace><a href=/tx/{txid}>{txid}</a></td>
+  </tr>
+</table>
+<div class=center>
+  <a class=prev href=/runes/block/7>prev</a>
+  <a class=next href=/runes/block/9>next</a>
+</div>
+.*"
+      ),
+    );
+
+    server.assert_response_regex("/runes/block/10", StatusCode::NOT_FOUND, ".*");
+  }
+
+  #[test]
+  fn runes_feed() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_runes()
+      .build();
+
+    server.mine_blocks(1);
+
+    server.etch(
+      Runestone {
+        edicts: vec![Edict {
+          id: RuneId::default(),
+          amount: u128::MAX,
+          output: 0,
+        }],
+        etching: Some(Etching {
+          rune: Some(Rune(RUNE)),
+          premine: Some(u128::MAX),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+      None,
+    );
+
+    server.assert_response_regex(
+      "/runes/feed.xml",
+      StatusCode::OK,
+      ".*<title>Runes – Regtest</title>.*<title>Rune AAAAAAAAAAAAA</title>.*<link>/rune/AAAAAAAAAAAAA</link>.*",
+    );
+  }
+
+  #[test]
+  fn runes_feed_requires_rune_index() {
+    TestServer::new().assert_response_regex("/runes/feed.xml", StatusCode::NOT_FOUND, ".*");
+  }
+
+  #[test]
+  fn runes_feed_is_only_linked_with_rune_index() {
+    assert!(!TestServer::new()
+      .get("/")
+      .text()
+      .unwrap()
+      .contains("Rune Feed"));
+
+    assert!(TestServer::builder()
+      .index_runes()
+      .build()
+      .get("/")
+      .text()
+      .unwrap()
+      .contains(
+        "<link rel=alternate href=/runes/feed.xml type=application/rss+xml title='Rune Feed'>"
+      ));
+  }
+
+  #[test]
+  fn virtual_hosts_are_routed_by_host_header() {
+    let signet = mockcore::builder().network(Network::Signet).build();
+
+    let tempdir = TempDir::new().unwrap();
+
+    let cookie_file = tempdir.path().join("cookie");
+
+    fs::write(&cookie_file, "username:password").unwrap();
+
+    let config = tempdir.path().join("signet.yaml");
+
+    fs::write(
+      &config,
+      format!(
+        "bitcoin_rpc_url: {}\nchain: signet\ncookie_file: {}\ndata_dir: {}\n",
+        signet.url(),
+        cookie_file.display(),
+        tempdir.path().display(),
+      ),
+    )
+    .unwrap();
+
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .server_option(
+        "--virtual-host",
+        &format!("Signet.localhost={}", config.display()),
+      )
+      .build();
+
+    signet.mine_blocks(3);
+
+    let client = reqwest::blocking::Client::new();
+
+    for i in 0.. {
+      let response = client
+        .get(server.join_url("/blockcount"))
+        .header(header::HOST, "signet.localhost:1234")
+        .send()
+        .unwrap();
+
+      assert_eq!(response.status(), StatusCode::OK);
+
+      if response.text().unwrap() == "4" {
+        break;
+      }
+
+      if i == 400 {
+        panic!("virtual host index failed to update");
+      }
+
+      thread::sleep(Duration::from_millis(25));
+    }
+
+    let response = client
+      .get(server.join_url("/"))
+      .header(header::HOST, "signet.localhost")
+      .send()
+      .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_regex_match!(response.text().unwrap(), ".*<sup>signet</sup>.*");
+
+    assert_eq!(server.get("/blockcount").text().unwrap(), "1");
+
+    server.assert_response_regex("/", StatusCode::OK, ".*<sup>regtest</sup>.*");
+  }
+
   #[test]
   fn etched_runes_are_displayed_on_block_page() {
     let server = TestServer::builder()
@@ -6607,6 +7302,56 @@ next
     server_with_proxy.assert_response(format!("/content/{id}"), StatusCode::OK, "foo");
   }
 
+  #[test]
+  fn content_proxy_cache() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+
+    server.mine_blocks(1);
+
+    let inscription = Inscription {
+      content_type: Some("text/html".into()),
+      body: Some("foo".into()),
+      ..default()
+    };
+
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription.to_witness())],
+      ..default()
+    });
+
+    server.mine_blocks(1);
+
+    let id = InscriptionId { txid, index: 0 };
+
+    let server_with_proxy = TestServer::builder()
+      .chain(Chain::Regtest)
+      .server_option("--proxy", server.url.as_ref())
+      .server_option("--proxy-cache-size", "1000000")
+      .build();
+
+    server_with_proxy.mine_blocks(1);
+
+    server_with_proxy.assert_response(format!("/content/{id}"), StatusCode::OK, "foo");
+
+    drop(server);
+
+    let response = server_with_proxy.get(format!("/content/{id}"));
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response.headers().get(header::CONTENT_TYPE).unwrap(),
+      "text/html"
+    );
+    assert_eq!(response.text().unwrap(), "foo");
+
+    assert_eq!(
+      fs::read_dir(server_with_proxy.tempdir.path().join("regtest/proxy-cache"))
+        .unwrap()
+        .count(),
+      2
+    );
+  }
+
   #[test]
   fn metadata_proxy() {
     let server = TestServer::builder().chain(Chain::Regtest).build();
@@ -30,12 +30,34 @@ impl AcceptEncoding {
       return false;
     };
 
-    self
-      .0
-      .clone()
-      .unwrap_or_default()
-      .split(',')
-      .any(|value| value.split(';').next().unwrap_or_default().trim() == encoding)
+    let mut wildcard = false;
+
+    for value in self.0.as_deref().unwrap_or_default().split(',') {
+      let mut parameters = value.split(';');
+
+      let coding = parameters.next().unwrap_or_default().trim();
+
+      // a coding with a qvalue of zero is explicitly not acceptable
+      let acceptable = !parameters.any(|parameter| {
+        let parameter = parameter.trim();
+        parameter
+          .strip_prefix("q=")
+          .or_else(|| parameter.strip_prefix("Q="))
+          .and_then(|qvalue| qvalue.trim().parse::<f32>().ok())
+          .map(|qvalue| qvalue <= 0.0)
+          .unwrap_or_default()
+      });
+
+      if coding.eq_ignore_ascii_case(encoding) {
+        return acceptable;
+      }
+
+      if coding == "*" {
+        wildcard = acceptable;
+      }
+    }
+
+    wildcard
   }
 }
 
@@ -122,4 +144,43 @@ mod tests {
     assert!(encodings.is_acceptable(&HeaderValue::from_static("br")));
     assert!(!encodings.is_acceptable(&HeaderValue::from_static("bzip2")));
   }
+
+  #[test]
+  fn zero_qvalues_are_not_acceptable() {
+    let encodings = AcceptEncoding(Some("gzip;q=0, zstd; q=0.000, br;q=0.1".into()));
+
+    assert!(!encodings.is_acceptable(&HeaderValue::from_static("gzip")));
+    assert!(!encodings.is_acceptable(&HeaderValue::from_static("zstd")));
+    assert!(encodings.is_acceptable(&HeaderValue::from_static("br")));
+  }
+
+  #[test]
+  fn codings_are_case_insensitive() {
+    let encodings = AcceptEncoding(Some("GZIP, Zstd".into()));
+
+    assert!(encodings.is_acceptable(&HeaderValue::from_static("gzip")));
+    assert!(encodings.is_acceptable(&HeaderValue::from_static("zstd")));
+  }
+
+  #[test]
+  fn wildcard() {
+    let encodings = AcceptEncoding(Some("*".into()));
+
+    assert!(encodings.is_acceptable(&HeaderValue::from_static("zstd")));
+
+    let encodings = AcceptEncoding(Some("gzip;q=0, *".into()));
+
+    assert!(!encodings.is_acceptable(&HeaderValue::from_static("gzip")));
+    assert!(encodings.is_acceptable(&HeaderValue::from_static("zstd")));
+
+    let encodings = AcceptEncoding(Some("br, *;q=0".into()));
+
+    assert!(encodings.is_acceptable(&HeaderValue::from_static("br")));
+    assert!(!encodings.is_acceptable(&HeaderValue::from_static("zstd")));
+  }
+
+  #[test]
+  fn missing_header_accepts_nothing() {
+    assert!(!AcceptEncoding(None).is_acceptable(&HeaderValue::from_static("br")));
+  }
 }
@@ -0,0 +1,428 @@
+use {
+  super::*,
+  bitcoin::hashes::sha256,
+  std::{collections::HashMap, path::Path},
+};
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub(crate) struct ProxyResponse {
+  #[serde(skip)]
+  pub(crate) body: Vec<u8>,
+  pub(crate) fetched: u64,
+  pub(crate) headers: Vec<(String, String)>,
+  pub(crate) status: u16,
+}
+
+impl ProxyResponse {
+  const HEADERS: [header::HeaderName; 3] = [
+    header::CACHE_CONTROL,
+    header::CONTENT_ENCODING,
+    header::CONTENT_TYPE,
+  ];
+
+  pub(crate) fn fetch(url: &str) -> Result<Self> {
+    let response = reqwest::blocking::Client::new().get(url).send()?;
+
+    let headers = Self::HEADERS
+      .iter()
+      .filter_map(|name| {
+        let value = response.headers().get(name)?.to_str().ok()?;
+        Some((name.to_string(), value.into()))
+      })
+      .collect();
+
+    Ok(Self {
+      fetched: now(),
+      headers,
+      status: response.status().as_u16(),
+      body: response.bytes()?.to_vec(),
+    })
+  }
+
+  fn load(path: &Path) -> Result<Self> {
+    let contents = fs::read(path)?;
+
+    let newline = contents
+      .iter()
+      .position(|byte| *byte == b'\n')
+      .ok_or_else(|| anyhow!("missing header in `{}`", path.display()))?;
+
+    let mut response = serde_json::from_slice::<Self>(&contents[..newline])?;
+
+    response.body = contents[newline + 1..].to_vec();
+
+    Ok(response)
+  }
+
+  fn save(&self, path: &Path) -> Result {
+    let mut contents = serde_json::to_vec(self)?;
+    contents.push(b'\n');
+    contents.extend_from_slice(&self.body);
+
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, contents)?;
+    fs::rename(tmp, path)?;
+
+    Ok(())
+  }
+}
+
+#[derive(Default)]
+struct State {
+  entries: HashMap<String, (u64, u64)>,
+  recency: BTreeMap<u64, String>,
+  size: u64,
+  tick: u64,
+}
+
+impl State {
+  fn touch(&mut self, key: &str) -> bool {
+    let Some((_, tick)) = self.entries.get_mut(key) else {
+      return false;
+    };
+
+    self.recency.remove(tick);
+    self.tick += 1;
+    *tick = self.tick;
+    self.recency.insert(self.tick, key.into());
+
+    true
+  }
+
+  fn insert(&mut self, key: String, size: u64) {
+    self.remove(&key);
+    self.tick += 1;
+    self.size += size;
+    self.entries.insert(key.clone(), (size, self.tick));
+    self.recency.insert(self.tick, key);
+  }
+
+  fn remove(&mut self, key: &str) {
+    if let Some((size, tick)) = self.entries.remove(key) {
+      self.recency.remove(&tick);
+      self.size -= size;
+    }
+  }
+
+  fn least_recently_used(&self) -> Option<String> {
+    self.recency.values().next().cloned()
+  }
+}
+
+/// On-disk cache of responses from the `--proxy` server, evicting the least
+/// recently used entries once their total size exceeds `capacity`. Recency
+/// is tracked in memory, and is seeded from file modification times on open.
+///
+/// The cache directory is marked with a `CACHEDIR.TAG` file, and `ord` refuses
+/// to use a non-empty directory without one. Only files named by cache keys
+/// are counted towards the cache size or removed.
+pub(crate) struct ProxyCache {
+  capacity: u64,
+  dir: PathBuf,
+  max_age: Duration,
+  state: Mutex<State>,
+}
+
+impl ProxyCache {
+  const TAG: &'static str = "CACHEDIR.TAG";
+
+  pub(crate) fn open(dir: PathBuf, capacity: u64, max_age: Duration) -> Result<Self> {
+    fs::create_dir_all(&dir)
+      .with_context(|| format!("failed to create proxy cache dir `{}`", dir.display()))?;
+
+    let tag = dir.join(Self::TAG);
+
+    if !tag.exists() {
+      ensure!(
+        fs::read_dir(&dir)?.next().is_none(),
+        "refusing to use non-empty directory `{}` as proxy cache dir",
+        dir.display(),
+      );
+
+      fs::write(
+        &tag,
+        "Signature: 8a477f597d28d172789f06886806bc55\n\
+         # This file is a cache directory tag created by ord.\n",
+      )?;
+    }
+
+    let mut entries = Vec::new();
+
+    for entry in fs::read_dir(&dir)? {
+      let entry = entry?;
+      let path = entry.path();
+
+      let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        continue;
+      };
+
+      if let Some(key) = name.strip_suffix(".tmp") {
+        if Self::is_key(key) {
+          fs::remove_file(&path)?;
+        }
+        continue;
+      }
+
+      if !Self::is_key(name) {
+        continue;
+      }
+
+      let metadata = entry.metadata()?;
+
+      entries.push((
+        metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+        name.to_string(),
+        metadata.len(),
+      ));
+    }
+
+    entries.sort();
+
+    let mut state = State::default();
+
+    for (_, key, size) in entries {
+      state.insert(key, size);
+    }
+
+    let cache = Self {
+      capacity,
+      dir,
+      max_age,
+      state: Mutex::new(state),
+    };
+
+    cache.evict(&mut cache.state.lock().unwrap());
+
+    Ok(cache)
+  }
+
+  /// Immutable responses are served regardless of age, others only for
+  /// `max_age` after they were fetched.
+  pub(crate) fn get(&self, url: &str, immutable: bool) -> Option<ProxyResponse> {
+    let key = Self::key(url);
+
+    if !self.state.lock().unwrap().touch(&key) {
+      return None;
+    }
+
+    let response = match ProxyResponse::load(&self.dir.join(&key)) {
+      Ok(response) => response,
+      Err(err) => {
+        log::warn!("failed to load cached proxy response for {url}: {err}");
+        self.state.lock().unwrap().remove(&key);
+        return None;
+      }
+    };
+
+    if !immutable && now().saturating_sub(response.fetched) > self.max_age.as_secs() {
+      return None;
+    }
+
+    Some(response)
+  }
+
+  pub(crate) fn insert(&self, url: &str, response: &ProxyResponse) -> Result {
+    let key = Self::key(url);
+    let path = self.dir.join(&key);
+
+    response.save(&path)?;
+
+    let size = fs::metadata(&path)?.len();
+
+    let mut state = self.state.lock().unwrap();
+
+    state.insert(key, size);
+
+    self.evict(&mut state);
+
+    Ok(())
+  }
+
+  fn evict(&self, state: &mut State) {
+    while state.size > self.capacity {
+      let Some(key) = state.least_recently_used() else {
+        break;
+      };
+
+      if let Err(err) = fs::remove_file(self.dir.join(&key)) {
+        log::warn!("failed to remove cached proxy response {key}: {err}");
+      }
+
+      state.remove(&key);
+    }
+  }
+
+  fn key(url: &str) -> String {
+    sha256::Hash::hash(url.as_bytes()).to_string()
+  }
+
+  fn is_key(name: &str) -> bool {
+    name.len() == 64
+      && name
+        .bytes()
+        .all(|byte| byte.is_ascii_digit() || (b'a'..=b'f').contains(&byte))
+  }
+}
+
+fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(SystemTime::UNIX_EPOCH)
+    .map(|duration| duration.as_secs())
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, tempfile::TempDir};
+
+  fn entries(dir: &Path) -> usize {
+    fs::read_dir(dir)
+      .unwrap()
+      .filter(|entry| ProxyCache::is_key(entry.as_ref().unwrap().file_name().to_str().unwrap()))
+      .count()
+  }
+
+  fn response(body: &str) -> ProxyResponse {
+    ProxyResponse {
+      body: body.into(),
+      fetched: 0,
+      headers: vec![("content-type".into(), "text/plain".into())],
+      status: 200,
+    }
+  }
+
+  #[test]
+  fn round_trip() {
+    let tempdir = TempDir::new().unwrap();
+
+    let cache = ProxyCache::open(tempdir.path().into(), 1024, Duration::from_secs(60)).unwrap();
+
+    assert_eq!(cache.get("http://proxy/content/foo", true), None);
+
+    cache
+      .insert("http://proxy/content/foo", &response("foo\nbar"))
+      .unwrap();
+
+    assert_eq!(
+      cache.get("http://proxy/content/foo", true),
+      Some(response("foo\nbar")),
+    );
+
+    let cache = ProxyCache::open(tempdir.path().into(), 1024, Duration::from_secs(60)).unwrap();
+
+    assert_eq!(
+      cache.get("http://proxy/content/foo", true),
+      Some(response("foo\nbar")),
+    );
+  }
+
+  #[test]
+  fn least_recently_used_entries_are_evicted() {
+    let tempdir = TempDir::new().unwrap();
+
+    let size = {
+      let tempdir = TempDir::new().unwrap();
+      let path = tempdir.path().join("size");
+      response("a").save(&path).unwrap();
+      fs::metadata(path).unwrap().len()
+    };
+
+    let cache = ProxyCache::open(tempdir.path().into(), size * 2, Duration::from_secs(60)).unwrap();
+
+    cache.insert("a", &response("a")).unwrap();
+    cache.insert("b", &response("b")).unwrap();
+
+    assert!(cache.get("a", true).is_some());
+
+    cache.insert("c", &response("c")).unwrap();
+
+    assert!(cache.get("a", true).is_some());
+    assert!(cache.get("b", true).is_none());
+    assert!(cache.get("c", true).is_some());
+
+    assert_eq!(entries(tempdir.path()), 2);
+
+    let cache = ProxyCache::open(tempdir.path().into(), size, Duration::from_secs(60)).unwrap();
+
+    assert_eq!(entries(tempdir.path()), 1);
+    assert_eq!(cache.state.lock().unwrap().entries.len(), 1);
+  }
+
+  #[test]
+  fn oversized_responses_are_not_kept() {
+    let tempdir = TempDir::new().unwrap();
+
+    let cache = ProxyCache::open(tempdir.path().into(), 10, Duration::from_secs(60)).unwrap();
+
+    cache.insert("a", &response("a")).unwrap();
+
+    assert!(cache.get("a", true).is_none());
+    assert_eq!(entries(tempdir.path()), 0);
+  }
+
+  #[test]
+  fn mutable_responses_expire() {
+    let tempdir = TempDir::new().unwrap();
+
+    let cache = ProxyCache::open(tempdir.path().into(), 1024, Duration::from_secs(60)).unwrap();
+
+    let fresh = ProxyResponse {
+      fetched: now(),
+      ..response("{}")
+    };
+
+    cache.insert("http://proxy/r/children/foo", &fresh).unwrap();
+    cache
+      .insert("http://proxy/r/inscription/foo", &response("{}"))
+      .unwrap();
+    cache
+      .insert("http://proxy/r/metadata/foo", &response("{}"))
+      .unwrap();
+
+    assert_eq!(cache.get("http://proxy/r/children/foo", false), Some(fresh));
+    assert_eq!(cache.get("http://proxy/r/inscription/foo", false), None);
+    assert_eq!(
+      cache.get("http://proxy/r/metadata/foo", true),
+      Some(response("{}")),
+    );
+  }
+
+  #[test]
+  fn unrelated_files_are_not_touched() {
+    let tempdir = TempDir::new().unwrap();
+
+    fs::write(tempdir.path().join("foo"), "foo").unwrap();
+
+    assert_eq!(
+      ProxyCache::open(tempdir.path().into(), 0, Duration::from_secs(60))
+        .err()
+        .unwrap()
+        .to_string(),
+      format!(
+        "refusing to use non-empty directory `{}` as proxy cache dir",
+        tempdir.path().display()
+      ),
+    );
+
+    fs::remove_file(tempdir.path().join("foo")).unwrap();
+
+    let cache = ProxyCache::open(tempdir.path().into(), 1024, Duration::from_secs(60)).unwrap();
+
+    cache.insert("a", &response("a")).unwrap();
+
+    fs::write(tempdir.path().join("foo"), "foo").unwrap();
+    fs::write(tempdir.path().join("foo.tmp"), "foo").unwrap();
+
+    ProxyCache::open(tempdir.path().into(), 0, Duration::from_secs(60)).unwrap();
+
+    assert_eq!(entries(tempdir.path()), 0);
+    assert_eq!(
+      fs::read_to_string(tempdir.path().join("foo")).unwrap(),
+      "foo"
+    );
+    assert_eq!(
+      fs::read_to_string(tempdir.path().join("foo.tmp")).unwrap(),
+      "foo"
+    );
+  }
+}
@@ -6,9 +6,11 @@ pub(crate) struct ServerConfig {
   pub(crate) csp_origin: Option<String>,
   pub(crate) decompress: bool,
   pub(crate) domain: Option<String>,
+  pub(crate) index_runes: bool,
   pub(crate) index_sats: bool,
   pub(crate) json_api_enabled: bool,
   pub(crate) proxy: Option<Url>,
+  pub(crate) proxy_cache: Option<Arc<ProxyCache>>,
 }
 
 impl ServerConfig {
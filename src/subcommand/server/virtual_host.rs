@@ -0,0 +1,109 @@
+use {
+  super::*,
+  std::{
+    convert::Infallible,
+    task::{Context, Poll},
+  },
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct VirtualHost {
+  pub(crate) config: PathBuf,
+  pub(crate) host: String,
+}
+
+impl VirtualHost {
+  /// Load the virtual host's settings from its config file alone, since
+  /// `ORD_` environment variables are meant for the primary server and would
+  /// otherwise override the virtual host's chain, index, and RPC settings.
+  pub(crate) fn settings(&self) -> Result<Settings> {
+    Settings::merge(
+      Options {
+        config: Some(self.config.clone()),
+        ..default()
+      },
+      BTreeMap::new(),
+    )
+  }
+}
+
+impl FromStr for VirtualHost {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (host, config) = s
+      .split_once('=')
+      .ok_or_else(|| anyhow!("virtual host `{s}` must be of the form <HOST>=<CONFIG>"))?;
+
+    ensure!(!host.is_empty(), "virtual host `{s}` has empty host");
+    ensure!(!config.is_empty(), "virtual host `{s}` has empty config");
+
+    Ok(Self {
+      config: config.into(),
+      host: host.to_lowercase(),
+    })
+  }
+}
+
+#[derive(Clone)]
+pub(super) struct VirtualHosts {
+  pub(super) default: Router,
+  pub(super) hosts: BTreeMap<String, Router>,
+}
+
+impl Service<http::Request<body::Body>> for VirtualHosts {
+  type Response = Response;
+  type Error = Infallible;
+  type Future = <Router as Service<http::Request<body::Body>>>::Future;
+
+  fn poll_ready(&mut self, _: &mut Context) -> Poll<Result<(), Self::Error>> {
+    Poll::Ready(Ok(()))
+  }
+
+  fn call(&mut self, request: http::Request<body::Body>) -> Self::Future {
+    let host = request
+      .headers()
+      .get(header::HOST)
+      .and_then(|host| host.to_str().ok())
+      .or_else(|| request.uri().host())
+      .map(|host| host.split(':').next().unwrap_or_default().to_lowercase());
+
+    host
+      .and_then(|host| self.hosts.get_mut(&host))
+      .unwrap_or(&mut self.default)
+      .call(request)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn from_str() {
+    assert_eq!(
+      "Signet.Example.com=/etc/ord/signet.yaml"
+        .parse::<VirtualHost>()
+        .unwrap(),
+      VirtualHost {
+        config: "/etc/ord/signet.yaml".into(),
+        host: "signet.example.com".into(),
+      }
+    );
+
+    assert_eq!(
+      "foo".parse::<VirtualHost>().unwrap_err().to_string(),
+      "virtual host `foo` must be of the form <HOST>=<CONFIG>",
+    );
+
+    assert_eq!(
+      "=foo.yaml".parse::<VirtualHost>().unwrap_err().to_string(),
+      "virtual host `=foo.yaml` has empty host",
+    );
+
+    assert_eq!(
+      "foo=".parse::<VirtualHost>().unwrap_err().to_string(),
+      "virtual host `foo=` has empty config",
+    );
+  }
+}
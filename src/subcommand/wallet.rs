@@ -6,24 +6,34 @@ use {
 
 pub mod balance;
 mod batch_command;
+pub mod broadcast;
+pub mod bump;
 pub mod burn;
 pub mod cardinals;
+pub mod consolidate;
 pub mod create;
+pub mod create_tr;
 pub mod dump;
+pub mod extract_sats;
 pub mod inscribe;
 pub mod inscriptions;
-mod label;
+pub mod label;
 pub mod mint;
+pub mod offer;
 pub mod outputs;
 pub mod pending;
 pub mod receive;
 pub mod restore;
 pub mod resume;
+pub mod reveal;
 pub mod runics;
 pub mod sats;
 pub mod send;
 mod shared_args;
+pub mod sign;
+pub mod split;
 pub mod transactions;
+pub mod transfer;
 
 #[derive(Debug, Parser)]
 pub(crate) struct WalletCommand {
@@ -31,11 +41,21 @@ pub(crate) struct WalletCommand {
   pub(crate) name: String,
   #[arg(long, alias = "nosync", help = "Do not update index.")]
   pub(crate) no_sync: bool,
+  #[arg(
+    long,
+    help = "Do not spend outputs containing sats of <RARITY> or rarer as cardinal outputs. Sats cannot be protected by charm. Requires `ord server` with `--index-sats`."
+  )]
+  pub(crate) protect_rarity: Option<Rarity>,
   #[arg(
     long,
     help = "Use ord running at <SERVER_URL>. [default: http://localhost:80]"
   )]
   pub(crate) server_url: Option<Url>,
+  #[arg(
+    long,
+    help = "Allow spending outputs protected by `--protect-rarity` as cardinal outputs."
+  )]
+  pub(crate) spend_protected_sats: bool,
   #[command(subcommand)]
   pub(crate) subcommand: Subcommand,
 }
@@ -47,22 +67,37 @@ pub(crate) enum Subcommand {
   Balance,
   #[command(about = "Create inscriptions and runes")]
   Batch(batch_command::Batch),
-  #[command(about = "Burn an inscription")]
+  #[command(about = "Broadcast signed PSBTs")]
+  Broadcast(broadcast::Broadcast),
+  #[command(about = "Bump fee of unconfirmed transaction")]
+  Bump(bump::Bump),
+  #[command(about = "Burn an inscription or runes")]
   Burn(burn::Burn),
   #[command(about = "List unspent cardinal outputs in wallet")]
   Cardinals,
+  #[command(about = "Merge cardinal outputs into a single output")]
+  Consolidate(consolidate::Consolidate),
   #[command(about = "Create new wallet")]
   Create(create::Create),
+  #[command(about = "Derive taproot addresses from wallet descriptors")]
+  CreateTr(create_tr::CreateTr),
   #[command(about = "Dump wallet descriptors")]
   Dump,
+  #[command(about = "Move rare sats into their own outputs and lock them")]
+  ExtractSats(extract_sats::ExtractSats),
   #[command(about = "Create inscription")]
   Inscribe(inscribe::Inscribe),
   #[command(about = "List wallet inscriptions")]
   Inscriptions,
-  #[command(about = "Export output labels")]
-  Label,
+  #[command(about = "Export output labels, or import BIP-329 labels")]
+  Label(label::Label),
   #[command(about = "Mint a rune")]
   Mint(mint::Mint),
+  #[command(
+    subcommand,
+    about = "Create or accept offers to sell inscriptions and runes"
+  )]
+  Offer(offer::Offer),
   #[command(about = "List all unspent outputs in wallet")]
   Outputs(outputs::Outputs),
   #[command(about = "List pending etchings")]
@@ -73,14 +108,22 @@ pub(crate) enum Subcommand {
   Restore(restore::Restore),
   #[command(about = "Resume pending etchings")]
   Resume(resume::Resume),
+  #[command(about = "Reveal inscriptions committed to with `--commit-only`")]
+  Reveal(reveal::Reveal),
   #[command(about = "List unspent runic outputs in wallet")]
   Runics,
   #[command(about = "List wallet satoshis")]
   Sats(sats::Sats),
   #[command(about = "Send sat or inscription")]
   Send(send::Send),
+  #[command(about = "Sign PSBTs")]
+  Sign(sign::Sign),
+  #[command(about = "Send runes to many outputs listed in a split file")]
+  Split(split::Split),
   #[command(about = "See wallet transactions")]
   Transactions(transactions::Transactions),
+  #[command(about = "Send many inscriptions or sats in batched transactions")]
+  Transfer(transfer::Transfer),
 }
 
 impl WalletCommand {
@@ -94,6 +137,8 @@ impl WalletCommand {
     let wallet = WalletConstructor::construct(
       self.name.clone(),
       self.no_sync,
+      self.protect_rarity.or(settings.protect_rarity()),
+      self.spend_protected_sats,
       settings.clone(),
       self
         .server_url
@@ -108,22 +153,32 @@ impl WalletCommand {
     match self.subcommand {
       Subcommand::Balance => balance::run(wallet),
       Subcommand::Batch(batch) => batch.run(wallet),
+      Subcommand::Broadcast(broadcast) => broadcast.run(wallet),
+      Subcommand::Bump(bump) => bump.run(wallet),
       Subcommand::Burn(burn) => burn.run(wallet),
       Subcommand::Cardinals => cardinals::run(wallet),
+      Subcommand::Consolidate(consolidate) => consolidate.run(wallet),
       Subcommand::Create(_) | Subcommand::Restore(_) => unreachable!(),
+      Subcommand::CreateTr(create_tr) => create_tr.run(wallet),
       Subcommand::Dump => dump::run(wallet),
+      Subcommand::ExtractSats(extract_sats) => extract_sats.run(wallet),
       Subcommand::Inscribe(inscribe) => inscribe.run(wallet),
       Subcommand::Inscriptions => inscriptions::run(wallet),
-      Subcommand::Label => label::run(wallet),
+      Subcommand::Label(label) => label.run(wallet),
       Subcommand::Mint(mint) => mint.run(wallet),
+      Subcommand::Offer(offer) => offer.run(wallet),
       Subcommand::Outputs(outputs) => outputs.run(wallet),
       Subcommand::Pending(pending) => pending.run(wallet),
       Subcommand::Receive(receive) => receive.run(wallet),
       Subcommand::Resume(resume) => resume.run(wallet),
+      Subcommand::Reveal(reveal) => reveal.run(wallet),
       Subcommand::Runics => runics::run(wallet),
       Subcommand::Sats(sats) => sats.run(wallet),
       Subcommand::Send(send) => send.run(wallet),
+      Subcommand::Sign(sign) => sign.run(wallet),
+      Subcommand::Split(split) => split.run(wallet),
       Subcommand::Transactions(transactions) => transactions.run(wallet),
+      Subcommand::Transfer(transfer) => transfer.run(wallet),
     }
   }
 }
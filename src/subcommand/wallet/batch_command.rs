@@ -9,6 +9,12 @@ pub(crate) struct Batch {
     help = "Inscribe multiple inscriptions and rune defined in YAML <BATCH_FILE>."
   )]
   pub(crate) batch: PathBuf,
+  #[arg(
+    long,
+    conflicts_with_all = ["commit_only", "dry_run", "unsigned_psbt"],
+    help = "Estimate transaction sizes and fees without funding, signing, or broadcasting transactions."
+  )]
+  pub(crate) estimate: bool,
 }
 
 impl Batch {
@@ -41,8 +47,9 @@ impl Batch {
       Self::check_etching(&wallet, &etching)?;
     }
 
-    batch::Plan {
+    let plan = batch::Plan {
       commit_fee_rate: self.shared.commit_fee_rate.unwrap_or(self.shared.fee_rate),
+      commit_only: self.shared.commit_only,
       destinations,
       dry_run: self.shared.dry_run,
       etching: batchfile.etching,
@@ -55,14 +62,24 @@ impl Batch {
       reinscribe: batchfile.reinscribe,
       reveal_fee_rate: self.shared.fee_rate,
       reveal_satpoints,
+      satisfaction_weight: wallet.satisfaction_weight(),
       satpoint: if let Some(sat) = batchfile.sat {
         Some(wallet.find_sat_in_outputs(sat)?)
       } else {
         batchfile.satpoint
       },
+      unsigned_psbt: self.shared.unsigned_psbt,
+    };
+
+    if self.estimate {
+      return Ok(Some(Box::new(plan.estimate(&wallet)?)));
     }
-    .inscribe(
-      &locked_utxos.into_keys().collect(),
+
+    plan.inscribe(
+      &locked_utxos
+        .into_keys()
+        .chain(wallet.protected_outputs().iter().cloned())
+        .collect(),
       wallet.get_runic_outputs()?,
       utxos,
       &wallet,
@@ -0,0 +1,47 @@
+use {super::*, crate::wallet::offline};
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct Output {
+  pub txids: Vec<Txid>,
+}
+
+#[derive(Debug, Parser)]
+pub(crate) struct Broadcast {
+  #[arg(help = "Broadcast signed PSBTs in <PSBT_FILE>, in order.")]
+  psbt_file: PathBuf,
+}
+
+impl Broadcast {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    let psbts = offline::read_psbts(&self.psbt_file)?;
+
+    let mut transactions = Vec::new();
+
+    for (i, psbt) in psbts.iter().enumerate() {
+      wallet.check_psbt(psbt)?;
+
+      let transaction = wallet
+        .bitcoin_client()
+        .finalize_psbt(&offline::encode_psbt(psbt), None)?
+        .hex
+        .ok_or_else(|| {
+          anyhow!(
+            "PSBT {i} in `{}` is not fully signed",
+            self.psbt_file.display()
+          )
+        })?;
+
+      transactions.push(transaction);
+    }
+
+    let mut txids = Vec::new();
+
+    for transaction in transactions {
+      let txid = wallet.bitcoin_client().send_raw_transaction(&transaction)?;
+      wallet.clear_psbt_contents(txid)?;
+      txids.push(txid);
+    }
+
+    Ok(Some(Box::new(Output { txids })))
+  }
+}
@@ -0,0 +1,459 @@
+use {super::*, crate::wallet::offline, bitcoin::psbt::Psbt};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Bump {
+  #[arg(long, help = "Don't sign or broadcast transaction.")]
+  dry_run: bool,
+  #[arg(
+    long,
+    help = "Pay fee rate of <FEE_RATE> sats/vB, including the fee already paid by `--txid`."
+  )]
+  fee_rate: FeeRate,
+  #[arg(long, help = "Bump fee of unconfirmed transaction <TXID>.")]
+  txid: Txid,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct Output {
+  pub fee: u64,
+  pub method: Method,
+  pub psbt: String,
+  pub txid: Txid,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Copy, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub enum Method {
+  Cpfp,
+  Rbf,
+}
+
+/// Minimum fee rate increase required of replacement transactions
+const INCREMENTAL_RELAY_FEE_RATE: u64 = 1;
+
+impl Bump {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    if !self.dry_run {
+      wallet.check_private_keys()?;
+    }
+
+    let client = wallet.bitcoin_client();
+
+    // `getrawtransaction` only finds confirmed transactions with `-txindex`,
+    // so the transaction is looked up in the wallet
+    let result = client
+      .get_transaction(&self.txid, None)
+      .with_context(|| format!("transaction {} not found", self.txid))?;
+
+    ensure!(
+      result.info.confirmations <= 0,
+      "transaction {} is already confirmed",
+      self.txid,
+    );
+
+    let transaction = result.transaction()?;
+
+    let prevouts = transaction
+      .input
+      .iter()
+      .map(|input| Self::prevout(&wallet, input.previous_output))
+      .collect::<Result<Vec<TxOut>>>()?;
+
+    let fee = prevouts.iter().map(|prevout| prevout.value).sum::<Amount>()
+      - transaction
+        .output
+        .iter()
+        .map(|output| output.value)
+        .sum::<Amount>();
+
+    let vsize = transaction.vsize();
+
+    ensure!(
+      fee < self.fee_rate.fee(vsize),
+      "transaction {} already pays fee rate of at least {} sats/vB",
+      self.txid,
+      self.fee_rate.n(),
+    );
+
+    let occupied = Self::occupied_outputs(&wallet, &transaction, &prevouts)?;
+
+    // replacing a transaction evicts its descendants, such as the pre-signed
+    // reveal of an inscription commit, which cannot be signed again, so
+    // transactions with descendants are only bumped with a child, and
+    // replacements must pay for the fees of every transaction they evict
+    let (descendants, descendant_fee) = match client.get_mempool_entry(&self.txid) {
+      Ok(entry) => (entry.descendant_count > 1, entry.fees.descendant),
+      // transactions which are not in the mempool have no descendants there
+      Err(bitcoincore_rpc::Error::JsonRpc(bitcoincore_rpc::jsonrpc::Error::Rpc(
+        bitcoincore_rpc::jsonrpc::error::RpcError { code: -5, .. },
+      ))) => (false, fee),
+      Err(err) => return Err(err.into()),
+    };
+
+    let replacement = if descendants {
+      None
+    } else {
+      self.replacement(
+        &wallet,
+        &transaction,
+        &prevouts,
+        &occupied,
+        fee,
+        descendant_fee,
+      )?
+    };
+
+    if let Some((replacement, replacement_fee)) = replacement {
+      let (txid, psbt) = self.sign(&wallet, replacement)?;
+
+      return Ok(Some(Box::new(Output {
+        fee: replacement_fee.to_sat(),
+        method: Method::Rbf,
+        psbt,
+        txid,
+      })));
+    }
+
+    let (child, child_fee) = self.child(&wallet, &transaction, &occupied, fee)?;
+
+    let (txid, psbt) = self.sign(&wallet, child)?;
+
+    Ok(Some(Box::new(Output {
+      fee: child_fee.to_sat(),
+      method: Method::Cpfp,
+      psbt,
+      txid,
+    })))
+  }
+
+  fn prevout(wallet: &Wallet, outpoint: OutPoint) -> Result<TxOut> {
+    wallet
+      .get_tx_out(outpoint)?
+      .ok_or_else(|| anyhow!("input {outpoint} not found"))
+  }
+
+  /// Outputs of `transaction` which may receive inscriptions or runes. Sats
+  /// of inputs which have not been indexed, such as outputs of unconfirmed
+  /// transactions, may contain inscriptions, so outputs receiving them are
+  /// also included.
+  fn occupied_outputs(
+    wallet: &Wallet,
+    transaction: &Transaction,
+    prevouts: &[TxOut],
+  ) -> Result<BTreeSet<usize>> {
+    let mut ranges = Vec::new();
+    let mut runic = false;
+    let mut start = 0;
+
+    for (input, prevout) in transaction.input.iter().zip(prevouts) {
+      let value = prevout.value.to_sat();
+
+      match wallet.get_output_info(input.previous_output)? {
+        Some(info) => {
+          for inscription_id in info.inscriptions {
+            let offset = wallet.get_inscription(inscription_id)?.satpoint.offset;
+            ranges.push((start + offset, start + offset + 1));
+          }
+
+          runic |= !info.runes.is_empty();
+        }
+        None => {
+          ranges.push((start, start + value));
+          runic = true;
+        }
+      }
+
+      start += value;
+    }
+
+    let total = transaction
+      .output
+      .iter()
+      .map(|output| output.value.to_sat())
+      .sum::<u64>();
+
+    for envelope in ParsedEnvelope::from_transaction(transaction) {
+      let offset = envelope
+        .payload
+        .pointer()
+        .filter(|pointer| *pointer < total)
+        .unwrap_or_else(|| {
+          prevouts[..envelope.input.into_usize()]
+            .iter()
+            .map(|prevout| prevout.value.to_sat())
+            .sum()
+        });
+
+      ranges.push((offset, offset + 1));
+    }
+
+    let mut occupied = BTreeSet::new();
+    let mut start = 0;
+
+    for (vout, output) in transaction.output.iter().enumerate() {
+      let end = start + output.value.to_sat();
+
+      if ranges
+        .iter()
+        .any(|(range_start, range_end)| *range_start < end && start < *range_end)
+      {
+        occupied.insert(vout);
+      }
+
+      start = end;
+    }
+
+    let first_non_op_return = transaction
+      .output
+      .iter()
+      .position(|output| !output.script_pubkey.is_op_return());
+
+    match Runestone::decipher(transaction) {
+      Some(Artifact::Runestone(runestone)) => {
+        for edict in runestone.edicts {
+          let output = edict.output.into_usize();
+
+          if output == transaction.output.len() {
+            occupied.extend(
+              transaction
+                .output
+                .iter()
+                .enumerate()
+                .filter(|(_vout, output)| !output.script_pubkey.is_op_return())
+                .map(|(vout, _output)| vout),
+            );
+          } else {
+            occupied.insert(output);
+          }
+        }
+
+        occupied.extend(
+          runestone
+            .pointer
+            .map(|pointer| pointer.into_usize())
+            .or(first_non_op_return),
+        );
+      }
+      Some(Artifact::Cenotaph(_)) => {}
+      None => {
+        if runic {
+          occupied.extend(first_non_op_return);
+        }
+      }
+    }
+
+    Ok(occupied)
+  }
+
+  /// Build a transaction replacing `transaction` which pays the additional
+  /// fee from its last output, which must be unoccupied change, leaving the
+  /// sats in all other outputs unchanged. The replacement pays at least
+  /// `descendant_fee`, the fees of `transaction` and its descendants. Returns
+  /// `None` if `transaction` cannot be replaced.
+  fn replacement(
+    &self,
+    wallet: &Wallet,
+    transaction: &Transaction,
+    prevouts: &[TxOut],
+    occupied: &BTreeSet<usize>,
+    fee: Amount,
+    descendant_fee: Amount,
+  ) -> Result<Option<(Transaction, Amount)>> {
+    // reveal transactions spend commit outputs with keys the wallet does not
+    // hold, and so cannot be re-signed
+    if !transaction.is_explicitly_rbf() || !ParsedEnvelope::from_transaction(transaction).is_empty()
+    {
+      return Ok(None);
+    }
+
+    for prevout in prevouts {
+      if !wallet.is_mine(&prevout.script_pubkey)? {
+        return Ok(None);
+      }
+    }
+
+    let last = transaction.output.len() - 1;
+    let change = &transaction.output[last];
+
+    if occupied.contains(&last) || !wallet.is_mine(&change.script_pubkey)? {
+      return Ok(None);
+    }
+
+    let vsize = transaction.vsize();
+
+    let replacement_fee = self
+      .fee_rate
+      .fee(vsize)
+      .max(descendant_fee + Self::incremental_relay_fee(vsize));
+
+    let Some(value) = change
+      .value
+      .checked_sub(replacement_fee - fee)
+      .filter(|value| *value >= change.script_pubkey.minimal_non_dust())
+    else {
+      return Ok(None);
+    };
+
+    let mut replacement = transaction.clone();
+
+    replacement.output[last].value = value;
+
+    for input in &mut replacement.input {
+      input.script_sig = ScriptBuf::new();
+      input.witness = Witness::new();
+    }
+
+    Ok(Some((replacement, replacement_fee)))
+  }
+
+  /// Build a child transaction spending an output of `transaction` which
+  /// raises the fee rate of both to `--fee-rate`. If the spent output is
+  /// occupied, it is recreated with the same value as the first output of
+  /// the child, so inscriptions and runes it contains stay at the same
+  /// offsets.
+  fn child(
+    &self,
+    wallet: &Wallet,
+    transaction: &Transaction,
+    occupied: &BTreeSet<usize>,
+    fee: Amount,
+  ) -> Result<(Transaction, Amount)> {
+    let mut owned = Vec::new();
+
+    for (vout, output) in transaction.output.iter().enumerate() {
+      if !output.script_pubkey.is_op_return() && wallet.is_mine(&output.script_pubkey)? {
+        owned.push(vout);
+      }
+    }
+
+    let vout = owned
+      .iter()
+      .find(|vout| !occupied.contains(vout))
+      .or(owned.first())
+      .copied()
+      .ok_or_else(|| {
+        anyhow!(
+          "transaction {} cannot be replaced and has no outputs which can be spent by a child transaction",
+          self.txid,
+        )
+      })?;
+
+    let parent_output = transaction.output[vout].clone();
+
+    let mut outputs = Vec::new();
+
+    if occupied.contains(&vout) {
+      outputs.push(TxOut {
+        script_pubkey: wallet.get_change_address()?.script_pubkey(),
+        value: parent_output.value,
+      });
+    }
+
+    let change = wallet.get_change_address()?.script_pubkey();
+
+    outputs.push(TxOut {
+      script_pubkey: change.clone(),
+      value: Amount::ZERO,
+    });
+
+    let witness = TransactionBuilder::dummy_witness(wallet.satisfaction_weight());
+
+    let input = |previous_output| TxIn {
+      previous_output,
+      script_sig: ScriptBuf::new(),
+      sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+      witness: witness.clone(),
+    };
+
+    let mut child = Transaction {
+      version: Version(2),
+      lock_time: LockTime::ZERO,
+      input: vec![input(OutPoint {
+        txid: self.txid,
+        vout: vout.try_into().unwrap(),
+      })],
+      output: outputs,
+    };
+
+    let inscribed_outputs = wallet
+      .inscriptions()
+      .keys()
+      .map(|satpoint| satpoint.outpoint)
+      .collect::<HashSet<OutPoint>>();
+
+    let runic_outputs = wallet.get_runic_outputs()?;
+
+    let unspendable = wallet.unspendable_outputs();
+
+    // spend the largest cardinal outputs first, to keep the child small
+    let mut cardinals = wallet
+      .utxos()
+      .iter()
+      .filter(|(output, _tx_out)| {
+        !inscribed_outputs.contains(output)
+          && !runic_outputs.contains(output)
+          && !unspendable.contains(output)
+      })
+      .map(|(output, tx_out)| (tx_out.value, *output))
+      .collect::<Vec<(Amount, OutPoint)>>();
+
+    cardinals.sort();
+
+    let mut input_value = parent_output.value;
+
+    let fixed = child.output[..child.output.len() - 1]
+      .iter()
+      .map(|output| output.value)
+      .sum::<Amount>();
+
+    loop {
+      let child_vsize = child.vsize();
+
+      let child_fee = self
+        .fee_rate
+        .fee(transaction.vsize() + child_vsize)
+        .checked_sub(fee)
+        .unwrap_or_default()
+        .max(Self::incremental_relay_fee(child_vsize));
+
+      if let Some(value) = input_value
+        .checked_sub(fixed + child_fee)
+        .filter(|value| *value >= change.minimal_non_dust())
+      {
+        child.output.last_mut().unwrap().value = value;
+
+        for input in &mut child.input {
+          input.witness = Witness::new();
+        }
+
+        return Ok((child, child_fee));
+      }
+
+      let (value, output) = cardinals.pop().ok_or_else(|| {
+        anyhow!("wallet does not contain enough cardinal outputs to pay for child transaction")
+      })?;
+
+      child.input.push(input(output));
+
+      input_value += value;
+    }
+  }
+
+  fn incremental_relay_fee(vsize: usize) -> Amount {
+    Amount::from_sat(INCREMENTAL_RELAY_FEE_RATE * u64::try_from(vsize).unwrap())
+  }
+
+  fn sign(&self, wallet: &Wallet, transaction: Transaction) -> Result<(Txid, String)> {
+    let txid = transaction.compute_txid();
+
+    let psbt = Psbt::from_unsigned_tx(transaction)?;
+
+    let psbt = if self.dry_run {
+      offline::encode_psbt(&psbt)
+    } else {
+      wallet.sign_and_broadcast(&psbt)?.1
+    };
+
+    Ok((txid, psbt))
+  }
+}
@@ -8,19 +8,42 @@ pub struct Burn {
   fee_rate: FeeRate,
   #[arg(
     long,
-    help = "Target <AMOUNT> postage with sent inscriptions. [default: 10000 sat]",
+    help = "Target <AMOUNT> postage with sent inscriptions or runic change. [default: 10000 sat]",
     value_name = "AMOUNT"
   )]
   postage: Option<Amount>,
-  inscription: InscriptionId,
+  #[arg(
+    long,
+    value_name = "FILE",
+    conflicts_with = "dry_run",
+    help = "Write unsigned PSBT to <FILE> instead of signing and broadcasting transaction. Sign with `ord wallet sign` and broadcast with `ord wallet broadcast`."
+  )]
+  unsigned_psbt: Option<PathBuf>,
+  #[arg(
+    long,
+    requires = "amount",
+    conflicts_with = "inscription",
+    help = "Burn <RUNE>."
+  )]
+  rune: Option<SpacedRune>,
+  #[arg(long, requires = "rune", help = "Burn <AMOUNT> of <RUNE>.")]
+  amount: Option<Decimal>,
+  #[arg(required_unless_present = "rune")]
+  inscription: Option<InscriptionId>,
 }
 
 impl Burn {
   pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    if let Some((rune, decimal)) = self.rune.zip(self.amount) {
+      return self.burn_rune(wallet, rune, decimal);
+    }
+
+    let inscription = self.inscription.unwrap();
+
     let inscription_info = wallet
       .inscription_info()
-      .get(&self.inscription)
-      .ok_or_else(|| anyhow!("inscription {} not found", self.inscription))?
+      .get(&inscription)
+      .ok_or_else(|| anyhow!("inscription {inscription} not found"))?
       .clone();
 
     let Some(value) = inscription_info.value else {
@@ -44,16 +67,173 @@ impl Burn {
       self.fee_rate,
     )?;
 
-    let (txid, psbt, fee) = wallet.sign_transaction(unsigned_transaction, self.dry_run)?;
+    let (txid, psbt, fee) = wallet.sign_transaction(
+      unsigned_transaction,
+      self.dry_run,
+      self.unsigned_psbt.as_deref(),
+    )?;
+
+    Ok(Some(Box::new(send::Output {
+      txid,
+      psbt,
+      outgoing: Outgoing::InscriptionId(inscription),
+      fee,
+    })))
+  }
+
+  fn burn_rune(
+    self,
+    wallet: Wallet,
+    spaced_rune: SpacedRune,
+    decimal: Decimal,
+  ) -> SubcommandResult {
+    ensure!(
+      wallet.has_rune_index(),
+      "burning runes with `ord wallet burn` requires index created with `--index-runes` flag",
+    );
+
+    let unsigned_transaction = Self::create_unsigned_burn_runes_transaction(
+      &wallet,
+      spaced_rune,
+      decimal,
+      self.postage.unwrap_or(TARGET_POSTAGE),
+      self.fee_rate,
+    )?;
+
+    let (txid, psbt, fee) = wallet.sign_transaction(
+      unsigned_transaction,
+      self.dry_run,
+      self.unsigned_psbt.as_deref(),
+    )?;
 
     Ok(Some(Box::new(send::Output {
       txid,
       psbt,
-      outgoing: Outgoing::InscriptionId(self.inscription),
+      outgoing: Outgoing::Rune {
+        decimal,
+        rune: spaced_rune,
+      },
       fee,
     })))
   }
 
+  /// Burn runes with an edict to the runestone's own OP_RETURN output. Every
+  /// other rune in the inputs, and any of `spaced_rune` not burned, goes to a
+  /// runic change output via the runestone's pointer.
+  fn create_unsigned_burn_runes_transaction(
+    wallet: &Wallet,
+    spaced_rune: SpacedRune,
+    decimal: Decimal,
+    postage: Amount,
+    fee_rate: FeeRate,
+  ) -> Result<Transaction> {
+    wallet.lock_non_cardinal_outputs()?;
+
+    let (id, entry, _parent) = wallet
+      .get_rune(spaced_rune.rune)?
+      .with_context(|| format!("rune `{}` has not been etched", spaced_rune.rune))?;
+
+    let amount = decimal.to_integer(entry.divisibility)?;
+
+    ensure!(amount > 0, "burn amount must be greater than zero");
+
+    let inscribed_outputs = wallet
+      .inscriptions()
+      .keys()
+      .map(|satpoint| satpoint.outpoint)
+      .collect::<HashSet<OutPoint>>();
+
+    let mut inputs = Vec::new();
+    let mut input_balance = 0;
+    let mut other_runes = false;
+
+    for output in wallet.get_runic_outputs()? {
+      if inscribed_outputs.contains(&output) {
+        continue;
+      }
+
+      let balances = wallet.get_runes_balances_in_output(&output)?;
+
+      let Some(pile) = balances
+        .iter()
+        .find(|(rune, _pile)| rune.rune == spaced_rune.rune)
+        .map(|(_rune, pile)| pile)
+      else {
+        continue;
+      };
+
+      input_balance += pile.amount;
+      other_runes |= balances.len() > 1;
+      inputs.push(output);
+
+      if input_balance >= amount {
+        break;
+      }
+    }
+
+    ensure! {
+      input_balance >= amount,
+      "insufficient `{}` balance, only {} in wallet",
+      spaced_rune,
+      Pile {
+        amount: input_balance,
+        divisibility: entry.divisibility,
+        symbol: entry.symbol
+      },
+    }
+
+    let needs_runes_change_output = input_balance > amount || other_runes;
+
+    let runestone = Runestone {
+      edicts: vec![Edict {
+        amount,
+        id,
+        output: 0,
+      }],
+      pointer: needs_runes_change_output.then_some(1),
+      ..default()
+    };
+
+    let mut output = vec![TxOut {
+      script_pubkey: runestone.encipher(),
+      value: Amount::from_sat(0),
+    }];
+
+    if needs_runes_change_output {
+      output.push(TxOut {
+        script_pubkey: wallet.get_change_address()?.script_pubkey(),
+        value: postage,
+      });
+    }
+
+    let unfunded_transaction = Transaction {
+      version: Version(2),
+      lock_time: LockTime::ZERO,
+      input: inputs
+        .into_iter()
+        .map(|previous_output| TxIn {
+          previous_output,
+          script_sig: ScriptBuf::new(),
+          sequence: Sequence::MAX,
+          witness: Witness::new(),
+        })
+        .collect(),
+      output,
+    };
+
+    let unsigned_transaction =
+      fund_raw_transaction(wallet.bitcoin_client(), fee_rate, &unfunded_transaction)?;
+
+    let unsigned_transaction: Transaction = consensus::encode::deserialize(&unsigned_transaction)?;
+
+    ensure!(
+      Runestone::decipher(&unsigned_transaction) == Some(Artifact::Runestone(runestone)),
+      "burn transaction runestone did not decipher as expected",
+    );
+
+    Ok(unsigned_transaction)
+  }
+
   fn create_unsigned_burn_transaction(
     wallet: &Wallet,
     satpoint: SatPoint,
@@ -80,7 +260,7 @@ impl Burn {
         satpoint,
         wallet.inscriptions().clone(),
         wallet.utxos().clone(),
-        wallet.locked_utxos().clone().into_keys().collect(),
+        wallet.unspendable_outputs(),
         runic_outputs,
         script_pubkey,
         change,
@@ -88,6 +268,7 @@ impl Burn {
         postage,
         wallet.chain().network(),
       )
+      .satisfaction_weight(wallet.satisfaction_weight())
       .build_transaction()?,
     )
   }
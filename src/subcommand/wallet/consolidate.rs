@@ -0,0 +1,246 @@
+use {super::*, bitcoin::policy::MAX_STANDARD_TX_WEIGHT};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Consolidate {
+  #[arg(long, help = "Don't sign or broadcast transaction.")]
+  dry_run: bool,
+  #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vB.")]
+  fee_rate: FeeRate,
+  #[arg(
+    long,
+    help = "Spend at most <MAX_INPUTS> outputs. [default: as many as fit in a standard transaction]",
+    value_name = "MAX_INPUTS"
+  )]
+  max_inputs: Option<usize>,
+  #[arg(
+    long,
+    help = "Include <AMOUNT> postage with consolidated rune outputs. [default: 10000 sat]",
+    value_name = "AMOUNT"
+  )]
+  postage: Option<Amount>,
+  #[arg(
+    long,
+    help = "Also consolidate balances of each rune held in more than one output into a single output."
+  )]
+  runes: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct Output {
+  pub fee: u64,
+  pub inputs: usize,
+  pub psbt: String,
+  pub runes: BTreeSet<SpacedRune>,
+  pub txid: Txid,
+}
+
+impl Consolidate {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    ensure!(
+      !self.runes || wallet.has_rune_index(),
+      "consolidating runes requires index created with `--index-runes` flag",
+    );
+
+    if let Some(max_inputs) = self.max_inputs {
+      ensure!(max_inputs >= 2, "`--max-inputs` must be at least 2");
+    }
+
+    let max_inputs = self.max_inputs.unwrap_or(usize::MAX);
+
+    let postage = self.postage.unwrap_or(TARGET_POSTAGE);
+
+    let witness = TransactionBuilder::dummy_witness(wallet.satisfaction_weight());
+
+    let inscribed_outputs = wallet
+      .inscriptions()
+      .keys()
+      .map(|satpoint| satpoint.outpoint)
+      .collect::<HashSet<OutPoint>>();
+
+    let runic_outputs = wallet.get_runic_outputs()?;
+
+    // outputs containing rare sats are never consolidated, even without
+    // `--protect-rarity`, so that rare sats are not merged into larger outputs
+    let mut unspendable = wallet.unspendable_outputs();
+    unspendable.extend(wallet.rare_outputs());
+
+    let mut inputs = Vec::new();
+    let mut runes = BTreeSet::new();
+
+    if self.runes {
+      let mut holdings = Vec::new();
+      let mut counts = BTreeMap::<SpacedRune, usize>::new();
+
+      for output in &runic_outputs {
+        if inscribed_outputs.contains(output) || unspendable.contains(output) {
+          continue;
+        }
+
+        let balances = wallet.get_runes_balances_in_output(output)?;
+
+        for spaced_rune in balances.keys() {
+          *counts.entry(*spaced_rune).or_default() += 1;
+        }
+
+        holdings.push((*output, balances));
+      }
+
+      // every rune in a spent output is assigned by an edict, including runes
+      // held in only one output
+      for (output, balances) in holdings {
+        if inputs.len() < max_inputs && balances.keys().any(|spaced_rune| counts[spaced_rune] > 1) {
+          inputs.push(output);
+          runes.extend(balances.into_keys());
+        }
+      }
+    }
+
+    let mut rune_ids = BTreeMap::new();
+
+    for spaced_rune in runes {
+      let (id, _entry, _parent) = wallet
+        .get_rune(spaced_rune.rune)?
+        .with_context(|| format!("rune `{spaced_rune}` has not been etched"))?;
+
+      rune_ids.insert(id, spaced_rune);
+    }
+
+    let runestone = Runestone {
+      edicts: rune_ids
+        .keys()
+        .enumerate()
+        .map(|(i, id)| Edict {
+          amount: 0,
+          id: *id,
+          output: (i + 1).try_into().unwrap(),
+        })
+        .collect(),
+      ..default()
+    };
+
+    let mut outputs = Vec::new();
+
+    if !rune_ids.is_empty() {
+      let script_pubkey = runestone.encipher();
+
+      ensure!(
+        script_pubkey.len() <= 82,
+        "runestone greater than maximum OP_RETURN size: {} > 82",
+        script_pubkey.len(),
+      );
+
+      outputs.push(TxOut {
+        script_pubkey,
+        value: Amount::ZERO,
+      });
+
+      for _ in &rune_ids {
+        outputs.push(TxOut {
+          script_pubkey: wallet.get_change_address()?.script_pubkey(),
+          value: postage,
+        });
+      }
+    }
+
+    let destination = wallet.get_change_address()?.script_pubkey();
+
+    outputs.push(TxOut {
+      script_pubkey: destination.clone(),
+      value: Amount::ZERO,
+    });
+
+    let mut transaction = Transaction {
+      version: Version(2),
+      lock_time: LockTime::ZERO,
+      input: Vec::new(),
+      output: outputs,
+    };
+
+    let input = |previous_output| TxIn {
+      previous_output,
+      script_sig: ScriptBuf::new(),
+      sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+      witness: witness.clone(),
+    };
+
+    transaction.input = inputs.iter().cloned().map(input).collect();
+
+    let input_fee = {
+      let mut estimate = transaction.clone();
+      let before = estimate.vsize();
+      estimate.input.push(input(OutPoint::null()));
+      self.fee_rate.fee(estimate.vsize() - before)
+    };
+
+    // spend the smallest cardinal outputs first, skipping those which are not
+    // worth the fee needed to spend them
+    let mut cardinals = wallet
+      .utxos()
+      .iter()
+      .filter(|(output, tx_out)| {
+        !inscribed_outputs.contains(output)
+          && !runic_outputs.contains(output)
+          && !unspendable.contains(output)
+          && tx_out.value > input_fee
+      })
+      .map(|(output, tx_out)| (tx_out.value, *output))
+      .collect::<Vec<(Amount, OutPoint)>>();
+
+    cardinals.sort();
+
+    for (_value, output) in cardinals {
+      if inputs.len() >= max_inputs {
+        break;
+      }
+
+      transaction.input.push(input(output));
+
+      if transaction.weight().to_wu() > MAX_STANDARD_TX_WEIGHT.into() {
+        transaction.input.pop();
+        break;
+      }
+
+      inputs.push(output);
+    }
+
+    ensure!(
+      inputs.len() >= 2,
+      "wallet does not contain enough outputs to consolidate",
+    );
+
+    let fee = self.fee_rate.fee(transaction.vsize());
+
+    let total = inputs
+      .iter()
+      .map(|output| wallet.utxos()[output].value)
+      .sum::<Amount>();
+
+    let value = total
+      .checked_sub(postage * u64::try_from(rune_ids.len()).unwrap() + fee)
+      .filter(|value| *value >= destination.minimal_non_dust())
+      .ok_or_else(|| anyhow!("consolidated output value would be below dust limit"))?;
+
+    transaction.output.last_mut().unwrap().value = value;
+
+    for input in &mut transaction.input {
+      input.witness = Witness::new();
+    }
+
+    if !rune_ids.is_empty() {
+      assert_eq!(
+        Runestone::decipher(&transaction),
+        Some(Artifact::Runestone(runestone)),
+      );
+    }
+
+    let (txid, psbt, fee) = wallet.sign_transaction(transaction, self.dry_run, None)?;
+
+    Ok(Some(Box::new(Output {
+      fee,
+      inputs: inputs.len(),
+      psbt,
+      runes: rune_ids.into_values().collect(),
+      txid,
+    })))
+  }
+}
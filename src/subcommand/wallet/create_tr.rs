@@ -1,38 +1,149 @@
-use super::*;
+use {
+  super::*,
+  bitcoin::{
+    bip32::Fingerprint,
+    secp256k1::{All, Secp256k1, XOnlyPublicKey},
+    taproot::{LeafVersion, TapNodeHash, TaprootBuilder},
+  },
+  miniscript::{descriptor::DescriptorPublicKey, Miniscript, Tap},
+};
 
-#[derive(Deserialize, Serialize)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 pub struct Output {
-    pub addresses: Vec<Address<NetworkUnchecked>>,
+  pub addresses: Vec<TaprootAddress>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct TaprootAddress {
+  pub address: Address<NetworkUnchecked>,
+  pub derivation_path: String,
+  pub fingerprint: Fingerprint,
+  pub index: u32,
+  pub internal_key: XOnlyPublicKey,
+  pub script_tree: Option<ScriptTree>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct ScriptTree {
+  pub control_block: String,
+  pub leaf_version: u8,
+  pub merkle_root: TapNodeHash,
+  pub script: ScriptBuf,
 }
 
 #[derive(Debug, Parser)]
-pub(crate) struct CreateTR {
-    #[arg(short, long, help = "Create TapRootAddress <NUMBER>.")]
-    number: Option<u64>,
+pub(crate) struct CreateTr {
+  #[arg(long, help = "Derive change addresses.")]
+  change: bool,
+  #[arg(short, long, default_value = "1", help = "Derive <NUMBER> addresses.")]
+  number: u32,
+  #[arg(
+    long,
+    default_value = "0",
+    help = "Derive addresses starting at index <START>."
+  )]
+  start: u32,
+  #[arg(
+    long,
+    value_name = "MINISCRIPT",
+    help = "Commit addresses to a single tapscript leaf <MINISCRIPT> with x-only keys, e.g. `and_v(v:pk(<KEY>),older(144))`."
+  )]
+  tapscript: Option<Miniscript<XOnlyPublicKey, Tap>>,
 }
 
-impl CreateTR {
-    pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
-        let mut addresses: Vec<Address<NetworkUnchecked>> = Vec::new();
-        println!("[createTR.rs] Create TapRoot Address");
-        
-        // let number_of_addresses = self.number.unwrap_or(1);
-        // println!("[createTR.rs] Number of addresses to generate: {}", number_of_addresses);
-        // 
-        // for i in 0..number_of_addresses {
-        //     println!("[receive.rs] Generating address {} of {}", i + 1, number_of_addresses);
-        //     let new_address = wallet
-        //         .bitcoin_client()
-        //         .get_new_address(None, Some(bitcoincore_rpc::json::AddressType::Bech32m))?;
-        //     println!("[receive.rs] Generated address: {:?}", new_address);
-        //     addresses.push(new_address);
-        // }
-        // 
-        // println!("[receive.rs] All generated addresses: {:?}", addresses);
-        
-        Ok(Some(Box::new(Output { addresses })))
+impl CreateTr {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    let secp = Secp256k1::new();
+
+    let key = Self::internal_key(&wallet, &secp, self.change)?;
+
+    let end = self
+      .start
+      .checked_add(self.number)
+      .ok_or_else(|| anyhow!("address index overflow"))?;
+
+    let network = wallet.chain().network();
+
+    let mut addresses = Vec::new();
+
+    for index in self.start..end {
+      let key = key.clone().at_derivation_index(index)?;
+
+      let internal_key = XOnlyPublicKey::from(key.derive_public_key(&secp)?.inner);
+
+      let (address, script_tree) = match &self.tapscript {
+        Some(tapscript) => {
+          let script = tapscript.encode();
+
+          let spend_info = TaprootBuilder::new()
+            .add_leaf(0, script.clone())?
+            .finalize(&secp, internal_key)
+            .map_err(|_| anyhow!("failed to build taproot script tree"))?;
+
+          let control_block = spend_info
+            .control_block(&(script.clone(), LeafVersion::TapScript))
+            .unwrap();
+
+          (
+            Address::p2tr_tweaked(spend_info.output_key(), network),
+            Some(ScriptTree {
+              control_block: hex::encode(control_block.serialize()),
+              leaf_version: LeafVersion::TapScript.to_consensus(),
+              merkle_root: spend_info.merkle_root().unwrap(),
+              script,
+            }),
+          )
+        }
+        None => (Address::p2tr(&secp, internal_key, None, network), None),
+      };
+
+      addresses.push(TaprootAddress {
+        address: address.as_unchecked().clone(),
+        derivation_path: format!("m/{}", key.full_derivation_path().unwrap_or_default()),
+        fingerprint: key.master_fingerprint(),
+        index,
+        internal_key,
+        script_tree,
+      });
     }
-}
 
+    Ok(Some(Box::new(Output { addresses })))
+  }
+
+  /// Addresses are derived from the internal key of the wallet's active key
+  /// path only `tr` descriptor, without asking Bitcoin Core, so its keypool is
+  /// not advanced.
+  fn internal_key(
+    wallet: &Wallet,
+    secp: &Secp256k1<All>,
+    change: bool,
+  ) -> Result<DescriptorPublicKey> {
+    let descriptors = wallet
+      .bitcoin_client()
+      .call::<ListDescriptorsResult>("listdescriptors", &[serde_json::Value::Null])?
+      .descriptors;
 
+    for descriptor in descriptors {
+      if !descriptor.active || descriptor.internal.unwrap_or_default() != change {
+        continue;
+      }
 
+      let (miniscript::Descriptor::Tr(tr), _) =
+        miniscript::Descriptor::parse_descriptor(secp, &descriptor.desc)?
+      else {
+        continue;
+      };
+
+      if tr.tap_tree().is_some() || !tr.internal_key().has_wildcard() {
+        continue;
+      }
+
+      return Ok(tr.internal_key().clone());
+    }
+
+    bail!(
+      "wallet has no active ranged key path `tr` {} descriptor",
+      if change { "change" } else { "receive" }
+    )
+  }
+}
@@ -0,0 +1,266 @@
+use {super::*, sats::Sats};
+
+#[derive(Debug, Parser)]
+pub(crate) struct ExtractSats {
+  #[arg(long, help = "Don't sign or broadcast transaction.")]
+  pub(crate) dry_run: bool,
+  #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vB.")]
+  fee_rate: FeeRate,
+  #[arg(
+    long,
+    help = "Extract each sat into an output of <AMOUNT>. [default: 10000 sat]",
+    value_name = "AMOUNT"
+  )]
+  postage: Option<Amount>,
+  #[arg(
+    long,
+    default_value = "uncommon",
+    conflicts_with = "tsv",
+    help = "Extract sats of <RARITY> or rarer. Sats cannot be selected by charm, use `--tsv` to extract sats with a particular charm."
+  )]
+  rarity: Rarity,
+  #[arg(
+    long,
+    help = "Extract satoshis listed in first column of tab-separated value file <TSV>."
+  )]
+  tsv: Option<PathBuf>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct Output {
+  pub extracted: Vec<ExtractedSat>,
+  pub fee: u64,
+  pub psbt: String,
+  pub txid: Txid,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct ExtractedSat {
+  pub output: OutPoint,
+  pub rarity: Rarity,
+  pub sat: Sat,
+}
+
+impl ExtractSats {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    ensure!(
+      wallet.has_sat_index(),
+      "extracting sats requires index created with `--index-sats` flag"
+    );
+
+    let postage = self.postage.unwrap_or(TARGET_POSTAGE);
+
+    let change = wallet.get_change_address()?.script_pubkey();
+
+    let dust_limit = change.minimal_non_dust();
+
+    ensure!(
+      postage >= dust_limit,
+      "postage of {postage} is below dust limit of {dust_limit}",
+    );
+
+    let haystacks = wallet.get_wallet_sat_ranges()?;
+
+    let targets = self.targets(&wallet, haystacks)?;
+
+    ensure!(!targets.is_empty(), "no sats to extract");
+
+    let (unfunded_transaction, extracted) =
+      Self::extract(&wallet, &targets, &change, postage, dust_limit)?;
+
+    wallet.lock_non_cardinal_outputs()?;
+
+    let unsigned_transaction: Transaction = consensus::encode::deserialize(&fund_raw_transaction(
+      wallet.bitcoin_client(),
+      self.fee_rate,
+      &unfunded_transaction,
+    )?)?;
+
+    let (txid, psbt, fee) = wallet.sign_transaction(unsigned_transaction, self.dry_run, None)?;
+
+    let extracted = extracted
+      .into_iter()
+      .map(|(vout, sat)| ExtractedSat {
+        output: OutPoint { txid, vout },
+        rarity: sat.rarity(),
+        sat,
+      })
+      .collect::<Vec<ExtractedSat>>();
+
+    if !self.dry_run {
+      let outputs = extracted
+        .iter()
+        .map(|extracted| extracted.output)
+        .collect::<BTreeSet<OutPoint>>()
+        .into_iter()
+        .collect::<Vec<OutPoint>>();
+
+      if !wallet.bitcoin_client().lock_unspent(&outputs)? {
+        bail!("failed to lock UTXOs");
+      }
+    }
+
+    Ok(Some(Box::new(Output {
+      extracted,
+      fee,
+      psbt,
+      txid,
+    })))
+  }
+
+  /// Find sats to extract, grouped by output and sorted by offset, skipping
+  /// outputs which contain inscriptions or runes, are locked, or contain a
+  /// single sat to extract which has already been extracted.
+  fn targets(
+    &self,
+    wallet: &Wallet,
+    haystacks: Vec<(OutPoint, Vec<(u64, u64)>)>,
+  ) -> Result<BTreeMap<OutPoint, Vec<(u64, Sat)>>> {
+    let found = if let Some(path) = &self.tsv {
+      let tsv = fs::read_to_string(path)
+        .with_context(|| format!("I/O error reading `{}`", path.display()))?;
+
+      Sats::find(&Sats::needles(&tsv)?, &haystacks)
+        .into_iter()
+        .map(|(sat, satpoint)| Ok((satpoint.outpoint, Sat::from_str(&sat)?, satpoint.offset)))
+        .collect::<Result<Vec<(OutPoint, Sat, u64)>>>()?
+    } else {
+      Sats::rare_sats(haystacks)
+        .into_iter()
+        .filter(|(_outpoint, _sat, _offset, rarity)| *rarity >= self.rarity)
+        .map(|(outpoint, sat, offset, _rarity)| (outpoint, sat, offset))
+        .collect()
+    };
+
+    let excluded = wallet
+      .inscriptions()
+      .keys()
+      .map(|satpoint| satpoint.outpoint)
+      .chain(wallet.get_runic_outputs()?)
+      .chain(wallet.locked_utxos().keys().cloned())
+      .collect::<HashSet<OutPoint>>();
+
+    let mut targets = BTreeMap::<OutPoint, Vec<(u64, Sat)>>::new();
+
+    for (outpoint, sat, offset) in found {
+      if excluded.contains(&outpoint) || !wallet.utxos().contains_key(&outpoint) {
+        continue;
+      }
+
+      targets.entry(outpoint).or_default().push((offset, sat));
+    }
+
+    let postage = self.postage.unwrap_or(TARGET_POSTAGE);
+
+    targets.retain(|outpoint, sats| {
+      sats.sort();
+      !(sats.len() == 1 && sats[0].0 == 0 && wallet.utxos()[outpoint].value <= postage)
+    });
+
+    Ok(targets)
+  }
+
+  /// Build an unfunded transaction spending `targets` which moves each sat to
+  /// the start of its own output. Sats closer together than `postage` share an
+  /// output. Returns the transaction and the output index of each sat.
+  fn extract(
+    wallet: &Wallet,
+    targets: &BTreeMap<OutPoint, Vec<(u64, Sat)>>,
+    change: &ScriptBuf,
+    postage: Amount,
+    dust_limit: Amount,
+  ) -> Result<(Transaction, Vec<(u32, Sat)>)> {
+    // inputs are spent in order, so the sats they contain form a single
+    // sequence which is divided into outputs, with any outputs extending past
+    // the end of the sequence taking sats from inputs added when funding
+    let mut inputs = targets.keys().cloned().collect::<Vec<OutPoint>>();
+
+    if let Some(i) = inputs.iter().position(|outpoint| {
+      let first = targets[outpoint][0].0;
+      first == 0 || first >= dust_limit.to_sat()
+    }) {
+      inputs.swap(0, i);
+    }
+
+    let mut outputs = Vec::<TxOut>::new();
+    let mut extracted = Vec::new();
+    let mut cursor = 0;
+    let mut start = 0;
+
+    for outpoint in &inputs {
+      for (offset, sat) in &targets[outpoint] {
+        let offset = start + offset;
+
+        if offset < cursor {
+          extracted.push((outputs.len() - 1, *sat));
+          continue;
+        }
+
+        let gap = Amount::from_sat(offset - cursor);
+
+        if gap >= dust_limit {
+          outputs.push(TxOut {
+            script_pubkey: change.clone(),
+            value: gap,
+          });
+        } else if gap > Amount::ZERO {
+          let Some(last) = outputs.last_mut() else {
+            bail!(
+              "sat {sat} in output {outpoint} cannot be extracted, it is preceded by fewer sats than the dust limit of {dust_limit}"
+            );
+          };
+
+          last.value += gap;
+        }
+
+        outputs.push(TxOut {
+          script_pubkey: change.clone(),
+          value: postage,
+        });
+
+        extracted.push((outputs.len() - 1, *sat));
+
+        cursor = offset + postage.to_sat();
+      }
+
+      start += wallet.utxos()[outpoint].value.to_sat();
+    }
+
+    if let Some(remainder) = start.checked_sub(cursor).map(Amount::from_sat) {
+      if remainder >= dust_limit {
+        outputs.push(TxOut {
+          script_pubkey: change.clone(),
+          value: remainder,
+        });
+      } else {
+        outputs.last_mut().unwrap().value += remainder;
+      }
+    }
+
+    // use a fresh change address for each output
+    for output in &mut outputs {
+      output.script_pubkey = wallet.get_change_address()?.script_pubkey();
+    }
+
+    Ok((
+      Transaction {
+        version: Version(2),
+        lock_time: LockTime::ZERO,
+        input: inputs
+          .into_iter()
+          .map(|previous_output| TxIn {
+            previous_output,
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+            witness: Witness::new(),
+          })
+          .collect(),
+        output: outputs,
+      },
+      extracted
+        .into_iter()
+        .map(|(vout, sat)| (vout.try_into().unwrap(), sat))
+        .collect(),
+    ))
+  }
+}
@@ -62,6 +62,7 @@ impl Inscribe {
 
     batch::Plan {
       commit_fee_rate: self.shared.commit_fee_rate.unwrap_or(self.shared.fee_rate),
+      commit_only: self.shared.commit_only,
       destinations: vec![match self.destination.clone() {
         Some(destination) => destination.require_network(chain.network())?,
         None => wallet.get_change_address()?,
@@ -87,14 +88,16 @@ impl Inscribe {
       reinscribe: self.reinscribe,
       reveal_fee_rate: self.shared.fee_rate,
       reveal_satpoints: Vec::new(),
+      satisfaction_weight: wallet.satisfaction_weight(),
       satpoint: if let Some(sat) = self.sat {
         Some(wallet.find_sat_in_outputs(sat)?)
       } else {
         self.satpoint
       },
+      unsigned_psbt: self.shared.unsigned_psbt,
     }
     .inscribe(
-      &wallet.locked_utxos().clone().into_keys().collect(),
+      &wallet.unspendable_outputs(),
       wallet.get_runic_outputs()?,
       wallet.utxos(),
       &wallet,
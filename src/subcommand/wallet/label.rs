@@ -1,71 +1,159 @@
-use super::*;
+use {
+  super::*,
+  crate::wallet::label::{self, Type},
+};
 
-#[derive(Serialize)]
-struct Label {
+#[derive(Debug, Parser)]
+pub(crate) struct Label {
+  #[command(subcommand)]
+  subcommand: Option<LabelSubcommand>,
+}
+
+#[derive(Debug, Parser)]
+enum LabelSubcommand {
+  #[command(about = "Import BIP-329 labels")]
+  Import(Import),
+}
+
+#[derive(Debug, Parser)]
+struct Import {
+  #[arg(help = "Import labels from BIP-329 JSONL <FILE>.")]
+  file: PathBuf,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Output {
+  pub labels: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Generated {
   first_sat: SatLabel,
   inscriptions: BTreeMap<u64, BTreeSet<InscriptionId>>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  label: Option<String>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct SatLabel {
   name: String,
   number: u64,
   rarity: Rarity,
 }
 
-#[derive(Serialize)]
-struct Line {
-  label: String,
-  r#ref: String,
-  r#type: String,
-}
+impl Label {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    match self.subcommand {
+      None => Self::export(wallet),
+      Some(LabelSubcommand::Import(import)) => import.run(wallet),
+    }
+  }
 
-pub(crate) fn run(wallet: Wallet) -> SubcommandResult {
-  let mut lines: Vec<Line> = Vec::new();
+  /// Export a label for each wallet output, describing its first sat and
+  /// inscriptions, and containing any imported label for that output,
+  /// followed by all other imported labels.
+  fn export(wallet: Wallet) -> SubcommandResult {
+    let mut lines: Vec<label::Label> = Vec::new();
 
-  let sat_ranges = wallet.get_wallet_sat_ranges()?;
+    let mut imported = wallet
+      .labels()?
+      .into_iter()
+      .map(|label| ((label.r#type, label.r#ref.clone()), label))
+      .collect::<BTreeMap<(Type, String), label::Label>>();
 
-  let mut inscriptions_by_output: BTreeMap<OutPoint, BTreeMap<u64, Vec<InscriptionId>>> =
-    BTreeMap::new();
+    let sat_ranges = wallet.get_wallet_sat_ranges()?;
 
-  for (satpoint, inscriptions) in wallet.inscriptions() {
-    inscriptions_by_output
-      .entry(satpoint.outpoint)
-      .or_default()
-      .insert(satpoint.offset, inscriptions.clone());
-  }
+    let mut inscriptions_by_output: BTreeMap<OutPoint, BTreeMap<u64, Vec<InscriptionId>>> =
+      BTreeMap::new();
 
-  for (output, ranges) in sat_ranges {
-    let sat = Sat(ranges[0].0);
-    let mut inscriptions = BTreeMap::<u64, BTreeSet<InscriptionId>>::new();
+    for (satpoint, inscriptions) in wallet.inscriptions() {
+      inscriptions_by_output
+        .entry(satpoint.outpoint)
+        .or_default()
+        .insert(satpoint.offset, inscriptions.clone());
+    }
+
+    for (output, ranges) in sat_ranges {
+      let sat = Sat(ranges[0].0);
+      let mut inscriptions = BTreeMap::<u64, BTreeSet<InscriptionId>>::new();
 
-    if let Some(output_inscriptions) = inscriptions_by_output.get(&output) {
-      for (&offset, offset_inscriptions) in output_inscriptions {
-        inscriptions
-          .entry(offset)
-          .or_default()
-          .extend(offset_inscriptions);
+      if let Some(output_inscriptions) = inscriptions_by_output.get(&output) {
+        for (&offset, offset_inscriptions) in output_inscriptions {
+          inscriptions
+            .entry(offset)
+            .or_default()
+            .extend(offset_inscriptions);
+        }
       }
+
+      let r#ref = output.to_string();
+
+      let existing = imported.remove(&(Type::Output, r#ref.clone()));
+
+      lines.push(label::Label {
+        label: Some(serde_json::to_string(&Generated {
+          first_sat: SatLabel {
+            name: sat.name(),
+            number: sat.n(),
+            rarity: sat.rarity(),
+          },
+          inscriptions,
+          label: existing.as_ref().and_then(|label| label.label.clone()),
+        })?),
+        origin: existing.as_ref().and_then(|label| label.origin.clone()),
+        spendable: existing.and_then(|label| label.spendable),
+        r#ref,
+        r#type: Type::Output,
+      });
     }
 
-    lines.push(Line {
-      label: serde_json::to_string(&Label {
-        first_sat: SatLabel {
-          name: sat.name(),
-          number: sat.n(),
-          rarity: sat.rarity(),
-        },
-        inscriptions,
-      })?,
-      r#ref: output.to_string(),
-      r#type: "output".into(),
-    });
-  }
+    lines.extend(imported.into_values());
+
+    for line in lines {
+      serde_json::to_writer(io::stdout(), &line)?;
+      println!();
+    }
 
-  for line in lines {
-    serde_json::to_writer(io::stdout(), &line)?;
-    println!();
+    Ok(None)
   }
+}
+
+impl Import {
+  fn run(self, wallet: Wallet) -> SubcommandResult {
+    let file = fs::read_to_string(&self.file)
+      .with_context(|| format!("failed to read labels from `{}`", self.file.display()))?;
+
+    let mut labels = Vec::new();
+
+    for (i, line) in file.lines().enumerate() {
+      if line.trim().is_empty() {
+        continue;
+      }
+
+      let mut label = serde_json::from_str::<label::Label>(line)
+        .map_err(anyhow::Error::from)
+        .and_then(|label| label.validate().map(|()| label))
+        .with_context(|| format!("invalid label on line {}", i + 1))?;
+
+      // labels exported by `ord wallet label` wrap the user's label in a
+      // generated description of the output, which is regenerated on export
+      if label.r#type == Type::Output {
+        if let Some(generated) = label
+          .label
+          .as_deref()
+          .and_then(|text| serde_json::from_str::<Generated>(text).ok())
+        {
+          label.label = generated.label;
+        }
+      }
 
-  Ok(None)
+      labels.push(label);
+    }
+
+    wallet.save_labels(&labels)?;
+
+    Ok(Some(Box::new(Output {
+      labels: labels.len().try_into().unwrap(),
+    })))
+  }
 }
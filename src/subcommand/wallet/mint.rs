@@ -1,5 +1,9 @@
 use super::*;
 
+/// Bitcoin Core's default limit on the number of unconfirmed ancestors of a
+/// transaction, including itself
+const MAX_CHAINED_MINTS: usize = 25;
+
 #[derive(Debug, Parser)]
 pub(crate) struct Mint {
   #[clap(long, help = "Use <FEE_RATE> sats/vbyte for mint transaction.")]
@@ -13,6 +17,24 @@ pub(crate) struct Mint {
   postage: Option<Amount>,
   #[clap(long, help = "Send minted runes to <DESTINATION>.")]
   destination: Option<Address<NetworkUnchecked>>,
+  #[arg(
+    long,
+    default_value_t = 1,
+    conflicts_with = "unsigned_psbt",
+    help = "Mint <COUNT> times, in a chain of transactions each spending the change of the previous one. Stops early if the mint cap is reached."
+  )]
+  count: usize,
+  #[arg(
+    long,
+    help = "Spend the output of each mint in the next, so that all minted runes are sent to <DESTINATION> in a single output."
+  )]
+  consolidate: bool,
+  #[arg(
+    long,
+    value_name = "FILE",
+    help = "Write unsigned PSBT to <FILE> instead of signing and broadcasting mint transaction. Sign with `ord wallet sign` and broadcast with `ord wallet broadcast`."
+  )]
+  unsigned_psbt: Option<PathBuf>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -20,6 +42,8 @@ pub struct Output {
   pub rune: SpacedRune,
   pub pile: Pile,
   pub mint: Txid,
+  pub mints: Vec<Txid>,
+  pub psbt: Option<String>,
 }
 
 impl Mint {
@@ -29,6 +53,11 @@ impl Mint {
       "`ord wallet mint` requires index created with `--index-runes` flag",
     );
 
+    ensure!(
+      (1..=MAX_CHAINED_MINTS).contains(&self.count),
+      "`--count` must be between 1 and {MAX_CHAINED_MINTS}",
+    );
+
     let rune = self.rune.rune;
 
     let bitcoin_client = wallet.bitcoin_client();
@@ -45,10 +74,21 @@ impl Mint {
       .mintable(block_height + 1)
       .map_err(|err| anyhow!("rune {rune} {err}"))?;
 
+    // `mintable` succeeded, so the cap has not been reached, and mints past
+    // the cap would be burned
+    let remaining = rune_entry
+      .terms
+      .and_then(|terms| terms.cap)
+      .unwrap_or_default()
+      - rune_entry.mints;
+
+    let count =
+      usize::try_from(remaining).map_or(self.count, |remaining| self.count.min(remaining));
+
     let chain = wallet.chain();
 
-    let destination = match self.destination {
-      Some(destination) => destination.require_network(chain.network())?,
+    let destination = match &self.destination {
+      Some(destination) => destination.clone().require_network(chain.network())?,
       None => wallet.get_change_address()?,
     };
 
@@ -71,48 +111,156 @@ impl Mint {
       script_pubkey.len()
     );
 
-    let unfunded_transaction = Transaction {
-      version: Version(2),
-      lock_time: LockTime::ZERO,
-      input: Vec::new(),
-      output: vec![
+    let pile = Pile {
+      amount: amount * u128::try_from(count).unwrap(),
+      divisibility: rune_entry.divisibility,
+      symbol: rune_entry.symbol,
+    };
+
+    wallet.lock_non_cardinal_outputs()?;
+
+    let mut mints = Vec::new();
+    let mut previous = None;
+
+    for i in 0..count {
+      // when consolidating, only the last mint is sent to the destination
+      let recipient = if self.consolidate && i + 1 < count {
+        wallet.get_change_address()?
+      } else {
+        destination.clone()
+      };
+
+      let outputs = vec![
         TxOut {
-          script_pubkey,
+          script_pubkey: script_pubkey.clone(),
           value: Amount::from_sat(0),
         },
         TxOut {
-          script_pubkey: destination.script_pubkey(),
+          script_pubkey: recipient.script_pubkey(),
           value: postage,
         },
-      ],
-    };
+      ];
 
-    wallet.lock_non_cardinal_outputs()?;
+      let unsigned_transaction = match previous {
+        None => consensus::encode::deserialize(&fund_raw_transaction(
+          bitcoin_client,
+          self.fee_rate,
+          &Transaction {
+            version: Version(2),
+            lock_time: LockTime::ZERO,
+            input: Vec::new(),
+            output: outputs,
+          },
+        )?)?,
+        Some(previous) => self.chained_transaction(&wallet, previous, outputs)?,
+      };
 
-    let unsigned_transaction =
-      fund_raw_transaction(bitcoin_client, self.fee_rate, &unfunded_transaction)?;
+      assert_eq!(
+        Runestone::decipher(&unsigned_transaction),
+        Some(Artifact::Runestone(Runestone {
+          mint: Some(id),
+          ..default()
+        })),
+      );
 
-    let signed_transaction = bitcoin_client
-      .sign_raw_transaction_with_wallet(&unsigned_transaction, None, None)?
-      .hex;
+      if let Some(path) = &self.unsigned_psbt {
+        let psbt = wallet.unsigned_psbt(&unsigned_transaction)?;
 
-    let signed_transaction = consensus::encode::deserialize(&signed_transaction)?;
+        crate::wallet::offline::write_psbts(path, std::slice::from_ref(&psbt))?;
 
-    assert_eq!(
-      Runestone::decipher(&signed_transaction),
-      Some(Artifact::Runestone(runestone)),
-    );
+        return Ok(Some(Box::new(Output {
+          rune: self.rune,
+          pile,
+          mint: unsigned_transaction.compute_txid(),
+          mints: vec![unsigned_transaction.compute_txid()],
+          psbt: Some(crate::wallet::offline::encode_psbt(&psbt)),
+        })));
+      }
 
-    let transaction = bitcoin_client.send_raw_transaction(&signed_transaction)?;
+      wallet.check_private_keys()?;
+
+      let result =
+        bitcoin_client.sign_raw_transaction_with_wallet(&unsigned_transaction, None, None)?;
+
+      Wallet::check_complete(result.complete)?;
+
+      let signed_transaction: Transaction = consensus::encode::deserialize(&result.hex)?;
+
+      let txid = bitcoin_client.send_raw_transaction(&signed_transaction)?;
+
+      mints.push(txid);
+
+      previous = Some((txid, signed_transaction));
+    }
 
     Ok(Some(Box::new(Output {
       rune: self.rune,
-      pile: Pile {
-        amount,
-        divisibility: rune_entry.divisibility,
-        symbol: rune_entry.symbol,
-      },
-      mint: transaction,
+      pile,
+      mint: *mints.last().unwrap(),
+      mints,
+      psbt: None,
     })))
   }
+
+  /// Build a mint transaction spending the change of the previous mint, and,
+  /// when consolidating, the previous mint output, whose runes are then
+  /// allocated to the new mint output along with the newly minted runes.
+  fn chained_transaction(
+    &self,
+    wallet: &Wallet,
+    (txid, previous): (Txid, Transaction),
+    mut outputs: Vec<TxOut>,
+  ) -> Result<Transaction> {
+    // mint transactions are funded with change at index 2
+    let change = previous
+      .output
+      .get(2)
+      .ok_or_else(|| anyhow!("mint transaction {txid} has no change output to fund next mint"))?;
+
+    let mut inputs = vec![(OutPoint { txid, vout: 2 }, change.value)];
+
+    if self.consolidate {
+      inputs.push((OutPoint { txid, vout: 1 }, previous.output[1].value));
+    }
+
+    let change_script_pubkey = wallet.get_change_address()?.script_pubkey();
+
+    outputs.push(TxOut {
+      script_pubkey: change_script_pubkey.clone(),
+      value: Amount::ZERO,
+    });
+
+    let witness = TransactionBuilder::dummy_witness(wallet.satisfaction_weight());
+
+    let mut transaction = Transaction {
+      version: Version(2),
+      lock_time: LockTime::ZERO,
+      input: inputs
+        .iter()
+        .map(|(previous_output, _value)| TxIn {
+          previous_output: *previous_output,
+          script_sig: ScriptBuf::new(),
+          sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+          witness: witness.clone(),
+        })
+        .collect(),
+      output: outputs,
+    };
+
+    let fee = self.fee_rate.fee(transaction.vsize());
+
+    let value =
+      inputs.iter().map(|(_output, value)| *value).sum::<Amount>() - transaction.output[1].value;
+
+    transaction.output[2].value = value
+      .checked_sub(fee)
+      .filter(|value| *value >= change_script_pubkey.minimal_non_dust())
+      .ok_or_else(|| anyhow!("change of mint transaction {txid} is too small to fund next mint"))?;
+
+    for input in &mut transaction.input {
+      input.witness = Witness::new();
+    }
+
+    Ok(transaction)
+  }
 }
@@ -0,0 +1,39 @@
+use {
+  super::*,
+  crate::wallet::offline,
+  bitcoin::{psbt::Psbt, sighash::TapSighashType},
+};
+
+pub mod accept;
+pub mod create;
+
+#[derive(Debug, Parser)]
+pub(crate) enum Offer {
+  #[command(about = "Buy inscription or runes offered in a PSBT")]
+  Accept(accept::Accept),
+  #[command(about = "Create PSBT offering to sell inscription or runes")]
+  Create(create::Create),
+}
+
+impl Offer {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    match self {
+      Self::Accept(accept) => accept.run(wallet),
+      Self::Create(create) => create.run(wallet),
+    }
+  }
+}
+
+/// Offers are signed with SIGHASH_SINGLE|ANYONECANPAY, which commits only to
+/// the seller's input and the output paying the seller, so the buyer can add
+/// their own inputs and outputs without invalidating the seller's signature.
+const SIGHASH_TYPE: TapSighashType = TapSighashType::SinglePlusAnyoneCanPay;
+
+fn outgoing(inscription: Option<InscriptionId>, rune: Option<Outgoing>) -> Result<Outgoing> {
+  match (inscription, rune) {
+    (Some(inscription), None) => Ok(Outgoing::InscriptionId(inscription)),
+    (None, Some(rune @ Outgoing::Rune { .. })) => Ok(rune),
+    (None, Some(outgoing)) => bail!("`--rune` must be of the form <DECIMAL:RUNE>, not {outgoing}"),
+    _ => bail!("offer must be for exactly one of `--inscription` or `--rune`"),
+  }
+}
@@ -0,0 +1,268 @@
+use super::*;
+
+#[derive(Debug, Parser)]
+pub(crate) struct Accept {
+  #[arg(long, help = "Pay <AMOUNT>, which must match the amount of the offer.")]
+  amount: Amount,
+  #[arg(long, help = "Don't sign or broadcast transaction.")]
+  dry_run: bool,
+  #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vB.")]
+  fee_rate: FeeRate,
+  #[arg(
+    long,
+    conflicts_with = "rune",
+    required_unless_present = "rune",
+    help = "Buy <INSCRIPTION>, which must be in the offered output."
+  )]
+  inscription: Option<InscriptionId>,
+  #[arg(long, help = "Accept offer in PSBT file <PSBT>.", value_name = "PSBT")]
+  psbt: PathBuf,
+  #[arg(
+    long,
+    value_name = "DECIMAL:RUNE",
+    help = "Buy <DECIMAL:RUNE>, which must be the balance of the offered output."
+  )]
+  rune: Option<Outgoing>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct Output {
+  pub fee: u64,
+  pub psbt: String,
+  pub txid: Txid,
+}
+
+impl Accept {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    if !self.dry_run {
+      wallet.check_private_keys()?;
+    }
+
+    let outgoing = outgoing(self.inscription, self.rune.clone())?;
+
+    let mut psbts = offline::read_psbts(&self.psbt)?;
+
+    ensure!(
+      psbts.len() == 1,
+      "PSBT file `{}` must contain exactly one offer",
+      self.psbt.display(),
+    );
+
+    let offer = psbts.remove(0);
+
+    ensure!(
+      offer.unsigned_tx.input.len() == 1 && offer.unsigned_tx.output.len() == 1,
+      "offer must have exactly one input and one output",
+    );
+
+    let witness = offer.inputs[0]
+      .final_script_witness
+      .clone()
+      .ok_or_else(|| anyhow!("offer is not signed"))?;
+
+    // a taproot key path signature with a sighash type other than the default
+    // is followed by the sighash type
+    ensure!(
+      witness.len() == 1
+        && witness
+          .nth(0)
+          .is_some_and(|signature| signature.len() == 65 && signature[64] == SIGHASH_TYPE as u8),
+      "offer must be signed with SIGHASH_SINGLE|ANYONECANPAY",
+    );
+
+    let mut seller_input = offer.unsigned_tx.input[0].clone();
+    let seller_output = offer.unsigned_tx.output[0].clone();
+
+    ensure!(
+      seller_output.value == self.amount,
+      "offer amount of {} does not match `--amount` of {}",
+      seller_output.value,
+      self.amount,
+    );
+
+    let seller_prevout =
+      self.check_offered_output(&wallet, &outgoing, seller_input.previous_output)?;
+
+    // include the seller's signature when estimating the fee
+    seller_input.witness = witness.clone();
+
+    let (transaction, fee) =
+      self.purchase(&wallet, seller_input, seller_prevout.clone(), seller_output)?;
+
+    let txid = transaction.compute_txid();
+
+    let mut psbt = Psbt::from_unsigned_tx(transaction)?;
+
+    psbt.inputs[1].witness_utxo = Some(seller_prevout);
+    psbt.inputs[1].final_script_witness = Some(witness);
+
+    let psbt = if self.dry_run {
+      offline::encode_psbt(&psbt)
+    } else {
+      wallet.sign_and_broadcast(&psbt)?.1
+    };
+
+    Ok(Some(Box::new(Output {
+      fee: fee.to_sat(),
+      psbt,
+      txid,
+    })))
+  }
+
+  /// Check that the offered output is unspent and contains the inscription or
+  /// rune balance being bought, according to the index, and return it.
+  fn check_offered_output(
+    &self,
+    wallet: &Wallet,
+    outgoing: &Outgoing,
+    output: OutPoint,
+  ) -> Result<TxOut> {
+    let info = wallet
+      .get_output_info(output)?
+      .ok_or_else(|| anyhow!("offered output {output} has not been indexed"))?;
+
+    ensure!(
+      !info.spent,
+      "offered output {output} has already been spent"
+    );
+
+    match outgoing {
+      Outgoing::InscriptionId(id) => ensure!(
+        info.inscriptions.contains(id),
+        "offered output {output} does not contain inscription {id}",
+      ),
+      Outgoing::Rune { decimal, rune } => {
+        ensure!(
+          wallet.has_rune_index(),
+          "accepting runes offer with `ord wallet offer` requires index created with `--index-runes` flag",
+        );
+
+        let (_id, entry, _parent) = wallet
+          .get_rune(rune.rune)?
+          .with_context(|| format!("rune `{}` has not been etched", rune.rune))?;
+
+        let amount = decimal.to_integer(entry.divisibility)?;
+
+        ensure!(
+          info
+            .runes
+            .iter()
+            .find(|(spaced_rune, _pile)| spaced_rune.rune == rune.rune)
+            .map(|(_spaced_rune, pile)| pile.amount)
+            == Some(amount),
+          "offered output {output} does not contain exactly {decimal}:{rune}",
+        );
+      }
+      _ => unreachable!(),
+    }
+
+    Ok(TxOut {
+      script_pubkey: info.script_pubkey,
+      value: Amount::from_sat(info.value),
+    })
+  }
+
+  /// Build the purchase transaction. A padding input from the wallet comes
+  /// before the seller's input, so that the seller's input and output are both
+  /// at index one, and the sats of both, along with any runes, go to the first
+  /// output, which pays the buyer.
+  fn purchase(
+    &self,
+    wallet: &Wallet,
+    seller_input: TxIn,
+    seller_prevout: TxOut,
+    seller_output: TxOut,
+  ) -> Result<(Transaction, Amount)> {
+    let inscribed_outputs = wallet
+      .inscriptions()
+      .keys()
+      .map(|satpoint| satpoint.outpoint)
+      .collect::<HashSet<OutPoint>>();
+
+    let runic_outputs = wallet.get_runic_outputs()?;
+
+    let unspendable = wallet.unspendable_outputs();
+
+    let mut cardinals = wallet
+      .utxos()
+      .iter()
+      .filter(|(output, _tx_out)| {
+        **output != seller_input.previous_output
+          && !inscribed_outputs.contains(output)
+          && !runic_outputs.contains(output)
+          && !unspendable.contains(output)
+      })
+      .map(|(output, tx_out)| (tx_out.value, *output))
+      .collect::<Vec<(Amount, OutPoint)>>();
+
+    cardinals.sort();
+
+    ensure!(
+      !cardinals.is_empty(),
+      "wallet does not contain enough cardinal outputs to accept offer",
+    );
+
+    let (padding, padding_output) = cardinals.remove(0);
+
+    let witness = TransactionBuilder::dummy_witness(wallet.satisfaction_weight());
+
+    let input = |previous_output| TxIn {
+      previous_output,
+      script_sig: ScriptBuf::new(),
+      sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+      witness: witness.clone(),
+    };
+
+    let change = wallet.get_change_address()?.script_pubkey();
+
+    let mut transaction = Transaction {
+      version: Version(2),
+      lock_time: LockTime::ZERO,
+      input: vec![input(padding_output), seller_input],
+      output: vec![
+        TxOut {
+          script_pubkey: wallet.get_change_address()?.script_pubkey(),
+          value: padding + seller_prevout.value,
+        },
+        seller_output,
+        TxOut {
+          script_pubkey: change.clone(),
+          value: Amount::ZERO,
+        },
+      ],
+    };
+
+    let fixed = transaction.output[..2]
+      .iter()
+      .map(|output| output.value)
+      .sum::<Amount>();
+
+    let mut input_value = padding + seller_prevout.value;
+
+    // spend the largest cardinal outputs first, to keep the transaction small
+    loop {
+      let fee = self.fee_rate.fee(transaction.vsize());
+
+      if let Some(value) = input_value
+        .checked_sub(fixed + fee)
+        .filter(|value| *value >= change.minimal_non_dust())
+      {
+        transaction.output[2].value = value;
+
+        for input in &mut transaction.input {
+          input.witness = Witness::new();
+        }
+
+        return Ok((transaction, fee));
+      }
+
+      let (value, output) = cardinals.pop().ok_or_else(|| {
+        anyhow!("wallet does not contain enough cardinal outputs to accept offer")
+      })?;
+
+      transaction.input.push(input(output));
+
+      input_value += value;
+    }
+  }
+}
@@ -0,0 +1,153 @@
+use {super::*, bitcoin::sighash::EcdsaSighashType};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Create {
+  #[arg(long, help = "Sell for <AMOUNT>.")]
+  amount: Amount,
+  #[arg(
+    long,
+    conflicts_with = "rune",
+    required_unless_present = "rune",
+    help = "Sell <INSCRIPTION>, which must be the only inscription in its output."
+  )]
+  inscription: Option<InscriptionId>,
+  #[arg(
+    long,
+    value_name = "DECIMAL:RUNE",
+    help = "Sell <DECIMAL:RUNE>. Wallet must contain an output holding exactly <DECIMAL:RUNE> and no other runes or inscriptions."
+  )]
+  rune: Option<Outgoing>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct Output {
+  pub amount: u64,
+  pub outgoing: Outgoing,
+  pub output: OutPoint,
+  pub psbt: String,
+}
+
+impl Create {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    wallet.check_private_keys()?;
+
+    let outgoing = outgoing(self.inscription, self.rune)?;
+
+    let output = match &outgoing {
+      Outgoing::InscriptionId(id) => Self::inscription_output(&wallet, *id)?,
+      Outgoing::Rune { decimal, rune } => Self::rune_output(&wallet, *decimal, *rune)?,
+      _ => unreachable!(),
+    };
+
+    let seller = wallet.get_change_address()?.script_pubkey();
+
+    let dust_limit = seller.minimal_non_dust();
+
+    ensure!(
+      self.amount >= dust_limit,
+      "offer amount of {} is below dust limit of {dust_limit}",
+      self.amount,
+    );
+
+    let mut psbt = Psbt::from_unsigned_tx(Transaction {
+      version: Version(2),
+      lock_time: LockTime::ZERO,
+      input: vec![TxIn {
+        previous_output: output,
+        script_sig: ScriptBuf::new(),
+        sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+        witness: Witness::new(),
+      }],
+      output: vec![TxOut {
+        script_pubkey: seller,
+        value: self.amount,
+      }],
+    })?;
+
+    psbt.inputs[0].witness_utxo = Some(wallet.utxos()[&output].clone());
+    psbt.inputs[0].sighash_type = Some(SIGHASH_TYPE.into());
+
+    let result = wallet.bitcoin_client().wallet_process_psbt(
+      &offline::encode_psbt(&psbt),
+      Some(true),
+      Some(EcdsaSighashType::SinglePlusAnyoneCanPay.into()),
+      None,
+    )?;
+
+    Wallet::check_complete(result.complete)?;
+
+    Ok(Some(Box::new(Output {
+      amount: self.amount.to_sat(),
+      outgoing,
+      output,
+      psbt: result.psbt,
+    })))
+  }
+
+  fn inscription_output(wallet: &Wallet, id: InscriptionId) -> Result<OutPoint> {
+    let output = wallet
+      .inscription_info()
+      .get(&id)
+      .ok_or_else(|| anyhow!("inscription {id} not in wallet"))?
+      .satpoint
+      .outpoint;
+
+    let inscriptions = wallet
+      .inscriptions()
+      .iter()
+      .filter(|(satpoint, _inscriptions)| satpoint.outpoint == output)
+      .map(|(_satpoint, inscriptions)| inscriptions.len())
+      .sum::<usize>();
+
+    ensure!(
+      inscriptions == 1,
+      "output {output} containing inscription {id} contains other inscriptions",
+    );
+
+    ensure!(
+      !wallet.get_runic_outputs()?.contains(&output),
+      "output {output} containing inscription {id} contains runes",
+    );
+
+    Ok(output)
+  }
+
+  fn rune_output(wallet: &Wallet, decimal: Decimal, spaced_rune: SpacedRune) -> Result<OutPoint> {
+    ensure!(
+      wallet.has_rune_index(),
+      "creating runes offer with `ord wallet offer` requires index created with `--index-runes` flag",
+    );
+
+    let (_id, entry, _parent) = wallet
+      .get_rune(spaced_rune.rune)?
+      .with_context(|| format!("rune `{}` has not been etched", spaced_rune.rune))?;
+
+    let amount = decimal.to_integer(entry.divisibility)?;
+
+    let inscribed_outputs = wallet
+      .inscriptions()
+      .keys()
+      .map(|satpoint| satpoint.outpoint)
+      .collect::<HashSet<OutPoint>>();
+
+    for output in wallet.get_runic_outputs()? {
+      if inscribed_outputs.contains(&output) {
+        continue;
+      }
+
+      let balances = wallet.get_runes_balances_in_output(&output)?;
+
+      if balances.len() == 1
+        && balances
+          .iter()
+          .all(|(rune, pile)| rune.rune == spaced_rune.rune && pile.amount == amount)
+      {
+        return Ok(output);
+      }
+    }
+
+    bail!(
+      "wallet contains no output holding exactly {decimal}:{spaced_rune} and no other runes or inscriptions"
+    )
+  }
+}
@@ -1,4 +1,4 @@
-use super::*;
+use {super::*, crate::wallet::label};
 
 #[derive(Debug, Parser)]
 pub(crate) struct Outputs {
@@ -12,10 +12,14 @@ pub struct Output {
   pub amount: u64,
   #[serde(skip_serializing_if = "Option::is_none")]
   pub sat_ranges: Option<Vec<String>>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub label: Option<String>,
 }
 
 impl Outputs {
   pub(crate) fn run(&self, wallet: Wallet) -> SubcommandResult {
+    let labels = wallet.label_texts(label::Type::Output)?;
+
     let mut outputs = Vec::new();
     for (output, txout) in wallet.utxos() {
       let sat_ranges = if wallet.has_sat_index() && self.ranges {
@@ -34,6 +38,7 @@ impl Outputs {
         output: *output,
         amount: txout.value.to_sat(),
         sat_ranges,
+        label: labels.get(&output.to_string()).cloned(),
       });
     }
 
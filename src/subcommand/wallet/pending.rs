@@ -5,11 +5,24 @@ pub struct PendingOutput {
   pub commit: Txid,
   pub rune: SpacedRune,
 }
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct PendingListOutput {
+  pub batches: Vec<batch::Output>,
+  pub etchings: Vec<PendingOutput>,
+}
+
 #[derive(Debug, Parser)]
 pub(crate) struct Pending {}
 
 impl Pending {
   pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    let batches = wallet
+      .pending_batches()?
+      .into_iter()
+      .map(|entry| entry.output)
+      .collect::<Vec<batch::Output>>();
+
     let etchings = wallet
       .pending_etchings()?
       .into_iter()
@@ -23,6 +36,8 @@ impl Pending {
       })
       .collect::<Vec<PendingOutput>>();
 
-    Ok(Some(Box::new(etchings) as Box<dyn Output>))
+    Ok(Some(
+      Box::new(PendingListOutput { batches, etchings }) as Box<dyn Output>
+    ))
   }
 }
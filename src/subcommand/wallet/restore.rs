@@ -6,6 +6,11 @@ pub(crate) struct Restore {
   from: Source,
   #[arg(long, help = "Use <PASSPHRASE> when deriving wallet")]
   pub(crate) passphrase: Option<String>,
+  #[arg(
+    long,
+    help = "Restore watch-only wallet from public `tr`, `wpkh`, or `wsh` descriptors. Spending commands require `--unsigned-psbt`."
+  )]
+  pub(crate) watch_only: bool,
 }
 
 #[derive(clap::ValueEnum, Debug, Clone)]
@@ -36,9 +41,18 @@ impl Restore {
           "descriptor does not take a passphrase"
         );
         let wallet_descriptors: ListDescriptorsResult = serde_json::from_str(&buffer)?;
-        Wallet::initialize_from_descriptors(name, settings, wallet_descriptors.descriptors)?;
+        Wallet::initialize_from_descriptors(
+          name,
+          settings,
+          wallet_descriptors.descriptors,
+          self.watch_only,
+        )?;
       }
       Source::Mnemonic => {
+        ensure!(
+          !self.watch_only,
+          "mnemonic cannot be used to restore watch-only wallet"
+        );
         io::stdin().read_line(&mut buffer)?;
         let mnemonic = Mnemonic::from_str(&buffer)?;
         Wallet::initialize(
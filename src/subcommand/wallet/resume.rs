@@ -2,18 +2,37 @@ use {super::*, crate::wallet::Maturity};
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct ResumeOutput {
+  pub batches: Vec<batch::Output>,
   pub etchings: Vec<batch::Output>,
 }
 #[derive(Debug, Parser)]
 pub(crate) struct Resume {
   #[arg(long, help = "Don't broadcast transactions.")]
   pub(crate) dry_run: bool,
-  #[arg(long, help = "Pending <RUNE> etching to resume.")]
+  #[arg(
+    long,
+    help = "Pending <RUNE> etching to resume. Inscription batches are not resumed."
+  )]
   pub(crate) rune: Option<SpacedRune>,
 }
 
 impl Resume {
   pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    let mut batches = Vec::new();
+
+    // inscription batches do not need to wait for their commits to mature,
+    // so they are resumed once, before waiting on pending etchings
+    if self.rune.is_none() {
+      for entry in wallet.pending_batches()? {
+        if self.dry_run {
+          batches.push(entry.output);
+          continue;
+        }
+
+        batches.extend(wallet.send_batch(&entry)?);
+      }
+    }
+
     let mut etchings = Vec::new();
     loop {
       if SHUTTING_DOWN.load(atomic::Ordering::Relaxed) {
@@ -69,6 +88,8 @@ impl Resume {
       }
     }
 
-    Ok(Some(Box::new(ResumeOutput { etchings }) as Box<dyn Output>))
+    Ok(Some(
+      Box::new(ResumeOutput { batches, etchings }) as Box<dyn Output>
+    ))
   }
 }
@@ -0,0 +1,38 @@
+use super::*;
+
+#[derive(Debug, Parser)]
+pub(crate) struct Reveal {
+  #[arg(
+    long,
+    help = "Reveal inscriptions committed to by an output of transaction <TXID>."
+  )]
+  txid: Txid,
+}
+
+impl Reveal {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    let transaction = wallet
+      .bitcoin_client()
+      .get_raw_transaction(&self.txid, None)?;
+
+    for (vout, output) in transaction.output.into_iter().enumerate() {
+      let Some(entry) = wallet.load_reveal(&output.script_pubkey)? else {
+        continue;
+      };
+
+      let commit = OutPoint {
+        txid: self.txid,
+        vout: vout.try_into().unwrap(),
+      };
+
+      return Ok(Some(Box::new(batch::Plan::reveal(
+        &wallet, commit, output, entry,
+      )?)));
+    }
+
+    bail!(
+      "transaction {} does not pay to any pending commit address",
+      self.txid
+    )
+  }
+}
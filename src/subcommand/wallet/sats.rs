@@ -88,7 +88,7 @@ impl Sats {
     }
   }
 
-  fn find(
+  pub(super) fn find(
     needles: &[(Sat, &str)],
     ranges: &[(OutPoint, Vec<(u64, u64)>)],
   ) -> BTreeMap<String, SatPoint> {
@@ -131,7 +131,7 @@ impl Sats {
     results
   }
 
-  fn needles(tsv: &str) -> Result<Vec<(Sat, &str)>> {
+  pub(super) fn needles(tsv: &str) -> Result<Vec<(Sat, &str)>> {
     let mut needles = tsv
       .lines()
       .enumerate()
@@ -153,7 +153,9 @@ impl Sats {
     Ok(needles)
   }
 
-  fn rare_sats(haystacks: Vec<(OutPoint, Vec<(u64, u64)>)>) -> Vec<(OutPoint, Sat, u64, Rarity)> {
+  pub(super) fn rare_sats(
+    haystacks: Vec<(OutPoint, Vec<(u64, u64)>)>,
+  ) -> Vec<(OutPoint, Sat, u64, Rarity)> {
     haystacks
       .into_iter()
       .flat_map(|(outpoint, sat_ranges)| {
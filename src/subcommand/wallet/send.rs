@@ -12,6 +12,13 @@ pub(crate) struct Send {
     value_name = "AMOUNT"
   )]
   pub(crate) postage: Option<Amount>,
+  #[arg(
+    long,
+    value_name = "FILE",
+    conflicts_with = "dry_run",
+    help = "Write unsigned PSBT to <FILE> instead of signing and broadcasting transaction. Sign with `ord wallet sign` and broadcast with `ord wallet broadcast`."
+  )]
+  pub(crate) unsigned_psbt: Option<PathBuf>,
   address: Address<NetworkUnchecked>,
   outgoing: Outgoing,
 }
@@ -71,9 +78,20 @@ impl Send {
         self.fee_rate,
         true,
       )?,
+      Outgoing::SatRange { start, end } => Self::create_unsigned_send_sat_range_transaction(
+        &wallet,
+        address,
+        start,
+        end,
+        self.fee_rate,
+      )?,
     };
 
-    let (txid, psbt, fee) = wallet.sign_transaction(unsigned_transaction, self.dry_run)?;
+    let (txid, psbt, fee) = wallet.sign_transaction(
+      unsigned_transaction,
+      self.dry_run,
+      self.unsigned_psbt.as_deref(),
+    )?;
 
     Ok(Some(Box::new(Output {
       txid,
@@ -146,7 +164,7 @@ impl Send {
         satpoint,
         wallet.inscriptions().clone(),
         wallet.utxos().clone(),
-        wallet.locked_utxos().clone().into_keys().collect(),
+        wallet.unspendable_outputs(),
         runic_outputs,
         destination.script_pubkey(),
         change,
@@ -154,6 +172,7 @@ impl Send {
         postage,
         wallet.chain().network(),
       )
+      .satisfaction_weight(wallet.satisfaction_weight())
       .build_transaction()?,
     )
   }
@@ -300,4 +319,162 @@ impl Send {
 
     Ok(unsigned_transaction)
   }
+
+  /// Send exactly the sats in `start..end`, which must be contiguous in a
+  /// single wallet output. The sats before the range are returned to a change
+  /// output, padded with a cardinal input if there would otherwise be too few
+  /// of them to avoid dust, and the sats after the range are returned to
+  /// change along with any additional inputs needed to pay the fee.
+  fn create_unsigned_send_sat_range_transaction(
+    wallet: &Wallet,
+    destination: Address,
+    start: Sat,
+    end: Sat,
+    fee_rate: FeeRate,
+  ) -> Result<Transaction> {
+    ensure!(
+      wallet.has_sat_index(),
+      "sending sat ranges requires index created with `--index-sats` flag",
+    );
+
+    ensure!(start < end, "sat range start must be less than end");
+
+    let (outpoint, offset) = wallet
+      .get_wallet_sat_ranges()?
+      .into_iter()
+      .find_map(|(outpoint, ranges)| {
+        let mut offset = 0;
+        for (range_start, range_end) in ranges {
+          if range_start <= start.n() && end.n() <= range_end {
+            return Some((outpoint, offset + start.n() - range_start));
+          }
+          offset += range_end - range_start;
+        }
+        None
+      })
+      .ok_or_else(|| anyhow!("sat range {start}-{end} not found in a single wallet output"))?;
+
+    let runic_outputs = wallet.get_runic_outputs()?;
+
+    ensure!(
+      !runic_outputs.contains(&outpoint),
+      "runic outpoints may not be sent by sat range"
+    );
+
+    wallet.lock_non_cardinal_outputs()?;
+
+    let size = end.n() - start.n();
+
+    let value = wallet.utxos()[&outpoint].value.to_sat();
+
+    let rest = value - offset - size;
+
+    let mut inscribed_after = false;
+
+    for (satpoint, inscriptions) in wallet.inscriptions() {
+      if satpoint.outpoint != outpoint {
+        continue;
+      }
+
+      ensure!(
+        satpoint.offset < offset || satpoint.offset >= offset + size,
+        "sat range {start}-{end} contains inscription {}",
+        inscriptions[0],
+      );
+
+      inscribed_after |= satpoint.offset >= offset + size;
+    }
+
+    let change = wallet.get_change_address()?;
+
+    let recipient = TxOut {
+      script_pubkey: destination.script_pubkey(),
+      value: Amount::from_sat(size),
+    };
+
+    let dust = recipient.script_pubkey.minimal_non_dust();
+
+    ensure!(
+      recipient.value >= dust,
+      "sat range {start}-{end} is {size} sats, which is below the dust limit of {dust}",
+    );
+
+    let change_dust = change.script_pubkey().minimal_non_dust().to_sat();
+
+    let mut input = Vec::new();
+    let mut output = Vec::new();
+
+    let mut before = offset;
+
+    if before > 0 && before < change_dust {
+      let unspendable = wallet.unspendable_outputs();
+
+      let inscribed = wallet
+        .inscriptions()
+        .keys()
+        .map(|satpoint| satpoint.outpoint)
+        .collect::<HashSet<OutPoint>>();
+
+      let (padding, tx_out) = wallet
+        .utxos()
+        .iter()
+        .filter(|(utxo, _tx_out)| {
+          **utxo != outpoint
+            && !unspendable.contains(*utxo)
+            && !inscribed.contains(*utxo)
+            && !runic_outputs.contains(*utxo)
+        })
+        .min_by_key(|(_utxo, tx_out)| tx_out.value)
+        .ok_or_else(|| anyhow!("wallet contains no cardinal utxos"))?;
+
+      input.push(*padding);
+      before += tx_out.value.to_sat();
+    }
+
+    input.push(outpoint);
+
+    if before > 0 {
+      output.push(TxOut {
+        script_pubkey: change.script_pubkey(),
+        value: Amount::from_sat(before),
+      });
+    }
+
+    output.push(recipient);
+
+    // sats after the range would otherwise go to the change output added by
+    // `fund_raw_transaction`, from the end of which the fee is paid, so keep
+    // them in their own output if that could burn an inscription
+    if inscribed_after {
+      ensure!(
+        rest >= change_dust,
+        "sat range {start}-{end} is followed by an inscription in too few sats to return to change",
+      );
+
+      output.push(TxOut {
+        script_pubkey: wallet.get_change_address()?.script_pubkey(),
+        value: Amount::from_sat(rest),
+      });
+    }
+
+    let unfunded_transaction = Transaction {
+      version: Version(2),
+      lock_time: LockTime::ZERO,
+      input: input
+        .into_iter()
+        .map(|previous_output| TxIn {
+          previous_output,
+          script_sig: ScriptBuf::new(),
+          sequence: Sequence::MAX,
+          witness: Witness::new(),
+        })
+        .collect(),
+      output,
+    };
+
+    let unsigned_transaction =
+      fund_raw_transaction(wallet.bitcoin_client(), fee_rate, &unfunded_transaction)?;
+
+    Ok(consensus::encode::deserialize(&unsigned_transaction)?)
+  }
 }
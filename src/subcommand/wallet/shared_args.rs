@@ -7,8 +7,21 @@ pub(super) struct SharedArgs {
     help = "Use <COMMIT_FEE_RATE> sats/vbyte for commit transaction.\nDefaults to <FEE_RATE> if unset."
   )]
   pub(crate) commit_fee_rate: Option<FeeRate>,
-  #[arg(long, help = "Compress inscription content with brotli.")]
-  pub(crate) compress: bool,
+  #[arg(
+    long,
+    conflicts_with_all = ["dry_run", "unsigned_psbt"],
+    help = "Print commit address and amount instead of creating commit transaction. Reveal with `ord wallet reveal` once a transaction paying the commit address has been broadcast."
+  )]
+  pub(crate) commit_only: bool,
+  #[arg(
+    long,
+    value_name = "ALGORITHM",
+    num_args = 0..=1,
+    require_equals = true,
+    default_missing_value = "brotli",
+    help = "Compress inscription content with <ALGORITHM>, brotli if omitted. Content is left uncompressed if compression does not make it smaller."
+  )]
+  pub(crate) compress: Option<Compression>,
   #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vB.")]
   pub(crate) fee_rate: FeeRate,
   #[arg(long, help = "Don't sign or broadcast transactions.")]
@@ -21,4 +34,11 @@ pub(super) struct SharedArgs {
     help = "Do not check that transactions are equal to or below the MAX_STANDARD_TX_WEIGHT of 400,000 weight units. Transactions over this limit are currently nonstandard and will not be relayed by bitcoind in its default configuration. Do not use this flag unless you understand the implications."
   )]
   pub(crate) no_limit: bool,
+  #[arg(
+    long,
+    value_name = "FILE",
+    conflicts_with = "dry_run",
+    help = "Write unsigned commit and reveal PSBTs to <FILE> instead of signing and broadcasting transactions. Sign with `ord wallet sign` and broadcast with `ord wallet broadcast`."
+  )]
+  pub(crate) unsigned_psbt: Option<PathBuf>,
 }
@@ -0,0 +1,53 @@
+use {super::*, crate::wallet::offline};
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct Output {
+  pub complete: bool,
+  pub psbts: Vec<String>,
+}
+
+#[derive(Debug, Parser)]
+pub(crate) struct Sign {
+  #[arg(help = "Sign PSBTs in <PSBT_FILE>, as written by `--unsigned-psbt`.")]
+  psbt_file: PathBuf,
+  #[arg(
+    long,
+    value_name = "FILE",
+    help = "Write signed PSBTs to <FILE>. [default: <PSBT_FILE>]"
+  )]
+  output: Option<PathBuf>,
+}
+
+impl Sign {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    ensure!(
+      !wallet.watch_only(),
+      "wallet is watch-only and cannot sign PSBTs"
+    );
+
+    let mut complete = true;
+    let mut signed = Vec::new();
+
+    for psbt in offline::read_psbts(&self.psbt_file)? {
+      wallet.check_psbt(&psbt)?;
+
+      let result = wallet.bitcoin_client().wallet_process_psbt(
+        &offline::encode_psbt(&psbt),
+        Some(true),
+        None,
+        None,
+      )?;
+
+      complete &= result.complete;
+
+      signed.push(offline::decode_psbt(&result.psbt)?);
+    }
+
+    offline::write_psbts(self.output.as_ref().unwrap_or(&self.psbt_file), &signed)?;
+
+    Ok(Some(Box::new(Output {
+      complete,
+      psbts: signed.iter().map(offline::encode_psbt).collect(),
+    })))
+  }
+}
@@ -0,0 +1,463 @@
+use {
+  super::*,
+  crate::wallet::offline,
+  bitcoin::{policy::MAX_STANDARD_TX_WEIGHT, psbt::Psbt},
+};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Split {
+  #[arg(long, help = "Don't sign or broadcast transactions.")]
+  pub(crate) dry_run: bool,
+  #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vB.")]
+  fee_rate: FeeRate,
+  #[arg(
+    long,
+    help = "Do not split outputs across transactions to keep runestones at or below 82 bytes and transactions at or below the MAX_STANDARD_TX_WEIGHT of 400,000 weight units. Transactions over these limits are currently nonstandard and will not be relayed by bitcoind in its default configuration. Do not use this flag unless you understand the implications."
+  )]
+  no_limit: bool,
+  #[arg(
+    long,
+    help = "Include <AMOUNT> postage with outputs which do not specify a value. [default: 10000 sat]",
+    value_name = "AMOUNT"
+  )]
+  postage: Option<Amount>,
+  #[arg(
+    long,
+    help = "Send runes to outputs listed in YAML <SPLIT_FILE>.",
+    value_name = "SPLIT_FILE"
+  )]
+  splits: PathBuf,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct Output {
+  pub fee: u64,
+  pub transactions: Vec<SplitTransaction>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct SplitTransaction {
+  pub fee: u64,
+  pub outputs: usize,
+  pub psbt: String,
+  pub txid: Txid,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Splitfile {
+  outputs: Vec<SplitfileOutput>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SplitfileOutput {
+  address: Address<NetworkUnchecked>,
+  runes: BTreeMap<SpacedRune, Decimal>,
+  value: Option<u64>,
+}
+
+struct Recipient {
+  edicts: Vec<(RuneId, u128)>,
+  output: TxOut,
+}
+
+impl Split {
+  const MAX_RUNESTONE_SIZE: usize = 82;
+
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    ensure!(
+      wallet.has_rune_index(),
+      "`ord wallet split` requires index created with `--index-runes` flag",
+    );
+
+    if !self.dry_run {
+      wallet.check_private_keys()?;
+    }
+
+    let splitfile: Splitfile = serde_yaml::from_reader(
+      fs::File::open(&self.splits)
+        .with_context(|| format!("failed to open split file `{}`", self.splits.display()))?,
+    )?;
+
+    ensure!(
+      !splitfile.outputs.is_empty(),
+      "split file must contain at least one output",
+    );
+
+    wallet.lock_non_cardinal_outputs()?;
+
+    let postage = self.postage.unwrap_or(TARGET_POSTAGE);
+
+    let mut entries = BTreeMap::new();
+    let mut required = BTreeMap::<Rune, u128>::new();
+    let mut recipients = Vec::new();
+
+    for output in splitfile.outputs {
+      let address = output.address.require_network(wallet.chain().network())?;
+
+      let mut edicts = Vec::new();
+
+      for (spaced_rune, decimal) in output.runes {
+        let (id, entry, _parent) = wallet
+          .get_rune(spaced_rune.rune)?
+          .with_context(|| format!("rune `{}` has not been etched", spaced_rune.rune))?;
+
+        let amount = decimal.to_integer(entry.divisibility)?;
+
+        ensure!(
+          amount > 0,
+          "split file output to {address} sends zero `{spaced_rune}`",
+        );
+
+        let total = required.entry(spaced_rune.rune).or_default();
+
+        *total = total
+          .checked_add(amount)
+          .ok_or_else(|| anyhow!("split file `{spaced_rune}` total overflows"))?;
+
+        entries.insert(spaced_rune.rune, entry);
+
+        edicts.push((id, amount));
+      }
+
+      ensure!(
+        !edicts.is_empty(),
+        "split file output to {address} does not send any runes",
+      );
+
+      let output = TxOut {
+        script_pubkey: address.script_pubkey(),
+        value: output.value.map(Amount::from_sat).unwrap_or(postage),
+      };
+
+      ensure!(
+        output.value >= output.script_pubkey.minimal_non_dust(),
+        "split file output to {address} value {} is below dust limit of {}",
+        output.value,
+        output.script_pubkey.minimal_non_dust(),
+      );
+
+      recipients.push(Recipient { edicts, output });
+    }
+
+    let inscribed_outputs = wallet
+      .inscriptions()
+      .keys()
+      .map(|satpoint| satpoint.outpoint)
+      .collect::<HashSet<OutPoint>>();
+
+    let mut inputs = Vec::new();
+    let mut balances = BTreeMap::<Rune, u128>::new();
+
+    for output in wallet.get_runic_outputs()? {
+      if required
+        .iter()
+        .all(|(rune, amount)| balances.get(rune).copied().unwrap_or_default() >= *amount)
+      {
+        break;
+      }
+
+      if inscribed_outputs.contains(&output) {
+        continue;
+      }
+
+      let runes = wallet.get_runes_balances_in_output(&output)?;
+
+      if !runes
+        .keys()
+        .any(|spaced_rune| required.contains_key(&spaced_rune.rune))
+      {
+        continue;
+      }
+
+      for (spaced_rune, pile) in runes {
+        *balances.entry(spaced_rune.rune).or_default() += pile.amount;
+      }
+
+      inputs.push(output);
+    }
+
+    for (rune, amount) in &required {
+      let balance = balances.get(rune).copied().unwrap_or_default();
+      let entry = &entries[rune];
+
+      ensure!(
+        balance >= *amount,
+        "insufficient `{}` balance, only {} in wallet",
+        entry.spaced_rune,
+        Pile {
+          amount: balance,
+          divisibility: entry.divisibility,
+          symbol: entry.symbol,
+        },
+      );
+    }
+
+    let leftover = balances
+      .iter()
+      .any(|(rune, balance)| *balance > required.get(rune).copied().unwrap_or_default());
+
+    let witness = TransactionBuilder::dummy_witness(wallet.satisfaction_weight());
+
+    let chunks = self.chunk(&recipients, inputs.len(), postage, &witness)?;
+
+    let change_addresses = chunks
+      .iter()
+      .map(|_| wallet.get_change_address())
+      .collect::<Result<Vec<Address>>>()?;
+
+    // transactions after the first spend the previous transaction's rune
+    // change output, which must carry enough value to fund every following
+    // transaction, so change values are computed from last to first
+    let mut change_values = vec![None; chunks.len()];
+    let mut next_input_value = None;
+
+    for (i, chunk) in chunks.iter().enumerate().rev() {
+      let change_value = next_input_value.or(leftover.then_some(postage));
+
+      change_values[i] = change_value;
+
+      if i > 0 {
+        let transaction = Self::chunk_transaction(
+          vec![OutPoint::null()],
+          chunk,
+          change_value.map(|value| TxOut {
+            script_pubkey: change_addresses[i].script_pubkey(),
+            value,
+          }),
+        );
+
+        let mut estimate = transaction.clone();
+        estimate.input[0].witness = witness.clone();
+
+        next_input_value = Some(
+          chunk
+            .iter()
+            .map(|recipient| recipient.output.value)
+            .sum::<Amount>()
+            + change_value.unwrap_or_default()
+            + self.fee_rate.fee(estimate.vsize()),
+        );
+      }
+    }
+
+    let mut transactions = Vec::new();
+    let mut previous: Option<(OutPoint, TxOut)> = None;
+
+    for ((chunk, change_value), change_address) in
+      chunks.iter().zip(change_values).zip(change_addresses)
+    {
+      let change = change_value.map(|value| TxOut {
+        script_pubkey: change_address.script_pubkey(),
+        value,
+      });
+
+      let (transaction, fee) = if let Some((outpoint, input)) = &previous {
+        let transaction = Self::chunk_transaction(vec![*outpoint], chunk, change.clone());
+
+        let fee = input.value
+          - transaction
+            .output
+            .iter()
+            .map(|output| output.value)
+            .sum::<Amount>();
+
+        (transaction, fee)
+      } else {
+        let transaction: Transaction = consensus::encode::deserialize(&fund_raw_transaction(
+          wallet.bitcoin_client(),
+          self.fee_rate,
+          &Self::chunk_transaction(inputs.clone(), chunk, change.clone()),
+        )?)?;
+
+        let fee = transaction
+          .input
+          .iter()
+          .map(|input| wallet.utxos()[&input.previous_output].value)
+          .sum::<Amount>()
+          - transaction
+            .output
+            .iter()
+            .map(|output| output.value)
+            .sum::<Amount>();
+
+        (transaction, fee)
+      };
+
+      assert_eq!(
+        Runestone::decipher(&transaction),
+        Some(Artifact::Runestone(Self::runestone(
+          chunk,
+          change.is_some()
+        ))),
+      );
+
+      let spent = previous.take().map(|(_outpoint, output)| output);
+
+      previous = change.map(|change| {
+        (
+          OutPoint {
+            txid: transaction.compute_txid(),
+            vout: (chunk.len() + 1).try_into().unwrap(),
+          },
+          change,
+        )
+      });
+
+      transactions.push((transaction, fee, chunk.len(), spent));
+    }
+
+    let mut output = Output {
+      fee: 0,
+      transactions: Vec::new(),
+    };
+
+    for (transaction, fee, outputs, spent) in transactions {
+      let mut psbt = Psbt::from_unsigned_tx(transaction.clone())?;
+
+      // the previous transaction has not been mined, so its output is included
+      psbt.inputs[0].witness_utxo = spent;
+
+      let psbt = if self.dry_run {
+        offline::encode_psbt(&psbt)
+      } else {
+        wallet.sign_and_broadcast(&psbt)?.1
+      };
+
+      output.fee += fee.to_sat();
+
+      output.transactions.push(SplitTransaction {
+        fee: fee.to_sat(),
+        outputs,
+        psbt,
+        txid: transaction.compute_txid(),
+      });
+    }
+
+    Ok(Some(Box::new(output)))
+  }
+
+  /// Split recipients into chunks, each of which fits in a transaction with a
+  /// standard size runestone and weight.
+  fn chunk<'a>(
+    &self,
+    recipients: &'a [Recipient],
+    inputs: usize,
+    postage: Amount,
+    witness: &Witness,
+  ) -> Result<Vec<&'a [Recipient]>> {
+    let check = |chunk: &[Recipient]| -> Result {
+      if self.no_limit {
+        return Ok(());
+      }
+
+      let change = TxOut {
+        script_pubkey: chunk[0].output.script_pubkey.clone(),
+        value: postage,
+      };
+
+      let mut transaction = Self::chunk_transaction(
+        vec![OutPoint::null(); inputs + 1],
+        chunk,
+        Some(change.clone()),
+      );
+
+      // leave room for change added when funding the first transaction
+      transaction.output.push(change);
+
+      for input in &mut transaction.input {
+        input.witness = witness.clone();
+      }
+
+      let runestone = transaction.output[0].script_pubkey.len();
+
+      ensure!(
+        runestone <= Self::MAX_RUNESTONE_SIZE,
+        "runestone greater than maximum OP_RETURN size: {runestone} > {}",
+        Self::MAX_RUNESTONE_SIZE,
+      );
+
+      let weight = transaction.weight().to_wu();
+
+      ensure!(
+        weight <= MAX_STANDARD_TX_WEIGHT.into(),
+        "transaction weight greater than {MAX_STANDARD_TX_WEIGHT} (MAX_STANDARD_TX_WEIGHT): {weight}",
+      );
+
+      Ok(())
+    };
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+
+    while start < recipients.len() {
+      check(&recipients[start..start + 1])
+        .with_context(|| format!("split file output {start} cannot be sent"))?;
+
+      let mut end = start + 1;
+
+      while end < recipients.len() && check(&recipients[start..end + 1]).is_ok() {
+        end += 1;
+      }
+
+      chunks.push(&recipients[start..end]);
+
+      start = end;
+    }
+
+    Ok(chunks)
+  }
+
+  fn runestone(chunk: &[Recipient], change: bool) -> Runestone {
+    let mut edicts = chunk
+      .iter()
+      .enumerate()
+      .flat_map(|(i, recipient)| {
+        recipient.edicts.iter().map(move |(id, amount)| Edict {
+          id: *id,
+          amount: *amount,
+          output: (i + 1).try_into().unwrap(),
+        })
+      })
+      .collect::<Vec<Edict>>();
+
+    // edicts are encoded in order of rune ID, so sort them the same way, in
+    // order for the runestone to match the one deciphered from the transaction
+    edicts.sort_by_key(|edict| edict.id);
+
+    Runestone {
+      edicts,
+      pointer: change.then(|| (chunk.len() + 1).try_into().unwrap()),
+      ..default()
+    }
+  }
+
+  fn chunk_transaction(
+    inputs: Vec<OutPoint>,
+    chunk: &[Recipient],
+    change: Option<TxOut>,
+  ) -> Transaction {
+    Transaction {
+      version: Version(2),
+      lock_time: LockTime::ZERO,
+      input: inputs
+        .into_iter()
+        .map(|previous_output| TxIn {
+          previous_output,
+          script_sig: ScriptBuf::new(),
+          sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+          witness: Witness::new(),
+        })
+        .collect(),
+      output: [TxOut {
+        script_pubkey: Self::runestone(chunk, change.is_some()).encipher(),
+        value: Amount::ZERO,
+      }]
+      .into_iter()
+      .chain(chunk.iter().map(|recipient| recipient.output.clone()))
+      .chain(change)
+      .collect(),
+    }
+  }
+}
@@ -1,34 +1,277 @@
-use super::*;
+use {super::*, crate::wallet::label, sats::Sats};
 
 #[derive(Debug, Parser)]
 pub(crate) struct Transactions {
   #[arg(long, help = "Fetch at most <LIMIT> transactions.")]
   limit: Option<u16>,
+  #[arg(
+    long,
+    help = "Only show transactions at or after <DATE>, for example `2024-01-01T00:00:00Z`."
+  )]
+  after: Option<DateTime<Utc>>,
+  #[arg(long, help = "Only show transactions before <DATE>.")]
+  before: Option<DateTime<Utc>>,
+  #[arg(
+    long,
+    help = "Only show transactions which create, receive, or send <INSCRIPTION>."
+  )]
+  inscription: Option<InscriptionId>,
+  #[arg(
+    long,
+    help = "Only show transactions which etch, mint, receive, or send <RUNE>."
+  )]
+  rune: Option<SpacedRune>,
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct Output {
   pub transaction: Txid,
   pub confirmations: i32,
+  pub timestamp: DateTime<Utc>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub label: Option<String>,
+  pub fee: Option<u64>,
+  pub counterparties: BTreeSet<Address<NetworkUnchecked>>,
+  pub inscriptions_created: Vec<InscriptionId>,
+  pub inscriptions_received: Vec<InscriptionId>,
+  pub inscriptions_sent: Vec<InscriptionId>,
+  pub rune_etched: Option<SpacedRune>,
+  pub rune_minted: Option<SpacedRune>,
+  pub runes_received: BTreeMap<SpacedRune, Decimal>,
+  pub runes_sent: BTreeMap<SpacedRune, Decimal>,
+  pub rare_sats: Vec<sats::OutputRare>,
+  pub unknown_outputs: Vec<OutPoint>,
 }
 
 impl Transactions {
   pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
     let client = wallet.bitcoin_client();
 
-    let mut output = Vec::new();
+    let labels = wallet.label_texts(label::Type::Tx)?;
+
+    let mut seen = HashSet::new();
+
+    // annotating a transaction takes several requests, so transactions are
+    // deduplicated and filtered by date first
+    let mut transactions = Vec::new();
     for tx in client.list_transactions(
       None,
       Some(self.limit.unwrap_or(u16::MAX).into()),
       None,
       None,
     )? {
-      output.push(Output {
-        transaction: tx.info.txid,
-        confirmations: tx.info.confirmations,
-      });
+      // Bitcoin Core lists transactions once per wallet input and output
+      if !seen.insert(tx.info.txid) {
+        continue;
+      }
+
+      let timestamp = timestamp(tx.info.blocktime.unwrap_or(tx.info.time));
+
+      if self.after.is_some_and(|after| timestamp < after)
+        || self.before.is_some_and(|before| timestamp >= before)
+      {
+        continue;
+      }
+
+      transactions.push((tx.info.txid, tx.info.confirmations, timestamp));
+    }
+
+    let mut output = Vec::new();
+    for (txid, confirmations, timestamp) in transactions {
+      let mut entry = Self::annotate(&wallet, txid)?;
+
+      entry.confirmations = confirmations;
+      entry.timestamp = timestamp;
+      entry.label = labels.get(&txid.to_string()).cloned();
+
+      if self.matches(&entry) {
+        output.push(entry);
+      }
     }
 
     Ok(Some(Box::new(output)))
   }
+
+  /// Describe what `txid` moved, ordinal-wise. Inscriptions, runes, and sats
+  /// are those currently in the transaction's outputs. The index does not
+  /// keep the contents of spent outputs, so outputs which have since been
+  /// spent by a later transaction are listed as unknown, rather than shown as
+  /// empty. Outputs not belonging to the wallet are only considered sent, and
+  /// their addresses counterparties, if the wallet funded the transaction.
+  /// Otherwise, the counterparties are the addresses of the inputs, if
+  /// the outputs they spend can be found.
+  fn annotate(wallet: &Wallet, txid: Txid) -> Result<Output> {
+    let result = wallet.bitcoin_client().get_transaction(&txid, None)?;
+
+    let transaction = result.transaction()?;
+
+    let mut output = Output {
+      transaction: txid,
+      confirmations: 0,
+      timestamp: timestamp(0),
+      label: None,
+      fee: result.fee.map(|fee| fee.unsigned_abs().to_sat()),
+      counterparties: BTreeSet::new(),
+      inscriptions_created: ParsedEnvelope::from_transaction(&transaction)
+        .iter()
+        .enumerate()
+        .map(|(index, _envelope)| InscriptionId {
+          txid,
+          index: index.try_into().unwrap(),
+        })
+        .collect(),
+      inscriptions_received: Vec::new(),
+      inscriptions_sent: Vec::new(),
+      rune_etched: None,
+      rune_minted: None,
+      runes_received: BTreeMap::new(),
+      runes_sent: BTreeMap::new(),
+      rare_sats: Vec::new(),
+      unknown_outputs: Vec::new(),
+    };
+
+    if let Some(Artifact::Runestone(runestone)) = Runestone::decipher(&transaction) {
+      output.rune_etched = runestone.etching.and_then(|etching| {
+        Some(SpacedRune {
+          rune: etching.rune?,
+          spacers: etching.spacers.unwrap_or_default(),
+        })
+      });
+
+      if let Some(id) = runestone.mint {
+        output.rune_minted = wallet
+          .get_rune_by_id(id)?
+          .map(|(_id, entry, _parent)| entry.spaced_rune);
+      }
+    }
+
+    // Bitcoin Core only reports fees of transactions funded by the wallet
+    let funded = output.fee.is_some();
+
+    let mut senders = BTreeSet::new();
+
+    if !funded && !transaction.is_coinbase() {
+      for input in &transaction.input {
+        senders.extend(
+          wallet
+            .get_tx_out(input.previous_output)?
+            .and_then(|prevout| {
+              Address::from_script(&prevout.script_pubkey, wallet.chain().network()).ok()
+            })
+            .map(|address| uncheck(&address)),
+        );
+      }
+    }
+
+    let mut recipients = BTreeSet::new();
+
+    for (vout, tx_out) in transaction.output.iter().enumerate() {
+      if tx_out.script_pubkey.is_op_return() {
+        continue;
+      }
+
+      let outpoint = OutPoint {
+        txid,
+        vout: vout.try_into().unwrap(),
+      };
+
+      let mine = wallet.is_mine(&tx_out.script_pubkey)?;
+
+      if !mine && !funded {
+        continue;
+      }
+
+      let info = match wallet.utxo_info(&outpoint) {
+        Some(info) => Some(info.clone()),
+        None => wallet.get_output_info(outpoint)?,
+      };
+
+      let (inscriptions, runes) = if mine {
+        (
+          &mut output.inscriptions_received,
+          &mut output.runes_received,
+        )
+      } else {
+        recipients.extend(
+          Address::from_script(&tx_out.script_pubkey, wallet.chain().network())
+            .ok()
+            .map(|address| uncheck(&address)),
+        );
+
+        (&mut output.inscriptions_sent, &mut output.runes_sent)
+      };
+
+      let Some(info) = info else {
+        // spent outputs are no longer indexed
+        if wallet
+          .bitcoin_client()
+          .get_tx_out(&txid, outpoint.vout, Some(true))?
+          .is_none()
+        {
+          output.unknown_outputs.push(outpoint);
+        }
+
+        continue;
+      };
+
+      inscriptions.extend(info.inscriptions);
+
+      for (spaced_rune, pile) in info.runes {
+        runes
+          .entry(spaced_rune)
+          .and_modify(|decimal: &mut Decimal| decimal.value += pile.amount)
+          .or_insert(Decimal {
+            value: pile.amount,
+            scale: pile.divisibility,
+          });
+      }
+
+      if mine {
+        if let Some(sat_ranges) = info.sat_ranges {
+          output.rare_sats.extend(
+            Sats::rare_sats(vec![(outpoint, sat_ranges)])
+              .into_iter()
+              .map(|(output, sat, offset, rarity)| sats::OutputRare {
+                sat,
+                output,
+                offset,
+                rarity,
+              }),
+          );
+        }
+      }
+    }
+
+    output.counterparties = if funded { recipients } else { senders };
+
+    Ok(output)
+  }
+
+  fn matches(&self, output: &Output) -> bool {
+    if let Some(inscription) = self.inscription {
+      if !output.inscriptions_created.contains(&inscription)
+        && !output.inscriptions_received.contains(&inscription)
+        && !output.inscriptions_sent.contains(&inscription)
+      {
+        return false;
+      }
+    }
+
+    if let Some(rune) = self.rune {
+      let rune = rune.rune;
+
+      if output.rune_etched.map(|etched| etched.rune) != Some(rune)
+        && output.rune_minted.map(|minted| minted.rune) != Some(rune)
+        && !output
+          .runes_received
+          .keys()
+          .chain(output.runes_sent.keys())
+          .any(|spaced_rune| spaced_rune.rune == rune)
+      {
+        return false;
+      }
+    }
+
+    true
+  }
 }
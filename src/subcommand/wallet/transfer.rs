@@ -0,0 +1,375 @@
+use {
+  super::*,
+  crate::wallet::offline,
+  bitcoin::{policy::MAX_STANDARD_TX_WEIGHT, psbt::Psbt},
+};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Transfer {
+  #[arg(
+    long,
+    default_value_t = 100,
+    help = "Send at most <BATCH_SIZE> inscriptions or sats per transaction."
+  )]
+  batch_size: usize,
+  #[arg(
+    long,
+    help = "Send <OUTGOING> without a destination in the transfer file to <ADDRESS>.",
+    value_name = "ADDRESS"
+  )]
+  destination: Option<Address<NetworkUnchecked>>,
+  #[arg(long, help = "Don't sign or broadcast transactions.")]
+  pub(crate) dry_run: bool,
+  #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vB.")]
+  fee_rate: FeeRate,
+  #[arg(
+    long,
+    help = "Target <AMOUNT> postage with sent inscriptions. [default: 10000 sat]",
+    value_name = "AMOUNT"
+  )]
+  postage: Option<Amount>,
+  #[arg(
+    long,
+    help = "Send inscriptions and sats listed in YAML <TRANSFER_FILE>.",
+    value_name = "TRANSFER_FILE"
+  )]
+  transfers: Option<PathBuf>,
+  #[arg(help = "Send <OUTGOING> inscriptions, satpoints, or sats to `--destination`.")]
+  outgoing: Vec<Outgoing>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct Output {
+  pub fee: u64,
+  pub transactions: Vec<TransferTransaction>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct TransferTransaction {
+  pub fee: u64,
+  pub outgoing: Vec<Outgoing>,
+  pub psbt: String,
+  pub txid: Txid,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Transferfile {
+  transfers: Vec<TransferfileEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TransferfileEntry {
+  destination: Option<Address<NetworkUnchecked>>,
+  outgoing: Outgoing,
+}
+
+impl Transfer {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    ensure!(self.batch_size > 0, "batch size must be greater than zero");
+
+    if !self.dry_run {
+      wallet.check_private_keys()?;
+    }
+
+    let network = wallet.chain().network();
+
+    let destination = self
+      .destination
+      .clone()
+      .map(|destination| destination.require_network(network))
+      .transpose()?;
+
+    let mut transfers = Vec::new();
+
+    for outgoing in &self.outgoing {
+      transfers.push((
+        outgoing.clone(),
+        destination
+          .clone()
+          .ok_or_else(|| anyhow!("sending {outgoing} requires `--destination`"))?,
+      ));
+    }
+
+    if let Some(path) = &self.transfers {
+      let transferfile: Transferfile = serde_yaml::from_reader(
+        fs::File::open(path)
+          .with_context(|| format!("failed to open transfer file `{}`", path.display()))?,
+      )?;
+
+      for entry in transferfile.transfers {
+        let destination = match entry.destination {
+          Some(destination) => destination.require_network(network)?,
+          None => destination.clone().ok_or_else(|| {
+            anyhow!(
+              "transfer file entry {} has no destination and `--destination` was not given",
+              entry.outgoing,
+            )
+          })?,
+        };
+
+        transfers.push((entry.outgoing, destination));
+      }
+    }
+
+    ensure!(!transfers.is_empty(), "no inscriptions or sats to transfer");
+
+    let mut satpoints = BTreeSet::new();
+
+    for (outgoing, _destination) in &transfers {
+      ensure!(
+        satpoints.insert(self.satpoint(&wallet, outgoing)?),
+        "{outgoing} is transferred more than once",
+      );
+    }
+
+    wallet.lock_non_cardinal_outputs()?;
+
+    let runic_outputs = wallet.get_runic_outputs()?;
+
+    let mut locked = wallet
+      .locked_utxos()
+      .keys()
+      .chain(runic_outputs.iter())
+      .cloned()
+      .chain(
+        wallet
+          .inscriptions()
+          .keys()
+          .map(|satpoint| satpoint.outpoint),
+      )
+      .collect::<BTreeSet<OutPoint>>();
+
+    let mut values = wallet.utxos().clone();
+    let mut spent = BTreeSet::new();
+
+    let mut output = Output {
+      fee: 0,
+      transactions: Vec::new(),
+    };
+
+    for batch in transfers.chunks(self.batch_size) {
+      let mut unfunded_transaction = Transaction {
+        version: Version(2),
+        lock_time: LockTime::ZERO,
+        input: Vec::new(),
+        output: Vec::new(),
+      };
+
+      for (outgoing, destination) in batch {
+        // each transfer is built at a zero fee rate, so its inputs and outputs
+        // carry the same value and can be concatenated without moving sats
+        // between transfers, and the fee is paid by inputs added by funding
+        let transaction =
+          self.transfer_transaction(&wallet, outgoing, destination, &runic_outputs, &spent)?;
+
+        spent.extend(transaction.input.iter().map(|input| input.previous_output));
+
+        unfunded_transaction.input.extend(transaction.input);
+        unfunded_transaction.output.extend(transaction.output);
+      }
+
+      let transaction: Transaction = consensus::encode::deserialize(&fund_raw_transaction(
+        wallet.bitcoin_client(),
+        self.fee_rate,
+        &unfunded_transaction,
+      )?)?;
+
+      self.check_destinations(&wallet, batch, &transaction, &values)?;
+
+      let weight = transaction.weight().to_wu();
+
+      ensure!(
+        weight <= MAX_STANDARD_TX_WEIGHT.into(),
+        "transaction weight greater than {MAX_STANDARD_TX_WEIGHT} (MAX_STANDARD_TX_WEIGHT): {weight}, use a smaller `--batch-size`",
+      );
+
+      let txid = transaction.compute_txid();
+
+      let fee = transaction
+        .input
+        .iter()
+        .map(|input| {
+          values
+            .get(&input.previous_output)
+            .map(|output| output.value)
+            .ok_or_else(|| anyhow!("input {} not found in wallet", input.previous_output))
+        })
+        .sum::<Result<Amount>>()?
+        - transaction
+          .output
+          .iter()
+          .map(|output| output.value)
+          .sum::<Amount>();
+
+      // dry run transactions are not broadcast, so nothing stops Bitcoin Core
+      // from funding this batch with inputs which funded an earlier one
+      for input in &transaction.input[unfunded_transaction.input.len()..] {
+        ensure!(
+          !spent.contains(&input.previous_output),
+          "batch would be funded with input {} which funds an earlier batch, use a larger `--batch-size`",
+          input.previous_output,
+        );
+      }
+
+      spent.extend(transaction.input.iter().map(|input| input.previous_output));
+
+      // keep later batches from being funded with inputs of this one
+      let inputs = transaction
+        .input
+        .iter()
+        .map(|input| input.previous_output)
+        .filter(|outpoint| locked.insert(*outpoint))
+        .collect::<Vec<OutPoint>>();
+
+      if !self.dry_run && !wallet.bitcoin_client().lock_unspent(&inputs)? {
+        bail!("failed to lock UTXOs");
+      }
+
+      for (vout, tx_out) in transaction.output.iter().enumerate() {
+        values.insert(
+          OutPoint {
+            txid,
+            vout: vout.try_into().unwrap(),
+          },
+          tx_out.clone(),
+        );
+      }
+
+      let psbt = Psbt::from_unsigned_tx(transaction)?;
+
+      let psbt = if self.dry_run {
+        offline::encode_psbt(&psbt)
+      } else {
+        wallet.sign_and_broadcast(&psbt)?.1
+      };
+
+      output.fee += fee.to_sat();
+
+      output.transactions.push(TransferTransaction {
+        fee: fee.to_sat(),
+        outgoing: batch.iter().map(|(outgoing, _)| outgoing.clone()).collect(),
+        psbt,
+        txid,
+      });
+    }
+
+    Ok(Some(Box::new(output)))
+  }
+
+  /// Check that each inscription or sat in `batch` is sent to its
+  /// destination by the funded `transaction`.
+  fn check_destinations(
+    &self,
+    wallet: &Wallet,
+    batch: &[(Outgoing, Address)],
+    transaction: &Transaction,
+    values: &BTreeMap<OutPoint, TxOut>,
+  ) -> Result {
+    for (outgoing, destination) in batch {
+      let satpoint = self.satpoint(wallet, outgoing)?;
+
+      let mut offset = 0;
+
+      for input in &transaction.input {
+        if input.previous_output == satpoint.outpoint {
+          break;
+        }
+
+        offset += values
+          .get(&input.previous_output)
+          .ok_or_else(|| anyhow!("input {} not found in wallet", input.previous_output))?
+          .value
+          .to_sat();
+      }
+
+      offset += satpoint.offset;
+
+      let mut start = 0;
+
+      let output = transaction.output.iter().find(|output| {
+        start += output.value.to_sat();
+        offset < start
+      });
+
+      ensure!(
+        output.map(|output| &output.script_pubkey) == Some(&destination.script_pubkey()),
+        "transfer transaction would not send {outgoing} to {destination}",
+      );
+    }
+
+    Ok(())
+  }
+
+  fn satpoint(&self, wallet: &Wallet, outgoing: &Outgoing) -> Result<SatPoint> {
+    match outgoing {
+      Outgoing::InscriptionId(id) => Ok(
+        wallet
+          .inscription_info()
+          .get(id)
+          .ok_or_else(|| anyhow!("inscription {id} not found"))?
+          .satpoint,
+      ),
+      Outgoing::SatPoint(satpoint) => {
+        ensure!(
+          !wallet.inscriptions().contains_key(satpoint),
+          "inscriptions must be sent by inscription ID",
+        );
+
+        Ok(*satpoint)
+      }
+      Outgoing::Sat(sat) => wallet.find_sat_in_outputs(*sat),
+      Outgoing::Amount(_) | Outgoing::Rune { .. } | Outgoing::SatRange { .. } => {
+        bail!("`ord wallet transfer` can only send inscriptions, satpoints, and sats")
+      }
+    }
+  }
+
+  fn transfer_transaction(
+    &self,
+    wallet: &Wallet,
+    outgoing: &Outgoing,
+    destination: &Address,
+    runic_outputs: &BTreeSet<OutPoint>,
+    spent: &BTreeSet<OutPoint>,
+  ) -> Result<Transaction> {
+    let satpoint = self.satpoint(wallet, outgoing)?;
+
+    ensure!(
+      !runic_outputs.contains(&satpoint.outpoint),
+      "runic outpoints may not be sent by satpoint"
+    );
+
+    ensure!(
+      !spent.contains(&satpoint.outpoint),
+      "{outgoing} is in an output already spent by another transfer",
+    );
+
+    let mut amounts = wallet.utxos().clone();
+    amounts.retain(|outpoint, _| !spent.contains(outpoint));
+
+    let postage = if let Some(postage) = self.postage {
+      Target::ExactPostage(postage)
+    } else {
+      Target::Postage
+    };
+
+    Ok(
+      TransactionBuilder::new(
+        satpoint,
+        wallet.inscriptions().clone(),
+        amounts,
+        wallet.unspendable_outputs(),
+        runic_outputs.clone(),
+        destination.script_pubkey(),
+        [wallet.get_change_address()?, wallet.get_change_address()?],
+        FeeRate::try_from(0.0)?,
+        postage,
+        wallet.chain().network(),
+      )
+      .satisfaction_weight(wallet.satisfaction_weight())
+      .build_transaction()?,
+    )
+  }
+}
@@ -25,8 +25,8 @@ pub(crate) use {
 };
 
 pub use {
-  blocks::BlocksHtml, rune::RuneHtml, runes::RunesHtml, status::StatusHtml,
-  transaction::TransactionHtml,
+  blocks::BlocksHtml, rune::RuneHtml, rune_activity::RuneActivityHtml, runes::RunesHtml,
+  runes_block::RunesBlockHtml, status::StatusHtml, transaction::TransactionHtml,
 };
 
 pub mod address;
@@ -47,7 +47,9 @@ mod parents;
 mod preview;
 mod rare;
 pub mod rune;
+pub mod rune_activity;
 pub mod runes;
+pub mod runes_block;
 pub mod sat;
 pub mod status;
 pub mod transaction;
@@ -119,6 +121,7 @@ mod tests {
         chain: Chain::Mainnet,
         csp_origin: Some("https://signet.ordinals.com".into()),
         domain: Some("signet.ordinals.com".into()),
+        index_runes: true,
         index_sats: true,
         ..default()
       }),),
@@ -133,6 +136,7 @@ mod tests {
     <meta property=twitter:card content=summary>
     <title>Foo</title>
     <link rel=alternate href=/feed.xml type=application/rss\+xml title='Inscription Feed'>
+    <link rel=alternate href=/runes/feed.xml type=application/rss\+xml title='Rune Feed'>
     <link rel=icon href=/static/favicon.png>
     <link rel=icon href=/static/favicon.svg>
     <link rel=stylesheet href=/static/index.css>
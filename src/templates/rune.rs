@@ -103,6 +103,8 @@ mod tests {
   <dd><a class=monospace href=/tx/0{64}>0{64}</a></dd>
   <dt>parent</dt>
   <dd><a class=monospace href=/inscription/0{64}i0>0{64}i0</a></dd>
+  <dt>activity</dt>
+  <dd><a href=/rune/B•CGDENLQRQWDSLRUGSNLBTMFIJAV/activity>activity</a></dd>
 </dl>
 "
     );
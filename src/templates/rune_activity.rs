@@ -0,0 +1,209 @@
+use {super::*, crate::index::RuneActivity};
+
+#[derive(Boilerplate, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RuneActivityHtml {
+  pub activity: Vec<RuneActivity>,
+  pub entry: RuneEntry,
+  pub id: RuneId,
+  pub more: bool,
+  pub next: Option<usize>,
+  pub prev: Option<usize>,
+  pub progress: MintProgress,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct MintProgress {
+  pub cap: u128,
+  pub end: Option<u64>,
+  pub end_timestamp: Option<u64>,
+  pub mintable: bool,
+  pub mints: u128,
+  pub mints_per_block: BTreeMap<u32, u64>,
+  pub remaining: u128,
+  pub remaining_blocks: Option<u64>,
+  pub start: Option<u64>,
+}
+
+impl MintProgress {
+  pub(crate) fn new(
+    entry: &RuneEntry,
+    height: u64,
+    mints_per_block: BTreeMap<u32, u64>,
+    end_timestamp: Option<u64>,
+  ) -> Self {
+    let cap = entry.terms.and_then(|terms| terms.cap).unwrap_or_default();
+
+    let end = entry.end();
+
+    Self {
+      cap,
+      end,
+      end_timestamp,
+      mintable: entry.mintable(height).is_ok(),
+      mints: entry.mints,
+      mints_per_block,
+      remaining: cap.saturating_sub(entry.mints),
+      remaining_blocks: end.map(|end| end.saturating_sub(height)),
+      start: entry.start(),
+    }
+  }
+}
+
+impl PageContent for RuneActivityHtml {
+  fn title(&self) -> String {
+    format!("Rune {} Activity", self.entry.spaced_rune)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, crate::index::RuneActivityKind};
+
+  fn entry() -> RuneEntry {
+    RuneEntry {
+      block: 10,
+      divisibility: 1,
+      mints: 2,
+      spaced_rune: SpacedRune {
+        rune: Rune(26),
+        spacers: 1,
+      },
+      symbol: Some('%'),
+      terms: Some(Terms {
+        amount: Some(100),
+        cap: Some(5),
+        height: (None, Some(20)),
+        offset: (None, None),
+      }),
+      ..default()
+    }
+  }
+
+  #[test]
+  fn mint_progress() {
+    assert_eq!(
+      MintProgress::new(&entry(), 15, [(12, 2)].into(), Some(3000)),
+      MintProgress {
+        cap: 5,
+        end: Some(20),
+        end_timestamp: Some(3000),
+        mintable: true,
+        mints: 2,
+        mints_per_block: [(12, 2)].into(),
+        remaining: 3,
+        remaining_blocks: Some(5),
+        start: None,
+      }
+    );
+
+    assert_eq!(
+      MintProgress::new(&entry(), 25, BTreeMap::new(), None).remaining_blocks,
+      Some(0),
+    );
+
+    assert!(!MintProgress::new(&entry(), 25, BTreeMap::new(), None).mintable);
+  }
+
+  #[test]
+  fn display() {
+    assert_regex_match!(
+      RuneActivityHtml {
+        activity: vec![
+          RuneActivity {
+            amount: 0,
+            height: 10,
+            kind: RuneActivityKind::Etching,
+            output: None,
+            transaction: 1,
+            txid: txid(1),
+          },
+          RuneActivity {
+            amount: 100,
+            height: 12,
+            kind: RuneActivityKind::Mint,
+            output: None,
+            transaction: 2,
+            txid: txid(2),
+          },
+          RuneActivity {
+            amount: 50,
+            height: 13,
+            kind: RuneActivityKind::Transfer,
+            output: Some(3),
+            transaction: 1,
+            txid: txid(3),
+          },
+        ],
+        entry: entry(),
+        id: RuneId { block: 10, tx: 1 },
+        more: false,
+        next: None,
+        prev: None,
+        progress: MintProgress::new(&entry(), 15, [(12, 2)].into(), Some(0)),
+      },
+      "<h1><a href=/rune/A•A>A•A</a> Activity</h1>
+<h2>Mint Progress</h2>
+<dl>
+  <dt>mints</dt>
+  <dd>2</dd>
+  <dt>cap</dt>
+  <dd>5</dd>
+  <dt>remaining</dt>
+  <dd>3</dd>
+  <dt>mintable</dt>
+  <dd>true</dd>
+  <dt>start</dt>
+  <dd>none</dd>
+  <dt>end</dt>
+  <dd><a href=/block/20>20</a></dd>
+  <dt>blocks remaining</dt>
+  <dd>5</dd>
+  <dt>end time</dt>
+  <dd><time>1970-01-01 00:00:00 UTC</time></dd>
+</dl>
+<h2>Mints per Block</h2>
+<table>
+  <tr>
+    <th>block</th>
+    <th>mints</th>
+  </tr>
+  <tr>
+    <td><a href=/block/12>12</a></td>
+    <td>2</td>
+  </tr>
+</table>
+<h2>Activity</h2>
+<table>
+  <tr>
+    <th>block</th>
+    <th>event</th>
+    <th>amount</th>
+    <th>transaction</th>
+  </tr>
+  <tr>
+    <td><a href=/block/10>10</a></td>
+    <td>etching</td>
+    <td>0\u{A0}%</td>
+    <td class=monospace><a href=/tx/1{64}>1{64}</a></td>
+  </tr>
+  <tr>
+    <td><a href=/block/12>12</a></td>
+    <td>mint</td>
+    <td>10\u{A0}%</td>
+    <td class=monospace><a href=/tx/2{64}>2{64}</a></td>
+  </tr>
+  <tr>
+    <td><a href=/block/13>13</a></td>
+    <td>transfer</td>
+    <td>5\u{A0}%</td>
+    <td class=monospace><a href=/output/3{64}:3>3{64}:3</a></td>
+  </tr>
+</table>
+<div class=center>
+  prev
+  next
+</div>
+"
+    );
+  }
+}
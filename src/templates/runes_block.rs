@@ -0,0 +1,151 @@
+use {super::*, crate::index::RuneActivity};
+
+#[derive(Boilerplate, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RunesBlockHtml {
+  pub activity: Vec<RunesBlockActivity>,
+  pub height: u32,
+  pub next: Option<u32>,
+  pub prev: Option<u32>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RunesBlockActivity {
+  pub activity: RuneActivity,
+  pub divisibility: u8,
+  pub id: RuneId,
+  pub spaced_rune: SpacedRune,
+  pub symbol: Option<char>,
+}
+
+impl RunesBlockActivity {
+  pub fn pile(&self) -> Pile {
+    Pile {
+      amount: self.activity.amount,
+      divisibility: self.divisibility,
+      symbol: self.symbol,
+    }
+  }
+}
+
+impl RunesBlockHtml {
+  pub(crate) fn new(
+    height: u32,
+    best_height: u32,
+    activity: Vec<(RuneId, RuneEntry, RuneActivity)>,
+  ) -> Self {
+    Self {
+      activity: activity
+        .into_iter()
+        .map(|(id, entry, activity)| RunesBlockActivity {
+          activity,
+          divisibility: entry.divisibility,
+          id,
+          spaced_rune: entry.spaced_rune,
+          symbol: entry.symbol,
+        })
+        .collect(),
+      height,
+      next: (best_height > height).then_some(height + 1),
+      prev: height.checked_sub(1),
+    }
+  }
+}
+
+impl PageContent for RunesBlockHtml {
+  fn title(&self) -> String {
+    format!("Runes in Block {}", self.height)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, crate::index::RuneActivityKind};
+
+  #[test]
+  fn display() {
+    assert_regex_match!(
+      RunesBlockHtml::new(
+        5,
+        6,
+        vec![
+          (
+            RuneId { block: 5, tx: 1 },
+            RuneEntry {
+              spaced_rune: SpacedRune {
+                rune: Rune(26),
+                spacers: 1,
+              },
+              ..default()
+            },
+            RuneActivity {
+              amount: 10,
+              height: 5,
+              kind: RuneActivityKind::Etching,
+              output: None,
+              transaction: 1,
+              txid: txid(1),
+            },
+          ),
+          (
+            RuneId { block: 3, tx: 1 },
+            RuneEntry {
+              divisibility: 1,
+              spaced_rune: SpacedRune {
+                rune: Rune(0),
+                spacers: 0,
+              },
+              symbol: Some('%'),
+              ..default()
+            },
+            RuneActivity {
+              amount: 25,
+              height: 5,
+              kind: RuneActivityKind::Mint,
+              output: None,
+              transaction: 2,
+              txid: txid(2),
+            },
+          ),
+        ],
+      ),
+      "<h1>Runes in <a href=/block/5>Block 5</a></h1>
+<table>
+  <tr>
+    <th>rune</th>
+    <th>event</th>
+    <th>amount</th>
+    <th>transaction</th>
+  </tr>
+  <tr>
+    <td><a href=/rune/A•A>A•A</a></td>
+    <td>etching</td>
+    <td>10\u{A0}¤</td>
+    <td class=monospace><a href=/tx/1{64}>1{64}</a></td>
+  </tr>
+  <tr>
+    <td><a href=/rune/A>A</a></td>
+    <td>mint</td>
+    <td>2.5\u{A0}%</td>
+    <td class=monospace><a href=/tx/2{64}>2{64}</a></td>
+  </tr>
+</table>
+<div class=center>
+  <a class=prev href=/runes/block/4>prev</a>
+  <a class=next href=/runes/block/6>next</a>
+</div>
+"
+    );
+  }
+
+  #[test]
+  fn first_and_last_block() {
+    assert_regex_match!(
+      RunesBlockHtml::new(0, 0, Vec::new()),
+      ".*<div class=center>
+  prev
+  next
+</div>
+"
+    );
+  }
+}
@@ -8,13 +8,20 @@ use {
     psbt::Psbt,
   },
   bitcoincore_rpc::bitcoincore_rpc_json::{ImportDescriptors, Timestamp},
-  entry::{EtchingEntry, EtchingEntryValue},
+  entry::{
+    BatchEntry, BatchEntryValue, EtchingEntry, EtchingEntryValue, RevealEntry, RevealEntryValue,
+  },
   fee_rate::FeeRate,
   index::entry::Entry,
   indicatif::{ProgressBar, ProgressStyle},
+  label::Label,
   log::log_enabled,
-  miniscript::descriptor::{DescriptorSecretKey, DescriptorXKey, Wildcard},
-  redb::{Database, DatabaseError, ReadableTable, RepairSession, StorageError, TableDefinition},
+  miniscript::descriptor::{DescriptorPublicKey, DescriptorSecretKey, DescriptorXKey, Wildcard},
+  offline::PsbtContents,
+  redb::{
+    Database, DatabaseError, ReadableTable, RepairSession, StorageError, TableDefinition,
+    TableError,
+  },
   reqwest::header,
   std::sync::Once,
   transaction_builder::TransactionBuilder,
@@ -22,12 +29,18 @@ use {
 
 pub mod batch;
 pub mod entry;
+pub mod label;
+pub mod offline;
 pub mod transaction_builder;
 pub mod wallet_constructor;
 
 const SCHEMA_VERSION: u64 = 1;
 
+define_table! { COMMIT_SCRIPT_TO_REVEAL, &[u8], RevealEntryValue }
+define_table! { COMMIT_TO_BATCH, &[u8; 32], BatchEntryValue }
+define_table! { LABELS, (&str, &str), &str }
 define_table! { RUNE_TO_ETCHING, u128, EtchingEntryValue }
+define_table! { TXID_TO_PSBT_CONTENTS, &[u8; 32], &[u8] }
 define_table! { STATISTICS, u64, u64 }
 
 #[derive(Copy, Clone)]
@@ -82,9 +95,12 @@ pub(crate) struct Wallet {
   ord_client: reqwest::blocking::Client,
   inscription_info: BTreeMap<InscriptionId, api::Inscription>,
   output_info: BTreeMap<OutPoint, api::Output>,
+  protected_outputs: BTreeSet<OutPoint>,
   inscriptions: BTreeMap<SatPoint, Vec<InscriptionId>>,
   locked_utxos: BTreeMap<OutPoint, TxOut>,
+  satisfaction_weight: Option<Weight>,
   settings: Settings,
+  watch_only: bool,
 }
 
 impl Wallet {
@@ -163,6 +179,47 @@ impl Wallet {
     &self.locked_utxos
   }
 
+  /// Outputs containing sats of the rarity given by `--protect-rarity` or
+  /// rarer, which are not spent as cardinal outputs.
+  pub(crate) fn protected_outputs(&self) -> &BTreeSet<OutPoint> {
+    &self.protected_outputs
+  }
+
+  /// Rare sats are always the first sat of a block subsidy, so only the first
+  /// sat of each range needs to be checked.
+  pub(crate) fn protected_outputs_with(
+    output_info: &BTreeMap<OutPoint, api::Output>,
+    rarity: Rarity,
+  ) -> BTreeSet<OutPoint> {
+    output_info
+      .iter()
+      .filter(|(_output, info)| {
+        info
+          .sat_ranges
+          .iter()
+          .flatten()
+          .any(|(start, _end)| Sat(*start).rarity() >= rarity)
+      })
+      .map(|(output, _info)| *output)
+      .collect()
+  }
+
+  /// Outputs containing sats rarer than common, which are only known if the
+  /// server has a sat index.
+  pub(crate) fn rare_outputs(&self) -> BTreeSet<OutPoint> {
+    Self::protected_outputs_with(&self.output_info, Rarity::Uncommon)
+  }
+
+  /// Outputs which may not be selected as cardinal inputs.
+  pub(crate) fn unspendable_outputs(&self) -> BTreeSet<OutPoint> {
+    self
+      .locked_utxos()
+      .keys()
+      .chain(self.protected_outputs())
+      .cloned()
+      .collect()
+  }
+
   pub(crate) fn lock_non_cardinal_outputs(&self) -> Result {
     let inscriptions = self
       .inscriptions()
@@ -181,8 +238,11 @@ impl Wallet {
       .keys()
       .filter(|utxo| inscriptions.contains(utxo))
       .chain(self.get_runic_outputs()?.iter())
+      .chain(self.protected_outputs())
       .cloned()
       .filter(|utxo| !locked.contains(utxo))
+      .collect::<BTreeSet<OutPoint>>()
+      .into_iter()
       .collect::<Vec<OutPoint>>();
 
     if !self.bitcoin_client().lock_unspent(&outputs)? {
@@ -216,6 +276,81 @@ impl Wallet {
     )
   }
 
+  /// Info for the unspent wallet output `output`, as fetched from the ord
+  /// server when the wallet was loaded.
+  pub(crate) fn utxo_info(&self, output: &OutPoint) -> Option<&api::Output> {
+    self.output_info.get(output)
+  }
+
+  /// Look up the value and script of `output`, which may be spent and need not
+  /// belong to the wallet. `getrawtransaction` only finds confirmed
+  /// transactions with `-txindex`, so the transaction is looked up in the
+  /// Bitcoin Core wallet, and otherwise the output is looked up in the index.
+  pub(crate) fn get_tx_out(&self, output: OutPoint) -> Result<Option<TxOut>> {
+    if let Ok(result) = self.bitcoin_client.get_transaction(&output.txid, None) {
+      return Ok(
+        result
+          .transaction()?
+          .output
+          .into_iter()
+          .nth(output.vout.into_usize()),
+      );
+    }
+
+    let response = self
+      .ord_client
+      .get(self.rpc_url.join(&format!("/output/{output}")).unwrap())
+      .send()?;
+
+    if !response.status().is_success() {
+      return Ok(None);
+    }
+
+    let info: api::Output = serde_json::from_str(&response.text()?)?;
+
+    Ok(Some(TxOut {
+      value: Amount::from_sat(info.value),
+      script_pubkey: info.script_pubkey,
+    }))
+  }
+
+  /// Look up `output`, which need not belong to the wallet. Returns `None` if
+  /// the output has not been indexed, for example because the transaction
+  /// which created it is unconfirmed.
+  pub(crate) fn get_output_info(&self, output: OutPoint) -> Result<Option<api::Output>> {
+    let response = self
+      .ord_client
+      .get(self.rpc_url.join(&format!("/output/{output}")).unwrap())
+      .send()?;
+
+    if !response.status().is_success() {
+      return Ok(None);
+    }
+
+    let info: api::Output = serde_json::from_str(&response.text()?)?;
+
+    Ok(info.indexed.then_some(info))
+  }
+
+  pub(crate) fn get_inscription(&self, inscription_id: InscriptionId) -> Result<api::Inscription> {
+    let response = self
+      .ord_client
+      .get(
+        self
+          .rpc_url
+          .join(&format!("/inscription/{inscription_id}"))
+          .unwrap(),
+      )
+      .send()?;
+
+    ensure!(
+      response.status().is_success(),
+      "inscription {inscription_id} not found"
+    );
+
+    Ok(serde_json::from_str(&response.text()?)?)
+  }
+
   pub(crate) fn get_parent_info(&self, parents: &[InscriptionId]) -> Result<Vec<ParentInfo>> {
     let mut parent_info = Vec::new();
     for parent_id in parents {
@@ -275,14 +410,20 @@ impl Wallet {
     &self,
     rune: Rune,
   ) -> Result<Option<(RuneId, RuneEntry, Option<InscriptionId>)>> {
+    self.fetch_rune(&SpacedRune { rune, spacers: 0 }.to_string())
+  }
+
+  pub(crate) fn get_rune_by_id(
+    &self,
+    id: RuneId,
+  ) -> Result<Option<(RuneId, RuneEntry, Option<InscriptionId>)>> {
+    self.fetch_rune(&id.to_string())
+  }
+
+  fn fetch_rune(&self, query: &str) -> Result<Option<(RuneId, RuneEntry, Option<InscriptionId>)>> {
     let response = self
       .ord_client
-      .get(
-        self
-          .rpc_url
-          .join(&format!("/rune/{}", SpacedRune { rune, spacers: 0 }))
-          .unwrap(),
-      )
+      .get(self.rpc_url.join(&format!("/rune/{query}")).unwrap())
       .send()?;
 
     if !response.status().is_success() {
@@ -304,6 +445,20 @@ impl Wallet {
     )
   }
 
+  pub(crate) fn is_mine(&self, script_pubkey: &Script) -> Result<bool> {
+    let Ok(address) = Address::from_script(script_pubkey, self.chain().network()) else {
+      return Ok(false);
+    };
+
+    Ok(
+      self
+        .bitcoin_client()
+        .get_address_info(&address)?
+        .is_mine
+        .unwrap_or_default(),
+    )
+  }
+
   pub(crate) fn has_sat_index(&self) -> bool {
     self.has_sat_index
   }
@@ -441,23 +596,113 @@ impl Wallet {
       .filter(|descriptor| descriptor.desc.starts_with("rawtr("))
       .count();
 
-    if tr != 2 || descriptors.len() != 2 + rawtr {
+    let multisig = descriptors.len() > rawtr
+      && descriptors
+        .iter()
+        .filter(|descriptor| !descriptor.desc.starts_with("rawtr("))
+        .all(|descriptor| Self::parse_multisig_descriptor(&descriptor.desc).is_some());
+
+    if (tr != 2 || descriptors.len() != 2 + rawtr) && !multisig {
       bail!("wallet \"{}\" contains unexpected output descriptors, and does not appear to be an `ord` wallet, create a new wallet with `ord wallet create`", wallet_name);
     }
 
     Ok(descriptors)
   }
 
+  /// Multisig and miniscript descriptors are `wsh` descriptors, or `tr`
+  /// descriptors with script trees. Private keys for some, all, or none of
+  /// their keys may be present.
+  fn parse_multisig_descriptor(
+    descriptor: &str,
+  ) -> Option<miniscript::Descriptor<DescriptorPublicKey>> {
+    let (descriptor, _key_map) =
+      miniscript::Descriptor::parse_descriptor(&Secp256k1::new(), descriptor).ok()?;
+
+    match &descriptor {
+      miniscript::Descriptor::Wsh(_) => Some(descriptor),
+      miniscript::Descriptor::Tr(tr) if tr.tap_tree().is_some() => Some(descriptor),
+      _ => None,
+    }
+  }
+
+  /// The maximum weight of a witness spending from any descriptor in
+  /// `descriptors`, used to estimate fees. `None` if the wallet contains only
+  /// taproot key path descriptors, whose witnesses are a single Schnorr
+  /// signature.
+  pub(crate) fn descriptor_satisfaction_weight(
+    descriptors: &[Descriptor],
+  ) -> Result<Option<Weight>> {
+    let mut satisfaction_weight = None;
+
+    for descriptor in descriptors {
+      let Ok((descriptor, _key_map)) =
+        miniscript::Descriptor::<DescriptorPublicKey>::parse_descriptor(
+          &Secp256k1::new(),
+          &descriptor.desc,
+        )
+      else {
+        continue;
+      };
+
+      if let miniscript::Descriptor::Tr(tr) = &descriptor {
+        if tr.tap_tree().is_none() {
+          continue;
+        }
+      }
+
+      satisfaction_weight = satisfaction_weight.max(Some(descriptor.max_weight_to_satisfy()?));
+    }
+
+    Ok(satisfaction_weight)
+  }
+
+  /// Watch-only wallets may contain any number of public `tr`, `wpkh`, or
+  /// `wsh` descriptors, since they need not have been created by `ord`.
+  fn check_watch_only_descriptors(descriptors: Vec<Descriptor>) -> Result<Vec<Descriptor>> {
+    ensure!(
+      !descriptors.is_empty(),
+      "watch-only wallet must contain at least one descriptor"
+    );
+
+    for descriptor in &descriptors {
+      let parsed = miniscript::Descriptor::<DescriptorPublicKey>::from_str(&descriptor.desc)
+        .with_context(|| {
+          format!(
+            "watch-only wallet descriptor `{}` is not a public descriptor",
+            descriptor.desc
+          )
+        })?;
+
+      ensure!(
+        matches!(
+          parsed,
+          miniscript::Descriptor::Tr(_)
+            | miniscript::Descriptor::Wpkh(_)
+            | miniscript::Descriptor::Wsh(_)
+        ),
+        "watch-only wallet descriptor `{}` is not a `tr`, `wpkh`, or `wsh` descriptor",
+        descriptor.desc,
+      );
+    }
+
+    Ok(descriptors)
+  }
+
   pub(crate) fn initialize_from_descriptors(
     name: String,
     settings: &Settings,
     descriptors: Vec<Descriptor>,
+    watch_only: bool,
   ) -> Result {
     let client = Self::check_version(settings.bitcoin_rpc_client(Some(name.clone()))?)?;
 
-    let descriptors = Self::check_descriptors(&name, descriptors)?;
+    let descriptors = if watch_only {
+      Self::check_watch_only_descriptors(descriptors)?
+    } else {
+      Self::check_descriptors(&name, descriptors)?
+    };
 
-    client.create_wallet(&name, None, Some(true), None, None)?;
+    client.create_wallet(&name, Some(watch_only), Some(true), None, None)?;
 
     let descriptors = descriptors
       .into_iter()
@@ -667,7 +912,11 @@ impl Wallet {
 
         let tx = database.begin_write()?;
 
+        tx.open_table(COMMIT_SCRIPT_TO_REVEAL)?;
+        tx.open_table(COMMIT_TO_BATCH)?;
+        tx.open_table(LABELS)?;
         tx.open_table(RUNE_TO_ETCHING)?;
+        tx.open_table(TXID_TO_PSBT_CONTENTS)?;
 
         tx.open_table(STATISTICS)?
           .insert(&Statistic::Schema.key(), &SCHEMA_VERSION)?;
@@ -740,14 +989,324 @@ impl Wallet {
     )
   }
 
+  pub(crate) fn save_reveal(&self, commit_script: &Script, entry: RevealEntry) -> Result {
+    let wtx = self.database.begin_write()?;
+
+    wtx
+      .open_table(COMMIT_SCRIPT_TO_REVEAL)?
+      .insert(commit_script.as_bytes(), entry.store())?;
+
+    wtx.commit()?;
+
+    Ok(())
+  }
+
+  /// Wallet databases created before reveals were saved have no reveal table
+  /// until the first reveal is saved.
+  pub(crate) fn load_reveal(&self, commit_script: &Script) -> Result<Option<RevealEntry>> {
+    let rtx = self.database.begin_read()?;
+
+    let table = match rtx.open_table(COMMIT_SCRIPT_TO_REVEAL) {
+      Ok(table) => table,
+      Err(TableError::TableDoesNotExist(_)) => return Ok(None),
+      Err(err) => return Err(err.into()),
+    };
+
+    Ok(
+      table
+        .get(commit_script.as_bytes())?
+        .map(|result| RevealEntry::load(result.value())),
+    )
+  }
+
+  pub(crate) fn clear_reveal(&self, commit_script: &Script) -> Result {
+    let wtx = self.database.begin_write()?;
+
+    wtx
+      .open_table(COMMIT_SCRIPT_TO_REVEAL)?
+      .remove(commit_script.as_bytes())?;
+    wtx.commit()?;
+
+    Ok(())
+  }
+
+  /// Save an inscription batch before its commit transaction is broadcast, so
+  /// that it can be completed with `ord wallet resume` if `ord` is
+  /// interrupted before its reveal transaction is broadcast.
+  pub(crate) fn save_batch(&self, entry: BatchEntry) -> Result {
+    let wtx = self.database.begin_write()?;
+
+    wtx
+      .open_table(COMMIT_TO_BATCH)?
+      .insert(&entry.commit.compute_txid().to_byte_array(), entry.store())?;
+
+    wtx.commit()?;
+
+    Ok(())
+  }
+
+  pub(crate) fn clear_batch(&self, commit: Txid) -> Result {
+    let wtx = self.database.begin_write()?;
+
+    wtx
+      .open_table(COMMIT_TO_BATCH)?
+      .remove(&commit.to_byte_array())?;
+    wtx.commit()?;
+
+    Ok(())
+  }
+
+  /// Wallet databases created before inscription batches were saved have no
+  /// batch table until the first batch is saved.
+  pub(crate) fn pending_batches(&self) -> Result<Vec<BatchEntry>> {
+    let rtx = self.database.begin_read()?;
+
+    let table = match rtx.open_table(COMMIT_TO_BATCH) {
+      Ok(table) => table,
+      Err(TableError::TableDoesNotExist(_)) => return Ok(Vec::new()),
+      Err(err) => return Err(err.into()),
+    };
+
+    Ok(
+      table
+        .iter()?
+        .map(|result| result.map(|(_key, value)| BatchEntry::load(value.value())))
+        .collect::<Result<Vec<BatchEntry>, StorageError>>()?,
+    )
+  }
+
+  /// Broadcast the commit transaction of a saved inscription batch, if it was
+  /// not broadcast, and then its reveal transaction. Returns `None`, and
+  /// discards the batch, if the commit transaction can never be broadcast,
+  /// because one of its inputs is missing or spent, or its output was spent by
+  /// a transaction other than the reveal transaction.
+  pub(crate) fn send_batch(&self, entry: &BatchEntry) -> Result<Option<batch::Output>> {
+    let client = self.bitcoin_client();
+
+    let commit = entry.commit.compute_txid();
+    let reveal = entry.reveal.compute_txid();
+
+    if client
+      .get_transaction(&commit, Some(true))
+      .into_option()?
+      .is_none()
+    {
+      if let Err(err) = client.send_raw_transaction(&entry.commit) {
+        // other errors, such as those connecting to Bitcoin Core, may be
+        // transient, so the batch is kept, and may be resumed again later
+        for input in &entry.commit.input {
+          if client
+            .get_tx_out(
+              &input.previous_output.txid,
+              input.previous_output.vout,
+              Some(true),
+            )?
+            .is_none()
+          {
+            eprintln!(
+              "Commit transaction {commit} of inscription batch could not be broadcast: {err}"
+            );
+            self.clear_batch(commit)?;
+            return Ok(None);
+          }
+        }
+
+        return Err(anyhow!(err).context(format!("failed to send commit transaction {commit}")));
+      }
+    }
+
+    if client
+      .get_transaction(&reveal, Some(true))
+      .into_option()?
+      .is_none()
+    {
+      let commit_output = entry
+        .reveal
+        .input
+        .iter()
+        .map(|input| input.previous_output)
+        .find(|output| output.txid == commit)
+        .ok_or_else(|| anyhow!("reveal transaction {reveal} does not spend commit {commit}"))?;
+
+      if client
+        .get_tx_out(&commit, commit_output.vout, Some(true))?
+        .is_none()
+      {
+        eprintln!("Commitment for inscription batch {commit_output} spent");
+        self.clear_batch(commit)?;
+        return Ok(None);
+      }
+
+      client
+        .send_raw_transaction(&entry.reveal)
+        .with_context(|| format!("failed to send reveal transaction {reveal}"))?;
+    }
+
+    self.clear_batch(commit)?;
+
+    Ok(Some(batch::Output {
+      reveal_broadcast: true,
+      ..entry.output.clone()
+    }))
+  }
+
+  pub(crate) fn save_psbt_contents(&self, txid: Txid, contents: &PsbtContents) -> Result {
+    let wtx = self.database.begin_write()?;
+
+    wtx.open_table(TXID_TO_PSBT_CONTENTS)?.insert(
+      &txid.to_byte_array(),
+      serde_json::to_vec(contents)?.as_slice(),
+    )?;
+
+    wtx.commit()?;
+
+    Ok(())
+  }
+
+  /// Wallet databases created before PSBT contents were saved have no PSBT
+  /// contents table until the first unsigned PSBT is created.
+  pub(crate) fn load_psbt_contents(&self, txid: Txid) -> Result<Option<PsbtContents>> {
+    let rtx = self.database.begin_read()?;
+
+    let table = match rtx.open_table(TXID_TO_PSBT_CONTENTS) {
+      Ok(table) => table,
+      Err(TableError::TableDoesNotExist(_)) => return Ok(None),
+      Err(err) => return Err(err.into()),
+    };
+
+    table
+      .get(&txid.to_byte_array())?
+      .map(|value| Ok(serde_json::from_slice(value.value())?))
+      .transpose()
+  }
+
+  pub(crate) fn clear_psbt_contents(&self, txid: Txid) -> Result {
+    let wtx = self.database.begin_write()?;
+
+    wtx
+      .open_table(TXID_TO_PSBT_CONTENTS)?
+      .remove(&txid.to_byte_array())?;
+    wtx.commit()?;
+
+    Ok(())
+  }
+
+  pub(crate) fn save_labels(&self, labels: &[Label]) -> Result {
+    let wtx = self.database.begin_write()?;
+
+    {
+      let mut table = wtx.open_table(LABELS)?;
+
+      for label in labels {
+        table.insert(
+          (label.r#type.to_string().as_str(), label.r#ref.as_str()),
+          serde_json::to_string(label)?.as_str(),
+        )?;
+      }
+    }
+
+    wtx.commit()?;
+
+    Ok(())
+  }
+
+  /// Imported labels, ordered by type and reference. Wallet databases created
+  /// before labels were supported have no label table until the first import.
+  pub(crate) fn labels(&self) -> Result<Vec<Label>> {
+    let rtx = self.database.begin_read()?;
+
+    let table = match rtx.open_table(LABELS) {
+      Ok(table) => table,
+      Err(TableError::TableDoesNotExist(_)) => return Ok(Vec::new()),
+      Err(err) => return Err(err.into()),
+    };
+
+    table
+      .iter()?
+      .map(|result| Ok(serde_json::from_str(result?.1.value())?))
+      .collect()
+  }
+
+  /// Text of imported labels of type `r#type`, keyed by reference.
+  pub(crate) fn label_texts(&self, r#type: label::Type) -> Result<BTreeMap<String, String>> {
+    Ok(
+      self
+        .labels()?
+        .into_iter()
+        .filter(|label| label.r#type == r#type)
+        .filter_map(|label| Some((label.r#ref, label.label?)))
+        .collect(),
+    )
+  }
+
+  pub(crate) fn watch_only(&self) -> bool {
+    self.watch_only
+  }
+
+  pub(crate) fn satisfaction_weight(&self) -> Option<Weight> {
+    self.satisfaction_weight
+  }
+
+  /// Multisig wallets may not hold enough keys to fully sign transactions, in
+  /// which case they must be signed by co-signers with `ord wallet sign`.
+  pub(crate) fn check_complete(complete: bool) -> Result {
+    ensure!(
+      complete,
+      "wallet cannot fully sign transaction, use `--unsigned-psbt` to write unsigned PSBTs and `ord wallet sign` to add signatures from each co-signer",
+    );
+    Ok(())
+  }
+
+  pub(crate) fn check_private_keys(&self) -> Result {
+    ensure!(
+      !self.watch_only,
+      "wallet is watch-only, use `--unsigned-psbt` to write unsigned PSBTs instead",
+    );
+    Ok(())
+  }
+
+  /// Sign `psbt` with wallet keys and broadcast it, returning the txid and the
+  /// signed PSBT.
+  pub(crate) fn sign_and_broadcast(&self, psbt: &Psbt) -> Result<(Txid, String)> {
+    let result = self.bitcoin_client().wallet_process_psbt(
+      &offline::encode_psbt(psbt),
+      Some(true),
+      None,
+      None,
+    )?;
+
+    Self::check_complete(result.complete)?;
+
+    let signed_tx = self
+      .bitcoin_client()
+      .finalize_psbt(&result.psbt, None)?
+      .hex
+      .ok_or_else(|| anyhow!("unable to sign transaction"))?;
+
+    Ok((
+      self.bitcoin_client().send_raw_transaction(&signed_tx)?,
+      result.psbt,
+    ))
+  }
+
   pub(super) fn sign_transaction(
     &self,
     unsigned_transaction: Transaction,
     dry_run: bool,
+    unsigned_psbt: Option<&Path>,
   ) -> Result<(Txid, String, u64)> {
     let unspent_outputs = self.utxos();
 
-    let (txid, psbt) = if dry_run {
+    let (txid, psbt) = if let Some(path) = unsigned_psbt {
+      let psbt = self.unsigned_psbt(&unsigned_transaction)?;
+
+      offline::write_psbts(path, std::slice::from_ref(&psbt))?;
+
+      (
+        unsigned_transaction.compute_txid(),
+        offline::encode_psbt(&psbt),
+      )
+    } else if dry_run {
       let psbt = self
         .bitcoin_client()
         .wallet_process_psbt(
@@ -761,27 +1320,9 @@ impl Wallet {
 
       (unsigned_transaction.compute_txid(), psbt)
     } else {
-      let psbt = self
-        .bitcoin_client()
-        .wallet_process_psbt(
-          &base64::engine::general_purpose::STANDARD
-            .encode(Psbt::from_unsigned_tx(unsigned_transaction.clone())?.serialize()),
-          Some(true),
-          None,
-          None,
-        )?
-        .psbt;
+      self.check_private_keys()?;
 
-      let signed_tx = self
-        .bitcoin_client()
-        .finalize_psbt(&psbt, None)?
-        .hex
-        .ok_or_else(|| anyhow!("unable to sign transaction"))?;
-
-      (
-        self.bitcoin_client().send_raw_transaction(&signed_tx)?,
-        psbt,
-      )
+      self.sign_and_broadcast(&Psbt::from_unsigned_tx(unsigned_transaction.clone())?)?
     };
 
     let mut fee = 0;
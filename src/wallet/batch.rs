@@ -5,13 +5,16 @@ use {
     key::PrivateKey,
     key::{TapTweak, TweakedKeypair, TweakedPublicKey, UntweakedKeypair},
     policy::MAX_STANDARD_TX_WEIGHT,
-    secp256k1::{self, constants::SCHNORR_SIGNATURE_SIZE, rand, Secp256k1, XOnlyPublicKey},
+    secp256k1::{self, constants::SCHNORR_SIGNATURE_SIZE, rand, All, Secp256k1, XOnlyPublicKey},
     sighash::{Prevouts, SighashCache, TapSighashType},
     taproot::Signature,
-    taproot::{ControlBlock, LeafVersion, TapLeafHash, TaprootBuilder},
+    taproot::{ControlBlock, LeafVersion, TapLeafHash, TaprootBuilder, TaprootSpendInfo},
   },
   bitcoincore_rpc::bitcoincore_rpc_json::{ImportDescriptors, SignRawTransactionInput, Timestamp},
-  wallet::transaction_builder::Target,
+  wallet::{
+    entry::{BatchEntry, RevealEntry},
+    transaction_builder::Target,
+  },
 };
 
 pub(crate) use transactions::Transactions;
@@ -38,10 +41,47 @@ pub struct Output {
   pub reveal: Txid,
   pub reveal_broadcast: bool,
   pub reveal_psbt: Option<String>,
+  #[serde(default)]
+  pub reveals: Vec<RevealOutput>,
   pub rune: Option<RuneInfo>,
   pub total_fees: u64,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RevealOutput {
+  pub commit: Txid,
+  pub commit_psbt: Option<String>,
+  pub reveal: Txid,
+  pub reveal_psbt: Option<String>,
+  pub total_fees: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Estimate {
+  pub commit_vsize: u64,
+  pub fees: Vec<EstimateFees>,
+  pub inscriptions: u64,
+  pub postage: u64,
+  pub reveal_vsize: u64,
+  pub reveal_weight: u64,
+  pub reveals: Vec<u64>,
+  pub standard: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct EstimateFees {
+  pub fee_rate: f64,
+  pub commit: u64,
+  pub reveal: u64,
+  pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CommitOutput {
+  pub address: Address<NetworkUnchecked>,
+  pub amount: u64,
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct InscriptionInfo {
   pub destination: Address<NetworkUnchecked>,
@@ -110,7 +110,7 @@ impl File {
     wallet: &Wallet,
     utxos: &BTreeMap<OutPoint, TxOut>,
     parent_values: Vec<u64>,
-    compress: bool,
+    compress: Option<Compression>,
   ) -> Result<(
     Vec<Inscription>,
     Vec<(SatPoint, TxOut)>,
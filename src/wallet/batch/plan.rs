@@ -2,6 +2,7 @@ use super::*;
 
 pub struct Plan {
   pub(crate) commit_fee_rate: FeeRate,
+  pub(crate) commit_only: bool,
   pub(crate) destinations: Vec<Address>,
   pub(crate) dry_run: bool,
   pub(crate) etching: Option<Etching>,
@@ -14,13 +15,16 @@ pub struct Plan {
   pub(crate) reinscribe: bool,
   pub(crate) reveal_fee_rate: FeeRate,
   pub(crate) reveal_satpoints: Vec<(SatPoint, TxOut)>,
+  pub(crate) satisfaction_weight: Option<Weight>,
   pub(crate) satpoint: Option<SatPoint>,
+  pub(crate) unsigned_psbt: Option<PathBuf>,
 }
 
 impl Default for Plan {
   fn default() -> Self {
     Self {
       commit_fee_rate: 1.0.try_into().unwrap(),
+      commit_only: false,
       destinations: Vec::new(),
       dry_run: false,
       etching: None,
@@ -33,7 +37,9 @@ impl Default for Plan {
       reinscribe: false,
       reveal_fee_rate: 1.0.try_into().unwrap(),
       reveal_satpoints: Vec::new(),
+      satisfaction_weight: None,
       satpoint: None,
+      unsigned_psbt: None,
     }
   }
 }
@@ -46,6 +52,10 @@ impl Plan {
     utxos: &BTreeMap<OutPoint, TxOut>,
     wallet: &Wallet,
   ) -> SubcommandResult {
+    if self.commit_only {
+      return self.inscribe_commit_only(wallet);
+    }
+
     let Transactions {
       commit_tx,
       commit_vout,
@@ -53,16 +63,31 @@ impl Plan {
       recovery_key_pair,
       total_fees,
       rune,
-    } = self.create_batch_transactions(
+    } = self.build_batch_transactions(
       wallet.inscriptions().clone(),
       wallet.chain(),
       locked_utxos.clone(),
-      runic_utxos,
+      runic_utxos.clone(),
       utxos.clone(),
       [wallet.get_change_address()?, wallet.get_change_address()?],
       wallet.get_change_address()?,
     )?;
 
+    if !self.no_limit {
+      if let Err(err) = self.check_reveal_weight(&reveal_tx) {
+        let reveals = self.split(
+          self.reveal_weight(reveal_tx.clone()),
+          MAX_STANDARD_TX_WEIGHT.into(),
+        );
+
+        if reveals.len() > 1 {
+          return self.inscribe_chunks(&reveals, locked_utxos, runic_utxos, utxos, wallet);
+        }
+
+        return Err(err);
+      }
+    }
+
     if self.dry_run {
       let commit_psbt = wallet
         .bitcoin_client()
@@ -89,10 +114,48 @@ impl Plan {
       ))));
     }
 
-    let signed_commit_tx = wallet
+    if let Some(path) = &self.unsigned_psbt {
+      ensure!(
+        rune.is_none(),
+        "--unsigned-psbt cannot be used to etch runes"
+      );
+
+      let commit_psbt = wallet.unsigned_psbt(&Self::remove_witnesses(commit_tx.clone()))?;
+
+      let reveal_psbt = Self::unsigned_reveal_psbt(wallet, &commit_tx, &reveal_tx, utxos)?;
+
+      ensure!(
+        self.no_backup || !wallet.watch_only(),
+        "recovery key cannot be backed up to watch-only wallet, use `--no-backup`",
+      );
+
+      if !self.no_backup {
+        Self::backup_recovery_key(wallet, recovery_key_pair)?;
+      }
+
+      offline::write_psbts(path, &[commit_psbt.clone(), reveal_psbt.clone()])?;
+
+      return Ok(Some(Box::new(self.output(
+        commit_tx.compute_txid(),
+        Some(offline::encode_psbt(&commit_psbt)),
+        reveal_tx.compute_txid(),
+        false,
+        Some(offline::encode_psbt(&reveal_psbt)),
+        total_fees,
+        self.inscriptions.clone(),
+        rune,
+      ))));
+    }
+
+    wallet.check_private_keys()?;
+
+    let result = wallet
       .bitcoin_client()
-      .sign_raw_transaction_with_wallet(&commit_tx, None, None)?
-      .hex;
+      .sign_raw_transaction_with_wallet(&commit_tx, None, None)?;
+
+    Wallet::check_complete(result.complete)?;
+
+    let signed_commit_tx = result.hex;
 
     let result = wallet.bitcoin_client().sign_raw_transaction_with_wallet(
       &reveal_tx,
@@ -124,9 +187,48 @@ impl Plan {
       Self::backup_recovery_key(wallet, recovery_key_pair)?;
     }
 
-    let commit_txid = wallet
+    let commit = consensus::encode::deserialize::<Transaction>(&signed_commit_tx)?;
+    let reveal = consensus::encode::deserialize::<Transaction>(&signed_reveal_tx)?;
+
+    let output = self.output(
+      commit.compute_txid(),
+      None,
+      reveal.compute_txid(),
+      false,
+      None,
+      total_fees,
+      self.inscriptions.clone(),
+      rune.clone(),
+    );
+
+    // save transactions before broadcasting the commit, so the batch can be
+    // resumed if we are interrupted before the reveal is broadcast
+    if let Some(ref rune_info) = rune {
+      wallet.save_etching(&rune_info.rune.rune, &commit, &reveal, output)?;
+    } else {
+      wallet.save_batch(BatchEntry {
+        commit: commit.clone(),
+        key: recovery_key_pair.to_inner().secret_key(),
+        output,
+        reveal: reveal.clone(),
+      })?;
+    }
+
+    let commit_txid = match wallet
       .bitcoin_client()
-      .send_raw_transaction(&signed_commit_tx)?;
+      .send_raw_transaction(&signed_commit_tx)
+    {
+      Ok(txid) => txid,
+      Err(err) => {
+        if let Some(ref rune_info) = rune {
+          wallet.clear_etching(rune_info.rune.rune)?;
+        } else {
+          wallet.clear_batch(commit.compute_txid())?;
+        }
+
+        return Err(err.into());
+      }
+    };
 
     if let Some(ref rune_info) = rune {
       wallet.bitcoin_client().lock_unspent(&[OutPoint {
@@ -134,25 +236,6 @@ impl Plan {
         vout: commit_vout.try_into().unwrap(),
       }])?;
 
-      let commit = consensus::encode::deserialize::<Transaction>(&signed_commit_tx)?;
-      let reveal = consensus::encode::deserialize::<Transaction>(&signed_reveal_tx)?;
-
-      wallet.save_etching(
-        &rune_info.rune.rune,
-        &commit,
-        &reveal,
-        self.output(
-          commit.compute_txid(),
-          None,
-          reveal.compute_txid(),
-          false,
-          None,
-          total_fees,
-          self.inscriptions.clone(),
-          rune.clone(),
-        ),
-      )?;
-
       Ok(Some(Box::new(
         wallet.wait_for_maturation(rune_info.rune.rune)?,
       )))
@@ -164,11 +247,13 @@ impl Plan {
         Ok(txid) => txid,
         Err(err) => {
           return Err(anyhow!(
-        "Failed to send reveal transaction: {err}\nCommit tx {commit_txid} will be recovered once mined"
+        "Failed to send reveal transaction: {err}\nRun `ord wallet resume` to retry, or commit tx {commit_txid} will be recovered once mined"
       ))
         }
       };
 
+      wallet.clear_batch(commit_txid)?;
+
       Ok(Some(Box::new(self.output(
         commit_txid,
         None,
@@ -182,6 +267,547 @@ impl Plan {
     }
   }
 
+  /// Inscribe a batch whose reveal transaction would be over the standard
+  /// weight limit as a chain of smaller batches, each with its own commit and
+  /// reveal transaction. Each commit may spend the change of the previous one,
+  /// and each reveal spends the parents returned by the previous one, so every
+  /// inscription has the same parents.
+  fn inscribe_chunks(
+    &self,
+    reveals: &[u64],
+    locked_utxos: &BTreeSet<OutPoint>,
+    runic_utxos: BTreeSet<OutPoint>,
+    utxos: &BTreeMap<OutPoint, TxOut>,
+    wallet: &Wallet,
+  ) -> SubcommandResult {
+    ensure!(
+      self.etching.is_none(),
+      "batches which etch a rune must fit in a single reveal transaction"
+    );
+
+    ensure!(
+      self.mode != Mode::SameSat,
+      "same-sat batches must fit in a single reveal transaction"
+    );
+
+    ensure!(
+      self.unsigned_psbt.is_none(),
+      "--unsigned-psbt cannot be used with batches which must be split across multiple reveal transactions"
+    );
+
+    // every part adds a commit and a reveal transaction to a single chain of
+    // unconfirmed transactions, which Bitcoin Core will not accept if longer
+    // than its ancestor and descendant limits
+    ensure!(
+      reveals.len() * 2 <= Self::MAX_UNCONFIRMED_CHAIN,
+      "batch would be split across {} reveal transactions, but Bitcoin Core does not accept chains of more than {} unconfirmed transactions",
+      reveals.len(),
+      Self::MAX_UNCONFIRMED_CHAIN,
+    );
+
+    if !self.dry_run {
+      wallet.check_private_keys()?;
+    }
+
+    let mut locked_utxos = locked_utxos.clone();
+    let mut utxos = utxos.clone();
+    let mut parent_info = self.parent_info.clone();
+    let mut prevtxs = Vec::new();
+    let mut outputs = Vec::new();
+    let mut start = 0;
+
+    for (i, &count) in reveals.iter().enumerate() {
+      let end = start + usize::try_from(count).unwrap();
+
+      let plan = self.chunk(start..end, parent_info.clone(), i == 0);
+
+      let Transactions {
+        commit_tx,
+        commit_vout,
+        reveal_tx,
+        recovery_key_pair,
+        total_fees,
+        rune,
+      } = plan.create_batch_transactions(
+        wallet.inscriptions().clone(),
+        wallet.chain(),
+        locked_utxos.clone(),
+        runic_utxos.clone(),
+        utxos.clone(),
+        [wallet.get_change_address()?, wallet.get_change_address()?],
+        wallet.get_change_address()?,
+      )?;
+
+      let commit_txid = commit_tx.compute_txid();
+      let reveal_txid = reveal_tx.compute_txid();
+
+      let signed_commit_tx = if self.dry_run {
+        None
+      } else {
+        let result = wallet.bitcoin_client().sign_raw_transaction_with_wallet(
+          &commit_tx,
+          Some(&prevtxs),
+          None,
+        )?;
+
+        Wallet::check_complete(result.complete)?;
+
+        Some(result.hex)
+      };
+
+      for input in &commit_tx.input {
+        utxos.remove(&input.previous_output);
+      }
+
+      for (vout, output) in commit_tx.output.iter().enumerate() {
+        prevtxs.push(SignRawTransactionInput {
+          txid: commit_txid,
+          vout: vout.try_into().unwrap(),
+          script_pub_key: output.script_pubkey.clone(),
+          redeem_script: None,
+          amount: Some(output.value),
+        });
+
+        if vout != commit_vout {
+          utxos.insert(
+            OutPoint {
+              txid: commit_txid,
+              vout: vout.try_into().unwrap(),
+            },
+            output.clone(),
+          );
+        }
+      }
+
+      let signed_reveal_tx = if self.dry_run {
+        None
+      } else {
+        let result = wallet.bitcoin_client().sign_raw_transaction_with_wallet(
+          &reveal_tx,
+          Some(&prevtxs),
+          None,
+        )?;
+
+        ensure!(
+          result.complete,
+          format!("Failed to sign reveal transaction: {:?}", result.errors)
+        );
+
+        Some(result.hex)
+      };
+
+      for input in &reveal_tx.input {
+        utxos.remove(&input.previous_output);
+      }
+
+      for (vout, info) in parent_info.iter_mut().enumerate() {
+        let outpoint = OutPoint {
+          txid: reveal_txid,
+          vout: vout.try_into().unwrap(),
+        };
+
+        info.location.outpoint = outpoint;
+        info.tx_out = reveal_tx.output[vout].clone();
+
+        prevtxs.push(SignRawTransactionInput {
+          txid: reveal_txid,
+          vout: outpoint.vout,
+          script_pub_key: info.tx_out.script_pubkey.clone(),
+          redeem_script: None,
+          amount: Some(info.tx_out.value),
+        });
+
+        locked_utxos.insert(outpoint);
+        utxos.insert(outpoint, info.tx_out.clone());
+      }
+
+      let (Some(signed_commit_tx), Some(signed_reveal_tx)) = (signed_commit_tx, signed_reveal_tx)
+      else {
+        let commit_psbt = wallet
+          .bitcoin_client()
+          .wallet_process_psbt(
+            &base64::engine::general_purpose::STANDARD.encode(
+              Psbt::from_unsigned_tx(Self::remove_witnesses(commit_tx.clone()))?.serialize(),
+            ),
+            Some(false),
+            None,
+            None,
+          )?
+          .psbt;
+
+        let reveal_psbt = Psbt::from_unsigned_tx(Self::remove_witnesses(reveal_tx.clone()))?;
+
+        outputs.push(plan.output(
+          commit_txid,
+          Some(commit_psbt),
+          reveal_txid,
+          false,
+          Some(base64::engine::general_purpose::STANDARD.encode(reveal_psbt.serialize())),
+          total_fees,
+          plan.inscriptions.clone(),
+          rune,
+        ));
+
+        start = end;
+
+        continue;
+      };
+
+      if !self.no_backup {
+        Self::backup_recovery_key(wallet, recovery_key_pair)?;
+      }
+
+      let commit = consensus::encode::deserialize::<Transaction>(&signed_commit_tx)?;
+      let reveal = consensus::encode::deserialize::<Transaction>(&signed_reveal_tx)?;
+
+      wallet.save_batch(BatchEntry {
+        commit,
+        key: recovery_key_pair.to_inner().secret_key(),
+        output: plan.output(
+          commit_txid,
+          None,
+          reveal_txid,
+          false,
+          None,
+          total_fees,
+          plan.inscriptions.clone(),
+          rune.clone(),
+        ),
+        reveal,
+      })?;
+
+      if let Err(err) = wallet
+        .bitcoin_client()
+        .send_raw_transaction(&signed_commit_tx)
+      {
+        wallet.clear_batch(commit_txid)?;
+        return Err(anyhow!(
+          "Failed to send commit transaction {} of {}: {err}",
+          i + 1,
+          reveals.len(),
+        ));
+      }
+
+      if let Err(err) = wallet
+        .bitcoin_client()
+        .send_raw_transaction(&signed_reveal_tx)
+      {
+        return Err(anyhow!(
+          "Failed to send reveal transaction {} of {}: {err}\nRun `ord wallet resume` to retry, or commit tx {commit_txid} will be recovered once mined",
+          i + 1,
+          reveals.len(),
+        ));
+      }
+
+      wallet.clear_batch(commit_txid)?;
+
+      outputs.push(plan.output(
+        commit_txid,
+        None,
+        reveal_txid,
+        true,
+        None,
+        total_fees,
+        plan.inscriptions.clone(),
+        rune,
+      ));
+
+      start = end;
+    }
+
+    let first = outputs.first().unwrap();
+    let last = outputs.last().unwrap();
+
+    Ok(Some(Box::new(Output {
+      commit: first.commit,
+      commit_psbt: first.commit_psbt.clone(),
+      inscriptions: outputs
+        .iter()
+        .flat_map(|output| output.inscriptions.clone())
+        .collect(),
+      parents: first.parents.clone(),
+      reveal: last.reveal,
+      reveal_broadcast: outputs.iter().all(|output| output.reveal_broadcast),
+      reveal_psbt: last.reveal_psbt.clone(),
+      reveals: outputs
+        .iter()
+        .flat_map(|output| output.reveals.clone())
+        .collect(),
+      rune: None,
+      total_fees: outputs.iter().map(|output| output.total_fees).sum(),
+    })))
+  }
+
+  /// The part of the batch containing `inscriptions`, with `parent_info`
+  /// giving the current location of the parents. Inscription pointers are
+  /// made relative to the chunk's reveal transaction.
+  fn chunk(
+    &self,
+    inscriptions: std::ops::Range<usize>,
+    parent_info: Vec<ParentInfo>,
+    first: bool,
+  ) -> Self {
+    let offset = self.postages[..inscriptions.start]
+      .iter()
+      .copied()
+      .sum::<Amount>()
+      .to_sat();
+
+    Self {
+      destinations: match self.mode {
+        Mode::SameSat | Mode::SharedOutput => self.destinations.clone(),
+        Mode::SatPoints | Mode::SeparateOutputs => self.destinations[inscriptions.clone()].to_vec(),
+      },
+      inscriptions: self.inscriptions[inscriptions.clone()]
+        .iter()
+        .cloned()
+        .map(|mut inscription| {
+          if let Some(pointer) = inscription.pointer() {
+            inscription.pointer = Some(Inscription::pointer_value(pointer - offset));
+          }
+          inscription
+        })
+        .collect(),
+      parent_info,
+      postages: self.postages[inscriptions.clone()].to_vec(),
+      reveal_satpoints: if self.mode == Mode::SatPoints {
+        self.reveal_satpoints[inscriptions].to_vec()
+      } else {
+        Vec::new()
+      },
+      satpoint: if first { self.satpoint } else { None },
+      unsigned_psbt: None,
+      ..*self
+    }
+  }
+
+  /// Bitcoin Core's default limit on the number of unconfirmed ancestors, and
+  /// descendants, of a transaction, each including the transaction itself.
+  const MAX_UNCONFIRMED_CHAIN: usize = 25;
+
+  const ESTIMATE_FEE_RATES: [f64; 7] = [1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0];
+
+  /// Estimate the size and cost of the batch without spending from the
+  /// wallet. The commit transaction is funded from a single placeholder
+  /// output, so its size is that of a commit with one cardinal input.
+  pub(crate) fn estimate(&self, wallet: &Wallet) -> Result<Estimate> {
+    let change = [wallet.get_change_address()?, wallet.get_change_address()?];
+
+    let placeholder = OutPoint {
+      txid: Txid::all_zeros(),
+      vout: 0,
+    };
+
+    let mut utxos = BTreeMap::new();
+
+    utxos.insert(
+      placeholder,
+      TxOut {
+        value: Amount::MAX_MONEY,
+        script_pubkey: change[0].script_pubkey(),
+      },
+    );
+
+    if let Some(satpoint) = self.satpoint {
+      if let Some(tx_out) = wallet.utxos().get(&satpoint.outpoint) {
+        utxos.insert(satpoint.outpoint, tx_out.clone());
+      }
+    }
+
+    for info in &self.parent_info {
+      utxos.insert(info.location.outpoint, info.tx_out.clone());
+    }
+
+    for (satpoint, tx_out) in &self.reveal_satpoints {
+      utxos.insert(satpoint.outpoint, tx_out.clone());
+    }
+
+    let Transactions {
+      mut commit_tx,
+      mut reveal_tx,
+      ..
+    } = Self {
+      satpoint: Some(self.satpoint.unwrap_or(SatPoint {
+        outpoint: placeholder,
+        offset: 0,
+      })),
+      no_limit: true,
+      parent_info: self.parent_info.clone(),
+      destinations: self.destinations.clone(),
+      inscriptions: self.inscriptions.clone(),
+      postages: self.postages.clone(),
+      reveal_satpoints: self.reveal_satpoints.clone(),
+      unsigned_psbt: None,
+      ..*self
+    }
+    .create_batch_transactions(
+      wallet.inscriptions().clone(),
+      wallet.chain(),
+      BTreeSet::new(),
+      BTreeSet::new(),
+      utxos,
+      change.clone(),
+      wallet.get_change_address()?,
+    )?;
+
+    for input in &mut commit_tx.input {
+      input.witness = TransactionBuilder::dummy_witness(self.satisfaction_weight);
+    }
+
+    self.add_dummy_reveal_witnesses(&mut reveal_tx);
+
+    let commit_vsize = commit_tx.vsize();
+    let reveal_vsize = reveal_tx.vsize();
+    let reveal_weight = reveal_tx.weight().to_wu();
+
+    let mut fee_rates = Self::ESTIMATE_FEE_RATES.to_vec();
+
+    if !fee_rates.contains(&self.reveal_fee_rate.n()) {
+      fee_rates.push(self.reveal_fee_rate.n());
+      fee_rates.sort_by(f64::total_cmp);
+    }
+
+    let fees = fee_rates
+      .into_iter()
+      .map(|fee_rate| {
+        let rate = FeeRate::try_from(fee_rate)?;
+        let commit = rate.fee(commit_vsize).to_sat();
+        let reveal = rate.fee(reveal_vsize).to_sat();
+        Ok(EstimateFees {
+          fee_rate,
+          commit,
+          reveal,
+          total: commit + reveal,
+        })
+      })
+      .collect::<Result<Vec<EstimateFees>>>()?;
+
+    let limit = u64::from(MAX_STANDARD_TX_WEIGHT);
+
+    Ok(Estimate {
+      commit_vsize: commit_vsize.try_into().unwrap(),
+      fees,
+      inscriptions: self.inscriptions.len().try_into().unwrap(),
+      postage: self.postages.iter().copied().sum::<Amount>().to_sat(),
+      reveal_vsize: reveal_vsize.try_into().unwrap(),
+      reveal_weight,
+      reveals: self.split(reveal_weight, limit),
+      standard: reveal_weight <= limit,
+    })
+  }
+
+  /// Give every input of the reveal transaction except the commit input, which
+  /// is already signed, a dummy witness, so the reveal has its final weight.
+  fn add_dummy_reveal_witnesses(&self, reveal_tx: &mut Transaction) {
+    let commit_input = self.parent_info.len() + self.reveal_satpoints.len();
+
+    for (i, input) in reveal_tx.input.iter_mut().enumerate() {
+      if i != commit_input {
+        input.witness = TransactionBuilder::dummy_witness(self.satisfaction_weight);
+      }
+    }
+  }
+
+  fn reveal_weight(&self, mut reveal_tx: Transaction) -> u64 {
+    self.add_dummy_reveal_witnesses(&mut reveal_tx);
+    reveal_tx.weight().to_wu()
+  }
+
+  /// Suggest how many inscriptions to put in each of several reveal
+  /// transactions so that each stays under `limit`, by greedily packing
+  /// inscriptions in order. Each inscription's weight is the size of its
+  /// envelope, plus its output and input, if it has its own, and the
+  /// remaining weight of the reveal is counted once per reveal.
+  fn split(&self, reveal_weight: u64, limit: u64) -> Vec<u64> {
+    if reveal_weight <= limit {
+      return vec![self.inscriptions.len().try_into().unwrap()];
+    }
+
+    let weights = self
+      .inscriptions
+      .iter()
+      .enumerate()
+      .map(|(i, inscription)| {
+        let mut weight = u64::try_from(
+          Inscription::append_batch_reveal_script(
+            std::slice::from_ref(inscription),
+            ScriptBuf::builder(),
+          )
+          .len(),
+        )
+        .unwrap();
+
+        if matches!(self.mode, Mode::SeparateOutputs | Mode::SatPoints) {
+          weight += TxOut {
+            value: Amount::ZERO,
+            script_pubkey: self.destinations[i].script_pubkey(),
+          }
+          .weight()
+          .to_wu();
+        }
+
+        if self.mode == Mode::SatPoints {
+          weight += Weight::from_non_witness_data_size(41).to_wu()
+            + TransactionBuilder::dummy_witness(self.satisfaction_weight).size() as u64;
+        }
+
+        weight
+      })
+      .collect::<Vec<u64>>();
+
+    let base = reveal_weight.saturating_sub(weights.iter().sum());
+
+    let mut reveals = Vec::new();
+    let mut count = 0;
+    let mut weight = base;
+
+    for inscription in weights {
+      if count > 0 && weight + inscription > limit {
+        reveals.push(count);
+        count = 0;
+        weight = base;
+      }
+
+      count += 1;
+      weight += inscription;
+    }
+
+    if count > 0 {
+      reveals.push(count);
+    }
+
+    reveals
+  }
+
+  /// The commit input of the reveal transaction is signed with the recovery
+  /// key when the transaction is created, so it is finalized in the PSBT, and
+  /// only the remaining inputs, if any, are left to be signed.
+  fn unsigned_reveal_psbt(
+    wallet: &Wallet,
+    commit_tx: &Transaction,
+    reveal_tx: &Transaction,
+    utxos: &BTreeMap<OutPoint, TxOut>,
+  ) -> Result<Psbt> {
+    let commit_txid = commit_tx.compute_txid();
+
+    let mut psbt = Psbt::from_unsigned_tx(Self::remove_witnesses(reveal_tx.clone()))?;
+
+    for (input, txin) in psbt.inputs.iter_mut().zip(&reveal_tx.input) {
+      let previous_output = txin.previous_output;
+
+      if previous_output.txid == commit_txid {
+        input.witness_utxo = Some(commit_tx.output[previous_output.vout.into_usize()].clone());
+        input.final_script_witness = Some(txin.witness.clone());
+      } else {
+        input.witness_utxo = utxos.get(&previous_output).cloned();
+      }
+    }
+
+    wallet.record_psbt_contents(&mut psbt)?;
+
+    Ok(psbt)
+  }
+
   fn remove_witnesses(mut transaction: Transaction) -> Transaction {
     for txin in transaction.input.iter_mut() {
       txin.witness = Witness::new();
@@ -240,18 +866,66 @@ impl Plan {
 
     Output {
       commit,
-      commit_psbt,
+      commit_psbt: commit_psbt.clone(),
       inscriptions: inscriptions_output,
       parents: self.parent_info.iter().map(|info| info.id).collect(),
       reveal,
       reveal_broadcast,
-      reveal_psbt,
+      reveal_psbt: reveal_psbt.clone(),
+      reveals: vec![RevealOutput {
+        commit,
+        commit_psbt,
+        reveal,
+        reveal_psbt,
+        total_fees,
+      }],
       rune,
       total_fees,
     }
   }
 
   pub(crate) fn create_batch_transactions(
+    &self,
+    wallet_inscriptions: BTreeMap<SatPoint, Vec<InscriptionId>>,
+    chain: Chain,
+    locked_utxos: BTreeSet<OutPoint>,
+    runic_utxos: BTreeSet<OutPoint>,
+    utxos: BTreeMap<OutPoint, TxOut>,
+    commit_change: [Address; 2],
+    reveal_change: Address,
+  ) -> Result<Transactions> {
+    let transactions = self.build_batch_transactions(
+      wallet_inscriptions,
+      chain,
+      locked_utxos,
+      runic_utxos,
+      utxos,
+      commit_change,
+      reveal_change,
+    )?;
+
+    if !self.no_limit {
+      self.check_reveal_weight(&transactions.reveal_tx)?;
+    }
+
+    Ok(transactions)
+  }
+
+  fn check_reveal_weight(&self, reveal_tx: &Transaction) -> Result {
+    let reveal_weight = reveal_tx.weight();
+
+    if reveal_weight > bitcoin::Weight::from_wu(MAX_STANDARD_TX_WEIGHT.into()) {
+      bail!(
+        "reveal transaction weight greater than {MAX_STANDARD_TX_WEIGHT} (MAX_STANDARD_TX_WEIGHT): {reveal_weight}"
+      );
+    }
+
+    Ok(())
+  }
+
+  /// Create the commit and reveal transactions without checking that the
+  /// reveal is under the standard weight limit.
+  fn build_batch_transactions(
     &self,
     wallet_inscriptions: BTreeMap<SatPoint, Vec<InscriptionId>>,
     chain: Chain,
@@ -367,22 +1041,10 @@ impl Plan {
     let key_pair = UntweakedKeypair::new(&secp256k1, &mut rand::thread_rng());
     let (public_key, _parity) = XOnlyPublicKey::from_keypair(&key_pair);
 
-    let reveal_script = Inscription::append_batch_reveal_script(
-      &self.inscriptions,
-      ScriptBuf::builder()
-        .push_slice(public_key.serialize())
-        .push_opcode(opcodes::all::OP_CHECKSIG),
-    );
+    let reveal_script = self.reveal_script(public_key);
 
-    let taproot_spend_info = TaprootBuilder::new()
-      .add_leaf(0, reveal_script.clone())
-      .expect("adding leaf should work")
-      .finalize(&secp256k1, public_key)
-      .expect("finalizing taproot builder should work");
-
-    let control_block = taproot_spend_info
-      .control_block(&(reveal_script.clone(), LeafVersion::TapScript))
-      .expect("should compute control block");
+    let (taproot_spend_info, control_block) =
+      Self::taproot_spend_info(&secp256k1, &reveal_script, public_key);
 
     let commit_tx_address = Address::p2tr_tweaked(taproot_spend_info.output_key(), chain.network());
 
@@ -413,15 +1075,7 @@ impl Plan {
 
     reveal_inputs.push(OutPoint::null());
 
-    for (i, destination) in self.destinations.iter().enumerate() {
-      reveal_outputs.push(TxOut {
-        script_pubkey: destination.script_pubkey(),
-        value: match self.mode {
-          Mode::SeparateOutputs | Mode::SatPoints => self.postages[i],
-          Mode::SharedOutput | Mode::SameSat => total_postage,
-        },
-      });
-    }
+    reveal_outputs.extend(self.destination_outputs());
 
     let rune;
     let premine;
@@ -534,6 +1188,7 @@ impl Plan {
       Target::Value(target_value),
       chain.network(),
     )
+    .satisfaction_weight(self.satisfaction_weight)
     .build_transaction()?;
 
     let (vout, _commit_output) = unsigned_commit_tx
@@ -579,38 +1234,16 @@ impl Plan {
 
     prevouts.push(unsigned_commit_tx.output[vout].clone());
 
-    let mut sighash_cache = SighashCache::new(&mut reveal_tx);
-
-    let sighash = sighash_cache
-      .taproot_script_spend_signature_hash(
-        commit_input,
-        &Prevouts::All(&prevouts),
-        TapLeafHash::from_script(&reveal_script, LeafVersion::TapScript),
-        TapSighashType::Default,
-      )
-      .expect("signature hash should compute");
-
-    let signature = secp256k1.sign_schnorr(
-      &secp256k1::Message::from_digest_slice(sighash.as_ref())
-        .expect("should be cryptographically secure hash"),
+    Self::sign_reveal_input(
+      &secp256k1,
+      &mut reveal_tx,
+      commit_input,
+      &prevouts,
+      reveal_script,
+      &control_block,
       &key_pair,
     );
 
-    let witness = sighash_cache
-      .witness_mut(commit_input)
-      .expect("getting mutable witness reference should work");
-
-    witness.push(
-      Signature {
-        signature,
-        sighash_type: TapSighashType::Default,
-      }
-      .to_vec(),
-    );
-
-    witness.push(reveal_script);
-    witness.push(control_block.serialize());
-
     let recovery_key_pair = key_pair.tap_tweak(&secp256k1, taproot_spend_info.merkle_root());
 
     let (x_only_pub_key, _parity) = recovery_key_pair.to_inner().x_only_public_key();
@@ -622,14 +1255,6 @@ impl Plan {
       commit_tx_address
     );
 
-    let reveal_weight = reveal_tx.weight();
-
-    if !self.no_limit && reveal_weight > bitcoin::Weight::from_wu(MAX_STANDARD_TX_WEIGHT.into()) {
-      bail!(
-        "reveal transaction weight greater than {MAX_STANDARD_TX_WEIGHT} (MAX_STANDARD_TX_WEIGHT): {reveal_weight}"
-      );
-    }
-
     utxos.insert(
       reveal_tx.input[commit_input].previous_output,
       unsigned_commit_tx.output[reveal_tx.input[commit_input].previous_output.vout as usize]
@@ -671,6 +1296,274 @@ impl Plan {
     })
   }
 
+  fn reveal_script(&self, public_key: XOnlyPublicKey) -> ScriptBuf {
+    Inscription::append_batch_reveal_script(
+      &self.inscriptions,
+      ScriptBuf::builder()
+        .push_slice(public_key.serialize())
+        .push_opcode(opcodes::all::OP_CHECKSIG),
+    )
+  }
+
+  fn taproot_spend_info(
+    secp256k1: &Secp256k1<All>,
+    reveal_script: &Script,
+    public_key: XOnlyPublicKey,
+  ) -> (TaprootSpendInfo, ControlBlock) {
+    let taproot_spend_info = TaprootBuilder::new()
+      .add_leaf(0, reveal_script.into())
+      .expect("adding leaf should work")
+      .finalize(secp256k1, public_key)
+      .expect("finalizing taproot builder should work");
+
+    let control_block = taproot_spend_info
+      .control_block(&(reveal_script.into(), LeafVersion::TapScript))
+      .expect("should compute control block");
+
+    (taproot_spend_info, control_block)
+  }
+
+  fn destination_outputs(&self) -> Vec<TxOut> {
+    let total_postage = self.postages.iter().copied().sum();
+
+    self
+      .destinations
+      .iter()
+      .enumerate()
+      .map(|(i, destination)| TxOut {
+        script_pubkey: destination.script_pubkey(),
+        value: match self.mode {
+          Mode::SeparateOutputs | Mode::SatPoints => self.postages[i],
+          Mode::SharedOutput | Mode::SameSat => total_postage,
+        },
+      })
+      .collect()
+  }
+
+  fn sign_reveal_input(
+    secp256k1: &Secp256k1<All>,
+    reveal_tx: &mut Transaction,
+    commit_input: usize,
+    prevouts: &[TxOut],
+    reveal_script: ScriptBuf,
+    control_block: &ControlBlock,
+    key_pair: &UntweakedKeypair,
+  ) {
+    let mut sighash_cache = SighashCache::new(reveal_tx);
+
+    let sighash = sighash_cache
+      .taproot_script_spend_signature_hash(
+        commit_input,
+        &Prevouts::All(prevouts),
+        TapLeafHash::from_script(&reveal_script, LeafVersion::TapScript),
+        TapSighashType::Default,
+      )
+      .expect("signature hash should compute");
+
+    let signature = secp256k1.sign_schnorr(
+      &secp256k1::Message::from_digest_slice(sighash.as_ref())
+        .expect("should be cryptographically secure hash"),
+      key_pair,
+    );
+
+    let witness = sighash_cache
+      .witness_mut(commit_input)
+      .expect("getting mutable witness reference should work");
+
+    witness.push(
+      Signature {
+        signature,
+        sighash_type: TapSighashType::Default,
+      }
+      .to_vec(),
+    );
+
+    witness.push(reveal_script);
+    witness.push(control_block.serialize());
+  }
+
+  /// Create a reveal transaction whose commit output will be funded by a
+  /// third party, saving it to the wallet database until the commit
+  /// transaction is known.
+  fn inscribe_commit_only(&self, wallet: &Wallet) -> SubcommandResult {
+    ensure!(
+      self.parent_info.is_empty(),
+      "--commit-only cannot be used with parent inscriptions"
+    );
+
+    ensure!(
+      self.etching.is_none(),
+      "--commit-only cannot be used to etch runes"
+    );
+
+    ensure!(
+      self.satpoint.is_none() && self.mode != Mode::SatPoints && !self.reinscribe,
+      "--commit-only cannot be used to inscribe specific sats"
+    );
+
+    ensure!(
+      self.no_backup || !wallet.watch_only(),
+      "recovery key cannot be backed up to watch-only wallet, use `--no-backup`",
+    );
+
+    let secp256k1 = Secp256k1::new();
+    let key_pair = UntweakedKeypair::new(&secp256k1, &mut rand::thread_rng());
+    let (public_key, _parity) = XOnlyPublicKey::from_keypair(&key_pair);
+
+    let reveal_script = self.reveal_script(public_key);
+
+    let (taproot_spend_info, control_block) =
+      Self::taproot_spend_info(&secp256k1, &reveal_script, public_key);
+
+    let commit_address =
+      Address::p2tr_tweaked(taproot_spend_info.output_key(), wallet.chain().network());
+
+    let (reveal_tx, reveal_fee) = Self::build_reveal_transaction(
+      0,
+      &control_block,
+      self.reveal_fee_rate,
+      self.destination_outputs(),
+      vec![OutPoint::null()],
+      &reveal_script,
+      false,
+    );
+
+    for output in reveal_tx.output.iter() {
+      ensure!(
+        output.value >= output.script_pubkey.minimal_non_dust(),
+        "commit transaction output would be dust"
+      );
+    }
+
+    let amount = reveal_fee + self.postages.iter().copied().sum();
+
+    if !self.no_backup {
+      Self::backup_recovery_key(
+        wallet,
+        key_pair.tap_tweak(&secp256k1, taproot_spend_info.merkle_root()),
+      )?;
+    }
+
+    wallet.save_reveal(
+      &commit_address.script_pubkey(),
+      RevealEntry {
+        amount,
+        fee_rate: self.reveal_fee_rate,
+        key: key_pair.secret_key(),
+        output: self.output(
+          Txid::all_zeros(),
+          None,
+          Txid::all_zeros(),
+          false,
+          None,
+          0,
+          self.inscriptions.clone(),
+          None,
+        ),
+        reveal: reveal_tx,
+        script: reveal_script,
+      },
+    )?;
+
+    Ok(Some(Box::new(CommitOutput {
+      address: uncheck(&commit_address),
+      amount: amount.to_sat(),
+    })))
+  }
+
+  /// Sign and broadcast a reveal transaction saved by `--commit-only`, spending
+  /// `commit`. Any amount in excess of what the reveal transaction requires is
+  /// sent to a wallet change address, if it would not be dust.
+  pub(crate) fn reveal(
+    wallet: &Wallet,
+    commit: OutPoint,
+    commit_output: TxOut,
+    entry: RevealEntry,
+  ) -> Result<Output> {
+    ensure!(
+      commit_output.value >= entry.amount,
+      "commit output {commit} contains {} but reveal transaction requires {}",
+      commit_output.value,
+      entry.amount,
+    );
+
+    let secp256k1 = Secp256k1::new();
+    let key_pair = UntweakedKeypair::from_secret_key(&secp256k1, &entry.key);
+    let (public_key, _parity) = XOnlyPublicKey::from_keypair(&key_pair);
+
+    let (_taproot_spend_info, control_block) =
+      Self::taproot_spend_info(&secp256k1, &entry.script, public_key);
+
+    let mut outputs = entry.reveal.output.clone();
+
+    let postage = outputs.iter().map(|output| output.value).sum::<Amount>();
+
+    let change = wallet.get_change_address()?.script_pubkey();
+
+    outputs.push(TxOut {
+      script_pubkey: change.clone(),
+      value: Amount::ZERO,
+    });
+
+    let (_reveal_tx, fee) = Self::build_reveal_transaction(
+      0,
+      &control_block,
+      entry.fee_rate,
+      outputs.clone(),
+      vec![commit],
+      &entry.script,
+      false,
+    );
+
+    match commit_output.value.checked_sub(postage + fee) {
+      Some(value) if value >= change.minimal_non_dust() => {
+        outputs.last_mut().unwrap().value = value;
+      }
+      _ => {
+        outputs.pop();
+      }
+    }
+
+    let (mut reveal_tx, _fee) = Self::build_reveal_transaction(
+      0,
+      &control_block,
+      entry.fee_rate,
+      outputs,
+      vec![commit],
+      &entry.script,
+      false,
+    );
+
+    Self::sign_reveal_input(
+      &secp256k1,
+      &mut reveal_tx,
+      0,
+      std::slice::from_ref(&commit_output),
+      entry.script,
+      &control_block,
+      &key_pair,
+    );
+
+    let reveal = wallet.bitcoin_client().send_raw_transaction(&reveal_tx)?;
+
+    wallet.clear_reveal(&commit_output.script_pubkey)?;
+
+    let mut output = entry.output;
+
+    output.commit = commit.txid;
+    output.reveal = reveal;
+    output.reveal_broadcast = true;
+    output.total_fees =
+      Self::calculate_fee(&reveal_tx, &[(commit, commit_output)].into_iter().collect());
+
+    for inscription in &mut output.inscriptions {
+      inscription.id.txid = reveal;
+      inscription.location.outpoint.txid = reveal;
+    }
+
+    Ok(output)
+  }
+
   fn backup_recovery_key(wallet: &Wallet, recovery_key_pair: TweakedKeypair) -> Result {
     let recovery_private_key = PrivateKey::new(
       recovery_key_pair.to_inner().secret_key(),
@@ -1,4 +1,4 @@
-use super::*;
+use {super::*, bitcoin::secp256k1::SecretKey};
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct EtchingEntry {
@@ -36,6 +36,94 @@ impl Entry for EtchingEntry {
   }
 }
 
+#[derive(Debug, PartialEq, Clone)]
+pub struct BatchEntry {
+  pub commit: Transaction,
+  pub key: SecretKey,
+  pub output: batch::Output,
+  pub reveal: Transaction,
+}
+
+pub(super) type BatchEntryValue = (
+  Vec<u8>, // commit
+  Vec<u8>, // key
+  Vec<u8>, // output
+  Vec<u8>, // reveal
+);
+
+impl Entry for BatchEntry {
+  type Value = BatchEntryValue;
+
+  fn load((commit, key, output, reveal): BatchEntryValue) -> Self {
+    Self {
+      commit: consensus::encode::deserialize::<Transaction>(&commit).unwrap(),
+      key: SecretKey::from_slice(&key).unwrap(),
+      output: serde_json::from_slice(&output).unwrap(),
+      reveal: consensus::encode::deserialize::<Transaction>(&reveal).unwrap(),
+    }
+  }
+
+  fn store(self) -> Self::Value {
+    (
+      consensus::encode::serialize(&self.commit),
+      self.key.secret_bytes().to_vec(),
+      serde_json::to_string(&self.output)
+        .unwrap()
+        .as_bytes()
+        .to_owned(),
+      consensus::encode::serialize(&self.reveal),
+    )
+  }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct RevealEntry {
+  pub amount: Amount,
+  pub fee_rate: FeeRate,
+  pub key: SecretKey,
+  pub output: batch::Output,
+  pub reveal: Transaction,
+  pub script: ScriptBuf,
+}
+
+pub(super) type RevealEntryValue = (
+  u64,     // amount
+  f64,     // fee rate
+  Vec<u8>, // key
+  Vec<u8>, // output
+  Vec<u8>, // reveal
+  Vec<u8>, // script
+);
+
+impl Entry for RevealEntry {
+  type Value = RevealEntryValue;
+
+  fn load((amount, fee_rate, key, output, reveal, script): RevealEntryValue) -> Self {
+    Self {
+      amount: Amount::from_sat(amount),
+      fee_rate: fee_rate.try_into().unwrap(),
+      key: SecretKey::from_slice(&key).unwrap(),
+      output: serde_json::from_slice(&output).unwrap(),
+      reveal: consensus::encode::deserialize::<Transaction>(&reveal).unwrap(),
+      script: ScriptBuf::from_bytes(script),
+    }
+  }
+
+  fn store(self) -> Self::Value {
+    (
+      self.amount.to_sat(),
+      self.fee_rate.n(),
+      self.key.secret_bytes().to_vec(),
+      serde_json::to_string(&self.output)
+        .unwrap()
+        .as_bytes()
+        .to_owned(),
+      consensus::encode::serialize(&self.reveal),
+      self.script.into_bytes(),
+    )
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -80,6 +168,7 @@ mod tests {
       reveal: txid,
       reveal_broadcast: true,
       reveal_psbt: None,
+      reveals: Vec::new(),
       rune: None,
       total_fees: 0,
     };
@@ -102,4 +191,64 @@ mod tests {
     assert_eq!(entry.clone().store(), value);
     assert_eq!(EtchingEntry::load(value), entry);
   }
+
+  #[test]
+  fn reveal_entry() {
+    let entry = RevealEntry {
+      amount: Amount::from_sat(10_500),
+      fee_rate: 2.5.try_into().unwrap(),
+      key: SecretKey::from_slice(&[1; 32]).unwrap(),
+      output: batch::Output {
+        commit: Txid::all_zeros(),
+        commit_psbt: None,
+        inscriptions: Vec::new(),
+        parents: Vec::new(),
+        reveal: Txid::all_zeros(),
+        reveal_broadcast: false,
+        reveal_psbt: None,
+        reveals: Vec::new(),
+        rune: None,
+        total_fees: 0,
+      },
+      reveal: Transaction {
+        version: Version(2),
+        lock_time: LockTime::ZERO,
+        input: Vec::new(),
+        output: Vec::new(),
+      },
+      script: ScriptBuf::from_bytes(vec![0x51]),
+    };
+
+    assert_eq!(RevealEntry::load(entry.clone().store()), entry);
+  }
+
+  #[test]
+  fn batch_entry() {
+    let transaction = Transaction {
+      version: Version(2),
+      lock_time: LockTime::ZERO,
+      input: Vec::new(),
+      output: Vec::new(),
+    };
+
+    let entry = BatchEntry {
+      commit: transaction.clone(),
+      key: SecretKey::from_slice(&[1; 32]).unwrap(),
+      output: batch::Output {
+        commit: Txid::all_zeros(),
+        commit_psbt: None,
+        inscriptions: Vec::new(),
+        parents: Vec::new(),
+        reveal: Txid::all_zeros(),
+        reveal_broadcast: false,
+        reveal_psbt: None,
+        reveals: Vec::new(),
+        rune: None,
+        total_fees: 0,
+      },
+      reveal: transaction,
+    };
+
+    assert_eq!(BatchEntry::load(entry.clone().store()), entry);
+  }
 }
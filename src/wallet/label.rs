@@ -0,0 +1,60 @@
+use super::*;
+
+/// A BIP-329 wallet label record.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Label {
+  pub r#type: Type,
+  pub r#ref: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub label: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub origin: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub spendable: Option<bool>,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize, Ord, PartialOrd, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Type {
+  Addr,
+  Input,
+  Output,
+  Pubkey,
+  Tx,
+  Xpub,
+}
+
+impl Display for Type {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        Self::Addr => "addr",
+        Self::Input => "input",
+        Self::Output => "output",
+        Self::Pubkey => "pubkey",
+        Self::Tx => "tx",
+        Self::Xpub => "xpub",
+      }
+    )
+  }
+}
+
+impl Label {
+  /// Check that `ref` is well-formed for the label's type. Address network is
+  /// not checked, since labels may be imported from wallets on other chains.
+  pub(crate) fn validate(&self) -> Result {
+    let valid = match self.r#type {
+      Type::Addr => Address::<NetworkUnchecked>::from_str(&self.r#ref).is_ok(),
+      Type::Input | Type::Output => OutPoint::from_str(&self.r#ref).is_ok(),
+      Type::Pubkey => bitcoin::PublicKey::from_str(&self.r#ref).is_ok(),
+      Type::Tx => Txid::from_str(&self.r#ref).is_ok(),
+      Type::Xpub => bitcoin::bip32::Xpub::from_str(&self.r#ref).is_ok(),
+    };
+
+    ensure!(valid, "invalid {} reference `{}`", self.r#type, self.r#ref);
+
+    Ok(())
+  }
+}
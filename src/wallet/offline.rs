@@ -0,0 +1,425 @@
+use {super::*, bitcoin::psbt::raw::ProprietaryKey};
+
+const PROPRIETARY_PREFIX: &[u8] = b"ord";
+const PROPRIETARY_SUBTYPE_CONTENTS: u8 = 0;
+
+/// Where a PSBT sends the inscriptions and runes in the wallet outputs it
+/// spends. These are recorded in the wallet database and in the PSBT when it
+/// is created. Before it is signed or broadcast, they are derived again from
+/// the index and checked against the wallet's own record, or, for a PSBT
+/// created by another wallet, the record in the PSBT, so that a PSBT which
+/// would now move inscriptions or runes anywhere other than where it was
+/// created to move them is rejected. Runes minted or etched by the PSBT are
+/// not included.
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PsbtContents {
+  /// Output each inscription is sent to, or `None` if it is paid as fee
+  pub inscriptions: BTreeMap<InscriptionId, Option<u32>>,
+  /// Runes sent to each output
+  pub runes: Vec<BTreeMap<Rune, u128>>,
+  /// Runes burned, either by a cenotaph, for lack of a non-OP_RETURN output,
+  /// or by being sent to an OP_RETURN output
+  pub burned: BTreeMap<Rune, u128>,
+}
+
+impl PsbtContents {
+  fn key() -> ProprietaryKey {
+    ProprietaryKey {
+      prefix: PROPRIETARY_PREFIX.into(),
+      subtype: PROPRIETARY_SUBTYPE_CONTENTS,
+      key: Vec::new(),
+    }
+  }
+
+  pub(crate) fn load(psbt: &Psbt) -> Result<Self> {
+    match psbt.proprietary.get(&Self::key()) {
+      Some(value) => Ok(serde_json::from_slice(value)?),
+      None => Ok(Self::default()),
+    }
+  }
+
+  pub(crate) fn store(&self, psbt: &mut Psbt) -> Result {
+    psbt
+      .proprietary
+      .insert(Self::key(), serde_json::to_vec(self)?);
+    Ok(())
+  }
+
+  fn destination(output: Option<u32>) -> String {
+    match output {
+      Some(vout) => format!("output {vout}"),
+      None => "fees".into(),
+    }
+  }
+}
+
+pub(crate) fn read_psbts(path: &Path) -> Result<Vec<Psbt>> {
+  fs::read_to_string(path)
+    .with_context(|| format!("failed to read PSBT file `{}`", path.display()))?
+    .lines()
+    .map(str::trim)
+    .filter(|line| !line.is_empty())
+    .map(decode_psbt)
+    .collect()
+}
+
+pub(crate) fn write_psbts(path: &Path, psbts: &[Psbt]) -> Result {
+  let mut contents = String::new();
+
+  for psbt in psbts {
+    contents.push_str(&encode_psbt(psbt));
+    contents.push('\n');
+  }
+
+  fs::write(path, contents)
+    .with_context(|| format!("failed to write PSBT file `{}`", path.display()))
+}
+
+pub(crate) fn encode_psbt(psbt: &Psbt) -> String {
+  base64::engine::general_purpose::STANDARD.encode(psbt.serialize())
+}
+
+pub(crate) fn decode_psbt(psbt: &str) -> Result<Psbt> {
+  Ok(Psbt::deserialize(
+    &base64::engine::general_purpose::STANDARD
+      .decode(psbt)
+      .context("invalid PSBT base64")?,
+  )?)
+}
+
+impl Wallet {
+  /// Derive where `psbt` sends the inscriptions and runes in the wallet
+  /// outputs it spends from the index, allocating runes the same way the
+  /// index does.
+  pub(crate) fn psbt_contents(&self, psbt: &Psbt) -> Result<PsbtContents> {
+    let transaction = &psbt.unsigned_tx;
+
+    let mut contents = PsbtContents {
+      runes: vec![BTreeMap::new(); transaction.output.len()],
+      ..default()
+    };
+
+    let mut inscriptions = Vec::new();
+    let mut unallocated = BTreeMap::<Rune, u128>::new();
+    let mut offset = 0;
+
+    for (i, input) in transaction.input.iter().enumerate() {
+      let outpoint = input.previous_output;
+
+      let info = self.output_info.get(&outpoint);
+
+      let value = info
+        .map(|info| info.value)
+        .or_else(|| {
+          psbt
+            .inputs
+            .get(i)
+            .and_then(|input| input.witness_utxo.as_ref())
+            .map(|tx_out| tx_out.value.to_sat())
+        })
+        .ok_or_else(|| anyhow!("PSBT input {outpoint} value unknown"))?;
+
+      for (satpoint, ids) in self.inscriptions.range(
+        SatPoint {
+          outpoint,
+          offset: 0,
+        }..=SatPoint {
+          outpoint,
+          offset: u64::MAX,
+        },
+      ) {
+        for id in ids {
+          inscriptions.push((*id, offset + satpoint.offset));
+        }
+      }
+
+      if let Some(info) = info {
+        for (spaced_rune, pile) in &info.runes {
+          *unallocated.entry(spaced_rune.rune).or_default() += pile.amount;
+        }
+      }
+
+      offset += value;
+    }
+
+    for (id, offset) in inscriptions {
+      let mut start = 0;
+      let mut destination = None;
+
+      for (vout, tx_out) in transaction.output.iter().enumerate() {
+        let end = start + tx_out.value.to_sat();
+
+        if offset < end {
+          destination = Some(vout.try_into().unwrap());
+          break;
+        }
+
+        start = end;
+      }
+
+      contents.inscriptions.insert(id, destination);
+    }
+
+    if unallocated.is_empty() {
+      return Ok(contents);
+    }
+
+    let runestone = match Runestone::decipher(transaction) {
+      Some(Artifact::Cenotaph(_)) => {
+        contents.burned = unallocated;
+        return Ok(contents);
+      }
+      Some(Artifact::Runestone(runestone)) => Some(runestone),
+      None => None,
+    };
+
+    let destinations = transaction
+      .output
+      .iter()
+      .enumerate()
+      .filter_map(|(vout, tx_out)| (!tx_out.script_pubkey.is_op_return()).then_some(vout))
+      .collect::<Vec<usize>>();
+
+    for edict in runestone.iter().flat_map(|runestone| &runestone.edicts) {
+      if edict.id == RuneId::default() {
+        continue;
+      }
+
+      let Some((_, entry, _)) = self.get_rune_by_id(edict.id)? else {
+        continue;
+      };
+
+      let rune = entry.spaced_rune.rune;
+
+      let Some(balance) = unallocated.get_mut(&rune) else {
+        continue;
+      };
+
+      let output = edict.output.into_usize();
+
+      let mut allocate = |balance: &mut u128, amount: u128, output: usize| {
+        if amount > 0 {
+          *balance -= amount;
+          *contents.runes[output].entry(rune).or_default() += amount;
+        }
+      };
+
+      if output == transaction.output.len() {
+        if edict.amount == 0 && !destinations.is_empty() {
+          let amount = *balance / destinations.len() as u128;
+          let remainder = usize::try_from(*balance % destinations.len() as u128).unwrap();
+
+          for (i, output) in destinations.iter().enumerate() {
+            allocate(
+              balance,
+              if i < remainder { amount + 1 } else { amount },
+              *output,
+            );
+          }
+        } else {
+          for output in &destinations {
+            allocate(balance, edict.amount.min(*balance), *output);
+          }
+        }
+      } else if output < transaction.output.len() {
+        let amount = if edict.amount == 0 {
+          *balance
+        } else {
+          edict.amount.min(*balance)
+        };
+
+        allocate(balance, amount, output);
+      }
+    }
+
+    let pointer = runestone
+      .and_then(|runestone| runestone.pointer)
+      .map(|pointer| pointer.into_usize())
+      .filter(|pointer| *pointer < transaction.output.len())
+      .or_else(|| destinations.first().copied());
+
+    for (rune, balance) in unallocated {
+      if balance == 0 {
+        continue;
+      }
+
+      match pointer {
+        Some(vout) => *contents.runes[vout].entry(rune).or_default() += balance,
+        None => *contents.burned.entry(rune).or_default() += balance,
+      }
+    }
+
+    for (tx_out, runes) in transaction.output.iter().zip(contents.runes.iter_mut()) {
+      if tx_out.script_pubkey.is_op_return() {
+        for (rune, amount) in std::mem::take(runes) {
+          *contents.burned.entry(rune).or_default() += amount;
+        }
+      }
+    }
+
+    Ok(contents)
+  }
+
+  /// Create a PSBT for `unsigned_transaction` with input UTXOs filled in by
+  /// Bitcoin Core, but no signatures.
+  pub(crate) fn unsigned_psbt(&self, unsigned_transaction: &Transaction) -> Result<Psbt> {
+    let mut psbt = decode_psbt(
+      &self
+        .bitcoin_client()
+        .wallet_process_psbt(
+          &encode_psbt(&Psbt::from_unsigned_tx(unsigned_transaction.clone())?),
+          Some(false),
+          None,
+          None,
+        )?
+        .psbt,
+    )?;
+
+    self.record_psbt_contents(&mut psbt)?;
+
+    Ok(psbt)
+  }
+
+  /// Record where `psbt` sends the inscriptions and runes it spends, both in
+  /// the wallet database and in the PSBT itself.
+  pub(crate) fn record_psbt_contents(&self, psbt: &mut Psbt) -> Result {
+    let contents = self.psbt_contents(psbt)?;
+
+    self.save_psbt_contents(psbt.unsigned_tx.compute_txid(), &contents)?;
+
+    contents.store(psbt)
+  }
+
+  pub(crate) fn check_psbt(&self, psbt: &Psbt) -> Result {
+    let expected = match self.load_psbt_contents(psbt.unsigned_tx.compute_txid())? {
+      Some(contents) => contents,
+      None => PsbtContents::load(psbt)?,
+    };
+
+    let actual = self.psbt_contents(psbt)?;
+
+    for input in &psbt.unsigned_tx.input {
+      let Some(info) = self.output_info.get(&input.previous_output) else {
+        continue;
+      };
+
+      for inscription in &info.inscriptions {
+        ensure!(
+          expected.inscriptions.contains_key(inscription),
+          "PSBT input {} contains inscription {inscription} which PSBT was not created to send",
+          input.previous_output,
+        );
+      }
+    }
+
+    for (inscription, output) in &actual.inscriptions {
+      let Some(expected) = expected.inscriptions.get(inscription) else {
+        bail!("PSBT sends inscription {inscription} which PSBT was not created to send");
+      };
+
+      ensure!(
+        output == expected,
+        "PSBT sends inscription {inscription} to {} but was created to send it to {}",
+        PsbtContents::destination(*output),
+        PsbtContents::destination(*expected),
+      );
+    }
+
+    let runes = |contents: &PsbtContents| {
+      let mut runes = BTreeMap::<Rune, BTreeMap<Option<u32>, u128>>::new();
+
+      for (vout, balances) in contents.runes.iter().enumerate() {
+        for (rune, amount) in balances {
+          runes
+            .entry(*rune)
+            .or_default()
+            .insert(Some(vout.try_into().unwrap()), *amount);
+        }
+      }
+
+      for (rune, amount) in &contents.burned {
+        runes.entry(*rune).or_default().insert(None, *amount);
+      }
+
+      runes
+    };
+
+    let expected = runes(&expected);
+
+    for (rune, allocations) in runes(&actual) {
+      for (output, amount) in allocations {
+        let expected = expected
+          .get(&rune)
+          .and_then(|allocations| allocations.get(&output))
+          .copied()
+          .unwrap_or_default();
+
+        match output {
+          Some(vout) => ensure!(
+            amount == expected,
+            "PSBT sends {amount} {rune} to output {vout} but was created to send {expected}",
+          ),
+          None => ensure!(
+            amount == expected,
+            "PSBT burns {amount} {rune} but was created to burn {expected}",
+          ),
+        }
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn contents_round_trip() {
+    let mut psbt = Psbt::from_unsigned_tx(Transaction {
+      version: Version(2),
+      lock_time: LockTime::ZERO,
+      input: Vec::new(),
+      output: Vec::new(),
+    })
+    .unwrap();
+
+    assert_eq!(PsbtContents::load(&psbt).unwrap(), PsbtContents::default());
+
+    let contents = PsbtContents {
+      inscriptions: [(inscription_id(1), Some(0)), (inscription_id(2), None)].into(),
+      runes: vec![[(Rune(1000), u128::MAX)].into()],
+      burned: [(Rune(1001), 1)].into(),
+    };
+
+    contents.store(&mut psbt).unwrap();
+
+    let psbt = decode_psbt(&encode_psbt(&psbt)).unwrap();
+
+    assert_eq!(PsbtContents::load(&psbt).unwrap(), contents);
+  }
+
+  #[test]
+  fn psbt_file_round_trip() {
+    let tempdir = tempfile::TempDir::new().unwrap();
+
+    let path = tempdir.path().join("psbts");
+
+    let psbts = (0..2)
+      .map(|i| {
+        Psbt::from_unsigned_tx(Transaction {
+          version: Version(2),
+          lock_time: LockTime::from_consensus(i),
+          input: Vec::new(),
+          output: Vec::new(),
+        })
+        .unwrap()
+      })
+      .collect::<Vec<Psbt>>();
+
+    write_psbts(&path, &psbts).unwrap();
+
+    assert_eq!(read_psbts(&path).unwrap(), psbts);
+  }
+}
@@ -115,12 +115,12 @@ pub struct TransactionBuilder {
   target: Target,
   unused_change_addresses: Vec<Address>,
   utxos: BTreeSet<OutPoint>,
+  witness: Witness,
 }
 
 type Result<T> = std::result::Result<T, Error>;
 
 impl TransactionBuilder {
-  const ADDITIONAL_INPUT_VBYTES: usize = 57;
   const ADDITIONAL_OUTPUT_VBYTES: usize = 43;
   const SCHNORR_SIGNATURE_SIZE: usize = 64;
   pub(crate) const MAX_POSTAGE: Amount = Amount::from_sat(2 * 10_000);
@@ -152,9 +152,39 @@ impl TransactionBuilder {
       target,
       unused_change_addresses: change.to_vec(),
       network,
+      witness: Self::dummy_witness(None),
     }
   }
 
+  /// Estimate fees assuming that input witnesses weigh `satisfaction_weight`,
+  /// as computed by `miniscript`, instead of assuming that inputs are taproot
+  /// key path spends. Used for wallets with other than taproot key path
+  /// descriptors, such as multisig, miniscript, and `wpkh` wallets.
+  pub fn satisfaction_weight(mut self, satisfaction_weight: Option<Weight>) -> Self {
+    self.witness = Self::dummy_witness(satisfaction_weight);
+    self
+  }
+
+  /// A witness at least as heavy as one satisfying an input with
+  /// `satisfaction_weight`, or a single Schnorr signature if `None`.
+  pub(crate) fn dummy_witness(satisfaction_weight: Option<Weight>) -> Witness {
+    let Some(satisfaction_weight) = satisfaction_weight else {
+      return Witness::from_slice(&[&[0; Self::SCHNORR_SIGNATURE_SIZE]]);
+    };
+
+    // satisfaction weight excludes the witness element count, but includes
+    // the length prefix of each element
+    let weight = usize::try_from(satisfaction_weight.to_wu()).unwrap();
+
+    let len = if weight <= 253 {
+      weight.saturating_sub(1)
+    } else {
+      (weight - 3).max(253)
+    };
+
+    Witness::from_slice(&[vec![0; len]])
+  }
+
   pub fn build_transaction(self) -> Result<Transaction> {
     if self.change_addresses.len() < 2 {
       return Err(Error::DuplicateAddress(
@@ -322,7 +352,7 @@ impl TransactionBuilder {
 
     if let Some(mut deficit) = total.checked_sub(self.outputs.last().unwrap().value) {
       while deficit > Amount::ZERO {
-        let additional_fee = self.fee_rate.fee(Self::ADDITIONAL_INPUT_VBYTES);
+        let additional_fee = self.fee_rate.fee(self.additional_input_vbytes());
 
         let needed = deficit
           .checked_add(additional_fee)
@@ -437,14 +467,20 @@ impl TransactionBuilder {
   }
 
   /// Estimate the size in virtual bytes of the transaction under construction.
-  /// We initialize wallets with taproot descriptors only, so we know that all
-  /// inputs are taproot key path spends, which allows us to know that witnesses
-  /// will all consist of single Schnorr signatures.
+  /// We initialize wallets with taproot descriptors only, so unless a
+  /// satisfaction weight was provided, we know that all inputs are taproot key
+  /// path spends, which allows us to know that witnesses will all consist of
+  /// single Schnorr signatures.
   fn estimate_vbytes(&self) -> usize {
-    Self::estimate_vbytes_with(self.inputs.len(), &self.outputs)
+    Self::estimate_vbytes_with(self.inputs.len(), &self.outputs, &self.witness)
   }
 
-  fn estimate_vbytes_with(inputs: usize, outputs: &[TxOut]) -> usize {
+  fn additional_input_vbytes(&self) -> usize {
+    Self::estimate_vbytes_with(1, &[], &self.witness)
+      - Self::estimate_vbytes_with(0, &[], &self.witness)
+  }
+
+  fn estimate_vbytes_with(inputs: usize, outputs: &[TxOut], witness: &Witness) -> usize {
     Transaction {
       version: Version(2),
       lock_time: LockTime::ZERO,
@@ -453,7 +489,7 @@ impl TransactionBuilder {
           previous_output: OutPoint::null(),
           script_sig: ScriptBuf::new(),
           sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
-          witness: Witness::from_slice(&[&[0; Self::SCHNORR_SIGNATURE_SIZE]]),
+          witness: witness.clone(),
         })
         .collect(),
       output: outputs.to_vec(),
@@ -613,7 +649,7 @@ impl TransactionBuilder {
 
     let mut modified_tx = transaction.clone();
     for input in &mut modified_tx.input {
-      input.witness = Witness::from_slice(&[&[0; 64]]);
+      input.witness = self.witness.clone();
     }
     let expected_fee = self.fee_rate.fee(modified_tx.vsize());
 
@@ -797,6 +833,7 @@ mod tests {
       ],
       target: Target::Postage,
       network: Network::Testnet,
+      witness: Witness::from_slice(&[&[0; 64]]),
     };
 
     pretty_assert_eq!(
@@ -1370,6 +1407,7 @@ mod tests {
       ],
       target: Target::Postage,
       network: Network::Testnet,
+      witness: Witness::from_slice(&[&[0; 64]]),
     }
     .build()
     .unwrap();
@@ -1411,6 +1449,7 @@ mod tests {
       ],
       target: Target::Postage,
       network: Network::Testnet,
+      witness: Witness::from_slice(&[&[0; 64]]),
     }
     .build()
     .unwrap();
@@ -1659,16 +1698,84 @@ mod tests {
     )
   }
 
+  fn default_builder() -> TransactionBuilder {
+    TransactionBuilder::new(
+      satpoint(1, 0),
+      BTreeMap::new(),
+      BTreeMap::new(),
+      BTreeSet::new(),
+      BTreeSet::new(),
+      recipient(),
+      [change(0), change(1)],
+      FeeRate::try_from(1.0).unwrap(),
+      Target::Postage,
+      Network::Testnet,
+    )
+  }
+
   #[test]
   fn additional_input_size_is_correct() {
-    let before = TransactionBuilder::estimate_vbytes_with(0, &[]);
-    let after = TransactionBuilder::estimate_vbytes_with(1, &[]);
-    assert_eq!(after - before, TransactionBuilder::ADDITIONAL_INPUT_VBYTES);
+    assert_eq!(default_builder().additional_input_vbytes(), 57);
+  }
+
+  #[test]
+  fn satisfaction_weight_is_used_to_estimate_input_size() {
+    for weight in [66, 253, 254, 255, 256, 500] {
+      let builder = default_builder().satisfaction_weight(Some(Weight::from_wu(weight)));
+      let size = u64::try_from(builder.witness.size()).unwrap();
+      assert!(size > weight, "{size} <= {weight}");
+      assert!(size <= weight + 3, "{size} > {weight} + 3");
+    }
+
+    assert_eq!(
+      default_builder()
+        .satisfaction_weight(Some(Weight::from_wu(65)))
+        .witness,
+      default_builder().witness,
+    );
+
+    assert_eq!(
+      default_builder()
+        .satisfaction_weight(Some(Weight::from_wu(254)))
+        .additional_input_vbytes(),
+      105,
+    );
+  }
+
+  #[test]
+  fn transactions_are_built_with_satisfaction_weight() {
+    let utxos = vec![(outpoint(1), tx_out(20_000, address()))];
+
+    let fee_rate = FeeRate::try_from(1.0).unwrap();
+
+    let transaction = TransactionBuilder::new(
+      satpoint(1, 0),
+      BTreeMap::new(),
+      utxos.into_iter().collect(),
+      BTreeSet::new(),
+      BTreeSet::new(),
+      recipient(),
+      [change(0), change(1)],
+      fee_rate,
+      Target::Postage,
+      Network::Testnet,
+    )
+    .satisfaction_weight(Some(Weight::from_wu(254)))
+    .build_transaction()
+    .unwrap();
+
+    let mut signed = transaction.clone();
+    signed.input[0].witness = Witness::from_slice(&[vec![0; 253]]);
+
+    assert_eq!(
+      Amount::from_sat(20_000) - transaction.output[0].value,
+      fee_rate.fee(signed.vsize()),
+    );
   }
 
   #[test]
   fn additional_output_size_is_correct() {
-    let before = TransactionBuilder::estimate_vbytes_with(0, &[]);
+    let before = TransactionBuilder::estimate_vbytes_with(0, &[], &Witness::new());
     let after = TransactionBuilder::estimate_vbytes_with(
       0,
       &[TxOut {
@@ -1679,6 +1786,7 @@ mod tests {
           .script_pubkey(),
         value: Amount::from_sat(0),
       }],
+      &Witness::new(),
     );
     assert_eq!(after - before, TransactionBuilder::ADDITIONAL_OUTPUT_VBYTES);
   }
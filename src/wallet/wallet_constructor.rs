@@ -5,14 +5,18 @@ pub(crate) struct WalletConstructor {
   ord_client: reqwest::blocking::Client,
   name: String,
   no_sync: bool,
+  protect_rarity: Option<Rarity>,
   rpc_url: Url,
   settings: Settings,
+  spend_protected_sats: bool,
 }
 
 impl WalletConstructor {
   pub(crate) fn construct(
     name: String,
     no_sync: bool,
+    protect_rarity: Option<Rarity>,
+    spend_protected_sats: bool,
     settings: Settings,
     rpc_url: Url,
   ) -> Result<Wallet> {
@@ -38,8 +42,10 @@ impl WalletConstructor {
         .build()?,
       name,
       no_sync,
+      protect_rarity,
       rpc_url,
       settings,
+      spend_protected_sats,
     }
     .build()
   }
@@ -47,7 +53,7 @@ impl WalletConstructor {
   pub(crate) fn build(self) -> Result<Wallet> {
     let database = Wallet::open_database(&self.name, &self.settings)?;
 
-    let bitcoin_client = {
+    let (bitcoin_client, satisfaction_weight, watch_only) = {
       let client =
         Wallet::check_version(self.settings.bitcoin_rpc_client(Some(self.name.clone()))?)?;
 
@@ -55,16 +61,19 @@ impl WalletConstructor {
         client.load_wallet(&self.name)?;
       }
 
-      if client.get_wallet_info()?.private_keys_enabled {
-        Wallet::check_descriptors(
-          &self.name,
-          client
-            .call::<ListDescriptorsResult>("listdescriptors", &[serde_json::Value::Null])?
-            .descriptors,
-        )?;
+      let watch_only = !client.get_wallet_info()?.private_keys_enabled;
+
+      let descriptors = client
+        .call::<ListDescriptorsResult>("listdescriptors", &[serde_json::Value::Null])?
+        .descriptors;
+
+      let satisfaction_weight = Wallet::descriptor_satisfaction_weight(&descriptors)?;
+
+      if !watch_only {
+        Wallet::check_descriptors(&self.name, descriptors)?;
       }
 
-      client
+      (client, satisfaction_weight, watch_only)
     };
 
     let chain_block_count = bitcoin_client.get_block_count().unwrap() + 1;
@@ -88,7 +97,7 @@ impl WalletConstructor {
     }
 
     let mut utxos = Self::get_utxos(&bitcoin_client)?;
-    let locked_utxos = Self::get_locked_utxos(&bitcoin_client)?;
+    let mut locked_utxos = Self::get_locked_utxos(&bitcoin_client)?;
     utxos.extend(locked_utxos.clone());
 
     let output_info = self.get_output_info(utxos.clone().into_keys().collect())?;
@@ -102,6 +111,41 @@ impl WalletConstructor {
 
     let status = self.get_server_status()?;
 
+    let mut protected_outputs = match self.protect_rarity {
+      Some(rarity) => {
+        ensure!(
+          status.sat_index || self.spend_protected_sats,
+          "`--protect-rarity` requires index created with `--index-sats` flag",
+        );
+
+        Wallet::protected_outputs_with(&output_info, rarity)
+      }
+      None => BTreeSet::new(),
+    };
+
+    if self.spend_protected_sats {
+      // protected outputs may have been locked by a previous command, so
+      // unlock those which do not also contain inscriptions or runes
+      let unlock = protected_outputs
+        .iter()
+        .filter(|output| {
+          let info = &output_info[output];
+          locked_utxos.contains_key(output) && info.inscriptions.is_empty() && info.runes.is_empty()
+        })
+        .cloned()
+        .collect::<Vec<OutPoint>>();
+
+      if !unlock.is_empty() && !bitcoin_client.unlock_unspent(&unlock)? {
+        bail!("failed to unlock UTXOs");
+      }
+
+      for output in unlock {
+        locked_utxos.remove(&output);
+      }
+
+      protected_outputs.clear();
+    }
+
     Ok(Wallet {
       bitcoin_client,
       database,
@@ -112,9 +156,12 @@ impl WalletConstructor {
       locked_utxos,
       ord_client: self.ord_client,
       output_info,
+      protected_outputs,
       rpc_url: self.rpc_url,
+      satisfaction_weight,
       settings: self.settings,
       utxos,
+      watch_only,
     })
   }
 
@@ -34,6 +34,67 @@ fn run() {
   child.kill().unwrap();
 }
 
+#[test]
+fn virtual_host_settings_ignore_environment() {
+  let core = mockcore::spawn();
+
+  let signet = mockcore::builder().network(Network::Signet).build();
+
+  let tempdir = TempDir::new().unwrap();
+
+  let config = tempdir.path().join("signet.yaml");
+
+  fs::write(
+    &config,
+    format!(
+      "bitcoin_rpc_url: {}\nchain: signet\ncookie_file: {}\ndata_dir: {}\n",
+      signet.url(),
+      signet.cookie_file().display(),
+      tempdir.path().display(),
+    ),
+  )
+  .unwrap();
+
+  let port = TcpListener::bind("127.0.0.1:0")
+    .unwrap()
+    .local_addr()
+    .unwrap()
+    .port();
+
+  let mut child = CommandBuilder::new(format!(
+    "server --address 127.0.0.1 --http-port {port} --virtual-host signet.localhost={}",
+    config.display()
+  ))
+  .core(&core)
+  .env("ORD_CHAIN", "mainnet")
+  .command()
+  .spawn()
+  .unwrap();
+
+  let client = reqwest::blocking::Client::new();
+
+  for attempt in 0.. {
+    if let Ok(response) = client
+      .get(format!("http://127.0.0.1:{port}/"))
+      .header(reqwest::header::HOST, "signet.localhost")
+      .send()
+    {
+      if response.status() == 200 {
+        assert_regex_match!(response.text().unwrap(), ".*<sup>signet</sup>.*");
+        break;
+      }
+    }
+
+    if attempt == 100 {
+      panic!("Server did not respond");
+    }
+
+    thread::sleep(Duration::from_millis(50));
+  }
+
+  child.kill().unwrap();
+}
+
 #[test]
 fn address_page_shows_outputs_and_sat_balance() {
   let core = mockcore::spawn();
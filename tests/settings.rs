@@ -28,6 +28,7 @@ fn default() {
   "index_transactions": false,
   "integration_test": false,
   "no_index_inscriptions": false,
+  "protect_rarity": null,
   "server_password": null,
   "server_url": null,
   "server_username": null
@@ -3,14 +3,19 @@ use super::*;
 mod authentication;
 mod balance;
 mod batch_command;
+mod bump;
 mod burn;
 mod cardinals;
+mod consolidate;
 mod create;
+mod create_tr;
 mod dump;
+mod extract_sats;
 mod inscribe;
 mod inscriptions;
 mod label;
 mod mint;
+mod offer;
 mod outputs;
 #[cfg(unix)]
 mod pending;
@@ -18,8 +23,12 @@ mod receive;
 mod restore;
 #[cfg(unix)]
 mod resume;
+mod reveal;
 mod runics;
 mod sats;
 mod selection;
 mod send;
+mod sign;
+mod split;
 mod transactions;
+mod transfer;
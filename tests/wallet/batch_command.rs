@@ -55,6 +55,17 @@ fn batch_inscribe_can_create_one_inscription() {
     .ord(&ord)
     .run_and_deserialize_output::<Batch>();
 
+  assert_eq!(
+    output.reveals,
+    [ord::wallet::batch::RevealOutput {
+      commit: output.commit,
+      commit_psbt: None,
+      reveal: output.reveal,
+      reveal_psbt: None,
+      total_fees: output.total_fees,
+    }],
+  );
+
   core.mine_blocks(1);
 
   assert_eq!(core.descriptors().len(), 3);
@@ -2807,3 +2818,288 @@ fn forbid_etching_below_rune_activation_height() {
     .expected_exit_code(1)
     .run_and_extract_stdout();
 }
+
+#[test]
+fn batch_estimate_does_not_require_funded_wallet() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  let estimate = CommandBuilder::new("wallet batch --fee-rate 3.5 --batch batch.yaml --estimate")
+    .write("inscription.txt", "Hello World")
+    .write("tulip.png", [0; 555])
+    .write(
+      "batch.yaml",
+      "mode: separate-outputs\ninscriptions:\n- file: inscription.txt\n- file: tulip.png\n",
+    )
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<batch::Estimate>();
+
+  assert!(core.mempool().is_empty());
+
+  assert_eq!(estimate.inscriptions, 2);
+  assert_eq!(estimate.postage, 20_000);
+  assert!(estimate.standard);
+  assert_eq!(estimate.reveals, [2]);
+  assert!(estimate.commit_vsize > 0);
+  assert!(estimate.reveal_vsize > 555 / 4);
+  assert_eq!(estimate.reveal_weight.div_ceil(4), estimate.reveal_vsize);
+
+  assert_eq!(
+    estimate
+      .fees
+      .iter()
+      .map(|fees| fees.fee_rate)
+      .collect::<Vec<f64>>(),
+    [1.0, 2.0, 3.5, 5.0, 10.0, 20.0, 50.0, 100.0],
+  );
+
+  for fees in estimate.fees {
+    assert_eq!(
+      fees.commit,
+      (fees.fee_rate * estimate.commit_vsize as f64).round() as u64
+    );
+    assert_eq!(
+      fees.reveal,
+      (fees.fee_rate * estimate.reveal_vsize as f64).round() as u64
+    );
+    assert_eq!(fees.total, fees.commit + fees.reveal);
+  }
+}
+
+#[test]
+fn batch_estimate_suggests_split_of_oversized_reveal() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  let estimate = CommandBuilder::new("wallet batch --fee-rate 1 --batch batch.yaml --estimate")
+    .write("a.bin", [0; 150_000])
+    .write("b.bin", [1; 150_000])
+    .write("c.bin", [2; 150_000])
+    .write(
+      "batch.yaml",
+      "mode: shared-output\ninscriptions:\n- file: a.bin\n- file: b.bin\n- file: c.bin\n",
+    )
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<batch::Estimate>();
+
+  assert!(!estimate.standard);
+  assert!(estimate.reveal_weight > 400_000);
+  assert_eq!(estimate.reveals, [2, 1]);
+}
+
+#[test]
+fn batch_estimate_conflicts_with_dry_run() {
+  CommandBuilder::new("wallet batch --fee-rate 1 --batch batch.yaml --estimate --dry-run")
+    .stderr_regex(".*cannot be used with.*")
+    .expected_exit_code(2)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn batch_inscribe_splits_oversized_batch_across_reveals() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let parent_id = CommandBuilder::new("wallet inscribe --fee-rate 5.0 --file parent.png")
+    .write("parent.png", [1; 520])
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Batch>()
+    .inscriptions[0]
+    .id;
+
+  core.mine_blocks(1);
+
+  let output = CommandBuilder::new("wallet batch --fee-rate 1 --batch batch.yaml")
+    .write("a.bin", [0; 150_000])
+    .write("b.bin", [1; 150_000])
+    .write("c.bin", [2; 150_000])
+    .write(
+      "batch.yaml",
+      format!("parents:\n- {parent_id}\nmode: shared-output\ninscriptions:\n- file: a.bin\n- file: b.bin\n- file: c.bin\n"),
+    )
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Batch>();
+
+  assert_eq!(output.reveals.len(), 2);
+  assert_eq!(output.commit, output.reveals[0].commit);
+  assert_eq!(output.reveal, output.reveals[1].reveal);
+  assert_ne!(output.reveals[0].reveal, output.reveals[1].reveal);
+  assert_eq!(output.inscriptions.len(), 3);
+  assert_eq!(output.inscriptions[0].id.txid, output.reveals[0].reveal);
+  assert_eq!(output.inscriptions[1].id.txid, output.reveals[0].reveal);
+  assert_eq!(output.inscriptions[2].id.txid, output.reveals[1].reveal);
+  assert_eq!(
+    output.total_fees,
+    output.reveals[0].total_fees + output.reveals[1].total_fees
+  );
+  assert!(output.reveal_broadcast);
+  assert_eq!(core.mempool().len(), 4);
+
+  core.mine_blocks(1);
+
+  for (output, contents) in output.inscriptions.iter().zip([0, 1, 2]) {
+    let inscription = serde_json::from_str::<api::Inscription>(
+      &ord
+        .json_request(format!("/inscription/{}", output.id))
+        .text()
+        .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(inscription.parents, [parent_id]);
+    assert_eq!(inscription.satpoint, output.location);
+
+    let content = ord
+      .request(format!("/content/{}", output.id))
+      .bytes()
+      .unwrap();
+
+    assert_eq!(content.len(), 150_000);
+    assert!(content.iter().all(|byte| *byte == contents));
+  }
+}
+
+#[test]
+fn batch_inscribe_splits_oversized_separate_outputs_batch() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let output = CommandBuilder::new("wallet batch --fee-rate 1 --batch batch.yaml")
+    .write("a.bin", [0; 150_000])
+    .write("b.bin", [1; 150_000])
+    .write("c.bin", [2; 150_000])
+    .write(
+      "batch.yaml",
+      "mode: separate-outputs\ninscriptions:\n- file: a.bin\n- file: b.bin\n- file: c.bin\n",
+    )
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Batch>();
+
+  assert_eq!(output.reveals.len(), 2);
+  assert_eq!(output.inscriptions.len(), 3);
+
+  core.mine_blocks(1);
+
+  for output in output.inscriptions {
+    let inscription = serde_json::from_str::<api::Inscription>(
+      &ord
+        .json_request(format!("/inscription/{}", output.id))
+        .text()
+        .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(inscription.satpoint, output.location);
+    assert_eq!(inscription.value, Some(10_000));
+  }
+}
+
+#[test]
+fn batch_inscribe_does_not_split_same_sat_batch() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new("wallet batch --fee-rate 1 --batch batch.yaml")
+    .write("a.bin", [0; 150_000])
+    .write("b.bin", [1; 150_000])
+    .write("c.bin", [2; 150_000])
+    .write(
+      "batch.yaml",
+      "mode: same-sat\ninscriptions:\n- file: a.bin\n- file: b.bin\n- file: c.bin\n",
+    )
+    .core(&core)
+    .ord(&ord)
+    .expected_stderr("error: same-sat batches must fit in a single reveal transaction\n")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn batch_inscribe_dry_run_splits_oversized_batch() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let output = CommandBuilder::new("wallet batch --fee-rate 1 --batch batch.yaml --dry-run")
+    .write("a.bin", [0; 150_000])
+    .write("b.bin", [1; 150_000])
+    .write("c.bin", [2; 150_000])
+    .write(
+      "batch.yaml",
+      "mode: shared-output\ninscriptions:\n- file: a.bin\n- file: b.bin\n- file: c.bin\n",
+    )
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Batch>();
+
+  assert!(core.mempool().is_empty());
+  assert!(!output.reveal_broadcast);
+  assert_eq!(output.reveals.len(), 2);
+
+  for reveal in output.reveals {
+    assert!(reveal.commit_psbt.is_some());
+    assert!(reveal.reveal_psbt.is_some());
+  }
+}
+
+#[test]
+fn batch_inscribe_does_not_split_beyond_unconfirmed_chain_limit() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let mut builder = CommandBuilder::new("wallet batch --fee-rate 1 --batch batch.yaml");
+
+  let mut batch = "mode: separate-outputs\ninscriptions:\n".to_string();
+
+  for i in 0..26 {
+    builder = builder.write(format!("{i}.bin"), [i; 150_000]);
+    batch.push_str(&format!("- file: {i}.bin\n"));
+  }
+
+  builder
+    .write("batch.yaml", batch)
+    .core(&core)
+    .ord(&ord)
+    .expected_stderr(
+      "error: batch would be split across 13 reveal transactions, but Bitcoin Core does not accept chains of more than 25 unconfirmed transactions\n",
+    )
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  assert!(core.mempool().is_empty());
+}
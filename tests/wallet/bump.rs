@@ -0,0 +1,225 @@
+use {super::*, ord::subcommand::wallet::bump};
+
+#[test]
+fn bump_replaces_transaction_paying_fee_from_change() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let send =
+    CommandBuilder::new("wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc")
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<Send>();
+
+  let original = core.mempool()[0].clone();
+
+  let output = CommandBuilder::new(format!("wallet bump --fee-rate 10 --txid {}", send.txid))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<bump::Output>();
+
+  assert_eq!(output.method, bump::Method::Rbf);
+
+  let mempool = core.mempool();
+
+  assert_eq!(mempool.len(), 1);
+
+  let replacement = &mempool[0];
+
+  assert_eq!(replacement.compute_txid(), output.txid);
+  assert_ne!(output.txid, send.txid);
+  assert_eq!(replacement.input, original.input);
+  assert_eq!(replacement.output[0], original.output[0]);
+  assert_eq!(replacement.output.len(), original.output.len());
+  assert!(output.fee >= 10 * u64::try_from(replacement.vsize()).unwrap());
+  assert_eq!(
+    original.output.last().unwrap().value - replacement.output.last().unwrap().value,
+    Amount::from_sat(output.fee) - Amount::from_sat(u64::try_from(original.vsize()).unwrap()),
+  );
+}
+
+#[test]
+fn bump_preserves_inscription_destination() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  let (inscription, _) = inscribe_with_postage(&core, &ord, Some(30_000));
+
+  let address = "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4";
+
+  let send = CommandBuilder::new(format!("wallet send --fee-rate 1 {address} {inscription}"))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Send>();
+
+  let output = CommandBuilder::new(format!("wallet bump --fee-rate 5 --txid {}", send.txid))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<bump::Output>();
+
+  assert_eq!(output.method, bump::Method::Rbf);
+
+  core.mine_blocks(1);
+
+  let inscription = ord
+    .json_request(format!("/inscription/{inscription}"))
+    .json::<api::Inscription>()
+    .unwrap();
+
+  assert_eq!(
+    inscription.satpoint,
+    SatPoint {
+      outpoint: OutPoint {
+        txid: output.txid,
+        vout: 0,
+      },
+      offset: 0,
+    }
+  );
+
+  assert_eq!(inscription.address, Some(address.into()));
+}
+
+#[test]
+fn bump_reveal_transaction_with_child() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(2);
+
+  let batch = CommandBuilder::new("wallet inscribe --fee-rate 1 --file foo.txt")
+    .write("foo.txt", "FOO")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Batch>();
+
+  let output = CommandBuilder::new(format!("wallet bump --fee-rate 20 --txid {}", batch.reveal))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<bump::Output>();
+
+  assert_eq!(output.method, bump::Method::Cpfp);
+
+  let mempool = core.mempool();
+
+  assert_eq!(mempool.len(), 3);
+
+  let child = &mempool[2];
+
+  assert_eq!(child.compute_txid(), output.txid);
+  assert_eq!(
+    child.input[0].previous_output,
+    OutPoint {
+      txid: batch.reveal,
+      vout: 0,
+    }
+  );
+  assert_eq!(child.output[0].value, mempool[1].output[0].value);
+
+  let reveal = &mempool[1];
+
+  let reveal_fee =
+    mempool[0].output[usize::try_from(reveal.input[0].previous_output.vout).unwrap()].value
+      - reveal.output[0].value;
+
+  let package_vsize = u64::try_from(reveal.vsize() + child.vsize()).unwrap();
+
+  assert!(reveal_fee.to_sat() + output.fee >= 20 * package_vsize);
+
+  core.mine_blocks(1);
+
+  assert_eq!(
+    ord
+      .json_request(format!("/inscription/{}", batch.inscriptions[0].id))
+      .json::<api::Inscription>()
+      .unwrap()
+      .satpoint,
+    SatPoint {
+      outpoint: OutPoint {
+        txid: output.txid,
+        vout: 0,
+      },
+      offset: 0,
+    }
+  );
+}
+
+#[test]
+fn bump_commit_transaction_with_pending_reveal_uses_child() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(2);
+
+  let batch = CommandBuilder::new("wallet inscribe --fee-rate 1 --file foo.txt")
+    .write("foo.txt", "FOO")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Batch>();
+
+  let output = CommandBuilder::new(format!("wallet bump --fee-rate 20 --txid {}", batch.commit))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<bump::Output>();
+
+  assert_eq!(output.method, bump::Method::Cpfp);
+
+  let mempool = core.mempool();
+
+  assert_eq!(mempool.len(), 3);
+
+  assert_eq!(mempool[0].compute_txid(), batch.commit);
+  assert_eq!(mempool[1].compute_txid(), batch.reveal);
+
+  let child = &mempool[2];
+
+  assert_eq!(child.compute_txid(), output.txid);
+  assert_eq!(child.input[0].previous_output.txid, batch.commit);
+
+  core.mine_blocks(1);
+
+  assert_eq!(
+    ord
+      .json_request(format!("/inscription/{}", batch.inscriptions[0].id))
+      .json::<api::Inscription>()
+      .unwrap()
+      .satpoint
+      .outpoint
+      .txid,
+    batch.reveal,
+  );
+}
+
+#[test]
+fn bump_confirmed_transaction_fails() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  let (_, reveal) = inscribe(&core, &ord);
+
+  CommandBuilder::new(format!("wallet bump --fee-rate 20 --txid {reveal}"))
+    .core(&core)
+    .ord(&ord)
+    .expected_exit_code(1)
+    .expected_stderr(format!(
+      "error: transaction {reveal} is already confirmed\n"
+    ))
+    .run_and_extract_stdout();
+}
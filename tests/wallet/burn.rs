@@ -212,3 +212,125 @@ fn cannot_burn_with_excess_postage() {
   .expected_exit_code(1)
   .run_and_extract_stdout();
 }
+
+#[test]
+fn runes_can_be_burned() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--regtest", "--index-runes"], &[]);
+
+  create_wallet(&core, &ord);
+
+  etch(&core, &ord, Rune(RUNE));
+
+  let output = CommandBuilder::new(format!(
+    "--regtest --index-runes wallet burn --fee-rate 1 --rune {} --amount 400",
+    Rune(RUNE)
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  assert_eq!(
+    output.outgoing,
+    Outgoing::Rune {
+      decimal: "400".parse().unwrap(),
+      rune: SpacedRune::new(Rune(RUNE), 0),
+    }
+  );
+
+  core.mine_blocks(1);
+
+  let rune = ord
+    .json_request(format!("/rune/{}", Rune(RUNE)))
+    .json::<api::Rune>()
+    .unwrap();
+
+  assert_eq!(rune.entry.burned, 400);
+
+  let balances = CommandBuilder::new("--regtest --index-runes balances")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<ord::subcommand::balances::Output>();
+
+  pretty_assert_eq!(
+    balances.runes[&SpacedRune::new(Rune(RUNE), 0)],
+    [(
+      OutPoint {
+        txid: output.txid,
+        vout: 1,
+      },
+      Pile {
+        amount: 600,
+        divisibility: 0,
+        symbol: Some('¢'),
+      },
+    )]
+    .into_iter()
+    .collect(),
+  );
+}
+
+#[test]
+fn entire_rune_balance_can_be_burned() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--regtest", "--index-runes"], &[]);
+
+  create_wallet(&core, &ord);
+
+  etch(&core, &ord, Rune(RUNE));
+
+  CommandBuilder::new(format!(
+    "--regtest --index-runes wallet burn --fee-rate 1 --rune {} --amount 1000",
+    Rune(RUNE)
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  core.mine_blocks(1);
+
+  let rune = ord
+    .json_request(format!("/rune/{}", Rune(RUNE)))
+    .json::<api::Rune>()
+    .unwrap();
+
+  assert_eq!(rune.entry.burned, 1000);
+
+  let balances = CommandBuilder::new("--regtest --index-runes balances")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<ord::subcommand::balances::Output>();
+
+  assert!(balances.runes.is_empty());
+}
+
+#[test]
+fn burning_more_runes_than_balance_fails() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--regtest", "--index-runes"], &[]);
+
+  create_wallet(&core, &ord);
+
+  etch(&core, &ord, Rune(RUNE));
+
+  CommandBuilder::new(format!(
+    "--regtest --index-runes wallet burn --fee-rate 1 --rune {} --amount 1001",
+    Rune(RUNE)
+  ))
+  .core(&core)
+  .ord(&ord)
+  .expected_stderr("error: insufficient `AAAAAAAAAAAAA` balance, only 1000\u{A0}¢ in wallet\n")
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn burning_runes_requires_amount() {
+  CommandBuilder::new(format!("wallet burn --fee-rate 1 --rune {}", Rune(RUNE)))
+    .stderr_regex(".*--amount <AMOUNT>.*")
+    .expected_exit_code(2)
+    .run_and_extract_stdout();
+}
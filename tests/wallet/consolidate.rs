@@ -0,0 +1,177 @@
+use {
+  super::*,
+  ord::subcommand::wallet::{consolidate, receive},
+};
+
+#[test]
+fn consolidate_merges_cardinal_outputs() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let (inscription, _) = inscribe(&core, &ord);
+
+  core.mine_blocks(2);
+
+  let inscription_output = ord
+    .json_request(format!("/inscription/{inscription}"))
+    .json::<api::Inscription>()
+    .unwrap()
+    .satpoint
+    .outpoint;
+
+  let output = CommandBuilder::new("wallet consolidate --fee-rate 1")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<consolidate::Output>();
+
+  let transaction = &core.mempool()[0];
+
+  assert_eq!(transaction.compute_txid(), output.txid);
+  assert_eq!(transaction.input.len(), output.inputs);
+  assert_eq!(transaction.output.len(), 1);
+  assert!(output.inputs >= 2);
+  assert!(output.runes.is_empty());
+
+  assert!(transaction
+    .input
+    .iter()
+    .all(|input| input.previous_output != inscription_output));
+
+  core.mine_blocks(1);
+
+  let cardinals = CommandBuilder::new("wallet cardinals")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<ord::subcommand::wallet::cardinals::CardinalUtxo>>();
+
+  assert!(cardinals.iter().any(|cardinal| cardinal.output
+    == OutPoint {
+      txid: output.txid,
+      vout: 0
+    }));
+}
+
+#[test]
+fn consolidate_respects_max_inputs() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(3);
+
+  let output = CommandBuilder::new("wallet consolidate --fee-rate 1 --max-inputs 2")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<consolidate::Output>();
+
+  assert_eq!(output.inputs, 2);
+  assert_eq!(core.mempool()[0].input.len(), 2);
+}
+
+#[test]
+fn consolidate_requires_two_outputs() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new("wallet consolidate --fee-rate 1")
+    .core(&core)
+    .ord(&ord)
+    .expected_exit_code(1)
+    .expected_stderr("error: wallet does not contain enough outputs to consolidate\n")
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn consolidate_does_not_spend_rare_sats() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(3);
+
+  CommandBuilder::new("--index-sats wallet consolidate --fee-rate 1")
+    .core(&core)
+    .ord(&ord)
+    .expected_exit_code(1)
+    .expected_stderr("error: wallet does not contain enough outputs to consolidate\n")
+    .run_and_extract_stdout();
+
+  assert!(core.mempool().is_empty());
+}
+
+#[test]
+fn consolidate_merges_rune_balances() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-runes", "--regtest"], &[]);
+
+  create_wallet(&core, &ord);
+
+  etch(&core, &ord, Rune(RUNE));
+
+  let address = CommandBuilder::new("--regtest wallet receive")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<receive::Output>()
+    .addresses
+    .into_iter()
+    .next()
+    .unwrap()
+    .assume_checked();
+
+  CommandBuilder::new(format!(
+    "--regtest --index-runes wallet send --fee-rate 1 {address} 400:{}",
+    Rune(RUNE),
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  core.mine_blocks(1);
+
+  let output =
+    CommandBuilder::new("--regtest --index-runes wallet consolidate --fee-rate 1 --runes")
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<consolidate::Output>();
+
+  assert_eq!(
+    output.runes,
+    [SpacedRune::new(Rune(RUNE), 0)].into_iter().collect(),
+  );
+
+  core.mine_blocks(1);
+
+  let balances = CommandBuilder::new("--regtest --index-runes balances")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<ord::subcommand::balances::Output>();
+
+  assert_eq!(
+    balances.runes[&SpacedRune::new(Rune(RUNE), 0)]
+      .iter()
+      .map(|(outpoint, pile)| (*outpoint, pile.amount))
+      .collect::<Vec<(OutPoint, u128)>>(),
+    [(
+      OutPoint {
+        txid: output.txid,
+        vout: 1,
+      },
+      1000,
+    )],
+  );
+}
@@ -0,0 +1,93 @@
+use {super::*, ord::subcommand::wallet::create_tr};
+
+#[test]
+fn create_tr_derives_addresses_at_index_range() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn(&core);
+
+  create_wallet(&core, &ord);
+
+  let output = CommandBuilder::new("wallet create-tr --start 5 --number 2")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<create_tr::Output>();
+
+  assert_eq!(output.addresses.len(), 2);
+
+  for (address, index) in output.addresses.iter().zip(5..) {
+    assert_eq!(address.index, index);
+    assert_eq!(address.derivation_path, format!("m/86'/0'/0'/0/{index}"));
+    assert_eq!(address.script_tree, None);
+    assert_eq!(
+      address.address.clone().assume_checked(),
+      Address::p2tr(
+        &bitcoin::secp256k1::Secp256k1::new(),
+        address.internal_key,
+        None,
+        Network::Bitcoin,
+      ),
+    );
+  }
+
+  assert_ne!(output.addresses[0].address, output.addresses[1].address);
+
+  let again = CommandBuilder::new("wallet create-tr --start 5")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<create_tr::Output>();
+
+  assert_eq!(again.addresses[0], output.addresses[0]);
+}
+
+#[test]
+fn create_tr_with_tapscript() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn(&core);
+
+  create_wallet(&core, &ord);
+
+  let key_path = CommandBuilder::new("wallet create-tr")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<create_tr::Output>();
+
+  let script_path = CommandBuilder::new([
+    "wallet",
+    "create-tr",
+    "--tapscript",
+    "and_v(v:pk(f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9),older(144))",
+  ])
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<create_tr::Output>();
+
+  let key_path = &key_path.addresses[0];
+  let script_path = &script_path.addresses[0];
+
+  assert_eq!(key_path.internal_key, script_path.internal_key);
+  assert_ne!(key_path.address, script_path.address);
+
+  let script_tree = script_path.script_tree.as_ref().unwrap();
+
+  assert_eq!(script_tree.leaf_version, 0xc0);
+  assert_eq!(
+    script_tree.script.to_asm_string(),
+    "OP_PUSHBYTES_32 f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9 \
+     OP_CHECKSIGVERIFY OP_PUSHBYTES_2 9000 OP_CSV",
+  );
+}
+
+#[test]
+fn create_tr_rejects_invalid_tapscript() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn(&core);
+
+  create_wallet(&core, &ord);
+
+  CommandBuilder::new("wallet create-tr --tapscript foo")
+    .core(&core)
+    .ord(&ord)
+    .expected_exit_code(2)
+    .stderr_regex("error: invalid value 'foo' for '--tapscript <MINISCRIPT>'.*")
+    .run_and_extract_stdout();
+}
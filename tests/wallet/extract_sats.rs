@@ -0,0 +1,142 @@
+use {
+  super::*,
+  ord::subcommand::wallet::{extract_sats, sats::OutputRare},
+};
+
+#[test]
+fn extract_sats_requires_sat_index() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  CommandBuilder::new("wallet extract-sats --fee-rate 1")
+    .core(&core)
+    .ord(&ord)
+    .expected_exit_code(1)
+    .expected_stderr("error: extracting sats requires index created with `--index-sats` flag\n")
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn rare_sats_are_extracted_and_locked() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let output = CommandBuilder::new("--index-sats wallet extract-sats --fee-rate 1")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<extract_sats::Output>();
+
+  let outpoint = OutPoint {
+    txid: output.txid,
+    vout: 0,
+  };
+
+  assert_eq!(
+    output.extracted,
+    [extract_sats::ExtractedSat {
+      output: outpoint,
+      rarity: Rarity::Uncommon,
+      sat: Sat(50 * COIN_VALUE),
+    }],
+  );
+
+  assert!(core.get_locked().contains(&outpoint));
+
+  core.mine_blocks(1);
+
+  let transaction = core.tx_by_id(output.txid);
+
+  assert_eq!(transaction.output[0].value.to_sat(), 10_000);
+  assert_eq!(
+    transaction
+      .output
+      .iter()
+      .map(|output| output.value)
+      .sum::<Amount>()
+      + Amount::from_sat(output.fee),
+    Amount::from_sat(50 * COIN_VALUE),
+  );
+
+  let sats = CommandBuilder::new("--index-sats wallet sats")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<OutputRare>>();
+
+  assert!(sats
+    .iter()
+    .any(|rare| rare.sat == 50 * COIN_VALUE && rare.output == outpoint && rare.offset == 0));
+
+  let output = CommandBuilder::new("--index-sats wallet extract-sats --fee-rate 1")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<extract_sats::Output>();
+
+  assert_eq!(
+    output
+      .extracted
+      .iter()
+      .map(|extracted| extracted.sat)
+      .collect::<Vec<Sat>>(),
+    [Sat(100 * COIN_VALUE)],
+  );
+}
+
+#[test]
+fn sats_listed_in_tsv_are_extracted() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let sat = 50 * COIN_VALUE + 1000;
+
+  let output = CommandBuilder::new(
+    "--index-sats wallet extract-sats --fee-rate 1 --postage 546sat --tsv sats.tsv",
+  )
+  .write("sats.tsv", format!("{sat}\n"))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<extract_sats::Output>();
+
+  assert_eq!(output.extracted.len(), 1);
+  assert_eq!(output.extracted[0].sat, sat);
+  assert_eq!(output.extracted[0].output.vout, 1);
+
+  core.mine_blocks(1);
+
+  let transaction = core.tx_by_id(output.txid);
+
+  assert_eq!(transaction.output[0].value.to_sat(), 1000);
+  assert_eq!(transaction.output[1].value.to_sat(), 546);
+}
+
+#[test]
+fn extract_sats_dry_run_does_not_lock() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let output = CommandBuilder::new("--index-sats wallet extract-sats --fee-rate 1 --dry-run")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<extract_sats::Output>();
+
+  assert_eq!(output.extracted.len(), 1);
+  assert!(core.mempool().is_empty());
+  assert!(!core.get_locked().contains(&output.extracted[0].output));
+}
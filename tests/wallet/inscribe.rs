@@ -1144,6 +1144,92 @@ fn server_can_decompress_brotli() {
   assert_eq!(response.bytes().unwrap().deref(), [0; 350_000]);
 }
 
+#[test]
+fn inscribe_can_compress_with_gzip_and_zstd() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  let mut inscriptions = Vec::new();
+
+  for algorithm in ["gzip", "zstd"] {
+    core.mine_blocks(1);
+
+    let batch = CommandBuilder::new(format!(
+      "wallet inscribe --compress={algorithm} --file foo.txt --fee-rate 1"
+    ))
+    .write("foo.txt", [0; 350_000])
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Batch>();
+
+    inscriptions.push((algorithm, batch.inscriptions[0].id));
+  }
+
+  core.mine_blocks(1);
+
+  ord.sync_server();
+
+  let decompressing = TestServer::spawn_with_server_args(&core, &[], &["--decompress"]);
+
+  decompressing.sync_server();
+
+  let client = reqwest::blocking::Client::builder()
+    .brotli(false)
+    .build()
+    .unwrap();
+
+  for (algorithm, inscription) in inscriptions {
+    let response = client
+      .get(
+        ord
+          .url()
+          .join(format!("/content/{inscription}").as_ref())
+          .unwrap(),
+      )
+      .send()
+      .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+
+    let response = client
+      .get(
+        ord
+          .url()
+          .join(format!("/content/{inscription}").as_ref())
+          .unwrap(),
+      )
+      .header(reqwest::header::ACCEPT_ENCODING, algorithm)
+      .send()
+      .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response
+        .headers()
+        .get(reqwest::header::CONTENT_ENCODING)
+        .unwrap(),
+      algorithm
+    );
+    assert!(response.bytes().unwrap().len() < 350_000);
+
+    let response = client
+      .get(
+        decompressing
+          .url()
+          .join(format!("/content/{inscription}").as_ref())
+          .unwrap(),
+      )
+      .send()
+      .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.bytes().unwrap().deref(), [0; 350_000]);
+  }
+}
+
 #[test]
 fn file_inscribe_with_delegate_inscription() {
   let core = mockcore::spawn();
@@ -28,3 +28,134 @@ fn label() {
 
   assert!(output.contains(&inscription.to_string()));
 }
+
+#[test]
+fn imported_labels_are_shown_and_round_trip() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(2);
+
+  let (inscription, reveal) = inscribe(&core, &ord);
+
+  let location = OutPoint {
+    txid: reveal,
+    vout: 0,
+  };
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  let output = CommandBuilder::new("wallet label import labels.jsonl")
+    .temp_dir(tempdir.clone())
+    .write(
+      "labels.jsonl",
+      format!(
+        "{{\"type\":\"tx\",\"ref\":\"{reveal}\",\"label\":\"reveal\"}}\n\
+         {{\"type\":\"output\",\"ref\":\"{location}\",\"label\":\"cat\",\"spendable\":false}}\n\
+         {{\"type\":\"addr\",\"ref\":\"bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4\",\"label\":\"friend\"}}\n"
+      ),
+    )
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<ord::subcommand::wallet::label::Output>();
+
+  assert_eq!(output.labels, 3);
+
+  let outputs = CommandBuilder::new("wallet outputs")
+    .temp_dir(tempdir.clone())
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<ord::subcommand::wallet::outputs::Output>>();
+
+  assert_eq!(
+    outputs
+      .iter()
+      .find(|output| output.output == location)
+      .unwrap()
+      .label,
+    Some("cat".into()),
+  );
+
+  assert!(outputs
+    .iter()
+    .filter(|output| output.output != location)
+    .all(|output| output.label.is_none()));
+
+  let inscriptions = CommandBuilder::new("wallet inscriptions")
+    .temp_dir(tempdir.clone())
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<ord::subcommand::wallet::inscriptions::Output>>();
+
+  assert_eq!(inscriptions[0].inscription, inscription);
+  assert_eq!(inscriptions[0].label, Some("cat".into()));
+
+  let transactions = CommandBuilder::new("wallet transactions")
+    .temp_dir(tempdir.clone())
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<ord::subcommand::wallet::transactions::Output>>();
+
+  assert_eq!(
+    transactions
+      .iter()
+      .find(|transaction| transaction.transaction == reveal)
+      .unwrap()
+      .label,
+    Some("reveal".into()),
+  );
+
+  let exported = CommandBuilder::new("wallet label")
+    .temp_dir(tempdir.clone())
+    .core(&core)
+    .ord(&ord)
+    .stdout_regex(".*")
+    .run_and_extract_stdout();
+
+  assert!(exported.contains(&format!(r#""ref":"{location}","label":"{{\"first_sat\":"#)));
+  assert!(exported.contains(r#"\"label\":\"cat\"}","spendable":false}"#));
+  assert!(exported.contains(
+    r#"{"type":"addr","ref":"bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4","label":"friend"}"#
+  ));
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  CommandBuilder::new("wallet label import labels.jsonl")
+    .temp_dir(tempdir.clone())
+    .write("labels.jsonl", &exported)
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<ord::subcommand::wallet::label::Output>();
+
+  let reexported = CommandBuilder::new("wallet label")
+    .temp_dir(tempdir.clone())
+    .core(&core)
+    .ord(&ord)
+    .stdout_regex(".*")
+    .run_and_extract_stdout();
+
+  assert_eq!(reexported, exported);
+}
+
+#[test]
+fn import_rejects_invalid_labels() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn(&core);
+
+  create_wallet(&core, &ord);
+
+  CommandBuilder::new("wallet label import labels.jsonl")
+    .write(
+      "labels.jsonl",
+      "{\"type\":\"tx\",\"ref\":\"foo\",\"label\":\"bar\"}\n",
+    )
+    .core(&core)
+    .ord(&ord)
+    .expected_stderr("error: invalid label on line 1\n\nbecause:\n- invalid tx reference `foo`\n")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
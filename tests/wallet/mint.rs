@@ -576,3 +576,155 @@ fn minting_is_allowed_when_mint_begins_next_block() {
     }
   );
 }
+
+fn etch_mintable(core: &mockcore::Handle, ord: &TestServer, cap: u128) {
+  core.mine_blocks(1);
+
+  create_wallet(core, ord);
+
+  batch(
+    core,
+    ord,
+    batch::File {
+      etching: Some(batch::Etching {
+        divisibility: 0,
+        rune: SpacedRune {
+          rune: Rune(RUNE),
+          spacers: 0,
+        },
+        premine: "0".parse().unwrap(),
+        symbol: '¢',
+        supply: (cap * 100).to_string().parse().unwrap(),
+        terms: Some(batch::Terms {
+          cap,
+          offset: None,
+          amount: "100".parse().unwrap(),
+          height: None,
+        }),
+        turbo: false,
+      }),
+      inscriptions: vec![batch::Entry {
+        file: Some("inscription.jpeg".into()),
+        ..default()
+      }],
+      ..default()
+    },
+  );
+}
+
+#[test]
+fn minting_rune_with_count_stops_at_cap() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-runes", "--regtest"], &[]);
+
+  etch_mintable(&core, &ord, 2);
+
+  let output = CommandBuilder::new(format!(
+    "--chain regtest --index-runes wallet mint --fee-rate 1 --rune {} --count 3",
+    Rune(RUNE)
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<mint::Output>();
+
+  assert_eq!(output.mints.len(), 2);
+  assert_eq!(output.mint, output.mints[1]);
+  assert_eq!(output.pile.amount, 200);
+
+  let mempool = core.mempool();
+
+  assert_eq!(mempool.len(), 2);
+  assert_eq!(
+    mempool[1].input[0].previous_output,
+    OutPoint {
+      txid: output.mints[0],
+      vout: 2,
+    }
+  );
+
+  core.mine_blocks(1);
+
+  let balances = CommandBuilder::new("--regtest --index-runes balances")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<ord::subcommand::balances::Output>();
+
+  assert_eq!(
+    balances.runes[&SpacedRune::new(Rune(RUNE), 0)]
+      .keys()
+      .cloned()
+      .collect::<Vec<OutPoint>>(),
+    output
+      .mints
+      .iter()
+      .map(|txid| OutPoint {
+        txid: *txid,
+        vout: 1,
+      })
+      .collect::<std::collections::BTreeSet<OutPoint>>()
+      .into_iter()
+      .collect::<Vec<OutPoint>>(),
+  );
+
+  CommandBuilder::new(format!(
+    "--chain regtest --index-runes wallet mint --fee-rate 1 --rune {}",
+    Rune(RUNE)
+  ))
+  .core(&core)
+  .ord(&ord)
+  .expected_exit_code(1)
+  .expected_stderr("error: rune AAAAAAAAAAAAA limited to 2 mints\n")
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn minting_rune_with_count_can_consolidate() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-runes", "--regtest"], &[]);
+
+  etch_mintable(&core, &ord, 10);
+
+  let destination = "bcrt1pyrmadgg78e38ewfv0an8c6eppk2fttv5vnuvz04yza60qau5va0saknu8k";
+
+  let output = CommandBuilder::new(format!(
+    "--chain regtest --index-runes wallet mint --fee-rate 1 --rune {} --count 3 --consolidate --destination {destination}",
+    Rune(RUNE)
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<mint::Output>();
+
+  assert_eq!(output.mints.len(), 3);
+
+  core.mine_blocks(1);
+
+  let balances = CommandBuilder::new("--regtest --index-runes balances")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<ord::subcommand::balances::Output>();
+
+  assert_eq!(
+    balances.runes[&SpacedRune::new(Rune(RUNE), 0)]
+      .iter()
+      .map(|(outpoint, pile)| (*outpoint, pile.amount))
+      .collect::<Vec<(OutPoint, u128)>>(),
+    [(
+      OutPoint {
+        txid: output.mint,
+        vout: 1,
+      },
+      300,
+    )],
+  );
+
+  assert_eq!(
+    core.tx_by_id(output.mint).output[1].script_pubkey,
+    destination
+      .parse::<Address<NetworkUnchecked>>()
+      .unwrap()
+      .assume_checked()
+      .script_pubkey(),
+  );
+}
@@ -0,0 +1,166 @@
+use {
+  super::*,
+  ord::subcommand::wallet::offer::{accept, create},
+};
+
+#[test]
+fn inscription_offer_can_be_accepted() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  let (inscription, _) = inscribe(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let offer = CommandBuilder::new(format!(
+    "wallet offer create --inscription {inscription} --amount 1btc"
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<create::Output>();
+
+  assert_eq!(offer.amount, COIN_VALUE);
+  assert_eq!(offer.outgoing, Outgoing::InscriptionId(inscription));
+
+  let output = CommandBuilder::new(format!(
+    "wallet offer accept --inscription {inscription} --amount 1btc --fee-rate 1 --psbt offer.psbt"
+  ))
+  .write("offer.psbt", &offer.psbt)
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<accept::Output>();
+
+  let transaction = &core.mempool()[0];
+
+  assert_eq!(transaction.compute_txid(), output.txid);
+  assert_eq!(transaction.input[1].previous_output, offer.output);
+  assert_eq!(transaction.output[1].value, Amount::from_sat(COIN_VALUE));
+  assert_eq!(transaction.input[1].witness.nth(0).unwrap()[64], 0x83);
+
+  core.mine_blocks(1);
+
+  assert_eq!(
+    ord
+      .json_request(format!("/inscription/{inscription}"))
+      .json::<api::Inscription>()
+      .unwrap()
+      .satpoint
+      .outpoint,
+    OutPoint {
+      txid: output.txid,
+      vout: 0,
+    },
+  );
+}
+
+#[test]
+fn rune_offer_can_be_accepted() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-runes", "--regtest"], &[]);
+
+  create_wallet(&core, &ord);
+
+  etch(&core, &ord, Rune(RUNE));
+
+  let offer = CommandBuilder::new(format!(
+    "--regtest --index-runes wallet offer create --rune 1000:{} --amount 1btc",
+    Rune(RUNE),
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<create::Output>();
+
+  let output = CommandBuilder::new(format!(
+    "--regtest --index-runes wallet offer accept --rune 1000:{} --amount 1btc --fee-rate 1 --psbt offer.psbt",
+    Rune(RUNE),
+  ))
+  .write("offer.psbt", &offer.psbt)
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<accept::Output>();
+
+  core.mine_blocks(1);
+
+  let balances = CommandBuilder::new("--regtest --index-runes balances")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<ord::subcommand::balances::Output>();
+
+  assert_eq!(
+    balances.runes[&SpacedRune::new(Rune(RUNE), 0)]
+      .keys()
+      .cloned()
+      .collect::<Vec<OutPoint>>(),
+    [OutPoint {
+      txid: output.txid,
+      vout: 0,
+    }],
+  );
+}
+
+#[test]
+fn offer_for_different_inscription_is_rejected() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  let (inscription, _) = inscribe(&core, &ord);
+
+  let (other, _) = inscribe(&core, &ord);
+
+  let offer = CommandBuilder::new(format!(
+    "wallet offer create --inscription {inscription} --amount 1btc"
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<create::Output>();
+
+  CommandBuilder::new(format!(
+    "wallet offer accept --inscription {other} --amount 1btc --fee-rate 1 --psbt offer.psbt"
+  ))
+  .write("offer.psbt", &offer.psbt)
+  .core(&core)
+  .ord(&ord)
+  .expected_exit_code(1)
+  .expected_stderr(format!(
+    "error: offered output {} does not contain inscription {other}\n",
+    offer.output,
+  ))
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn offer_with_different_amount_is_rejected() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  let (inscription, _) = inscribe(&core, &ord);
+
+  let offer = CommandBuilder::new(format!(
+    "wallet offer create --inscription {inscription} --amount 1btc"
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<create::Output>();
+
+  CommandBuilder::new(format!(
+    "wallet offer accept --inscription {inscription} --amount 1000sat --fee-rate 1 --psbt offer.psbt"
+  ))
+  .write("offer.psbt", &offer.psbt)
+  .core(&core)
+  .ord(&ord)
+  .expected_exit_code(1)
+  .expected_stderr(
+    "error: offer amount of 1 BTC does not match `--amount` of 0.00001000 BTC\n",
+  )
+  .run_and_extract_stdout();
+}
@@ -84,7 +84,8 @@ fn wallet_pending() {
     .temp_dir(tempdir)
     .core(&core)
     .ord(&ord)
-    .run_and_deserialize_output::<Vec<ord::subcommand::wallet::pending::PendingOutput>>();
+    .run_and_deserialize_output::<ord::subcommand::wallet::pending::PendingListOutput>();
 
-  assert_eq!(output.first().unwrap().rune.rune, Rune(RUNE));
+  assert!(output.batches.is_empty());
+  assert_eq!(output.etchings.first().unwrap().rune.rune, Rune(RUNE));
 }
@@ -194,3 +194,144 @@ fn passphrase_conflicts_with_descriptor() {
   .expected_stderr("error: descriptor does not take a passphrase\n")
   .run_and_extract_stdout();
 }
+
+#[test]
+fn restore_watch_only_from_public_descriptors() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn(&core);
+
+  CommandBuilder::new("wallet restore --from descriptor --watch-only")
+    .stdin(r#"{"wallet_name":"foo","descriptors":[{"desc":"tr(xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8/0/*)","timestamp":0,"active":true,"internal":false,"range":[0,1000],"next":0},{"desc":"wpkh(xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8/1/*)","timestamp":0,"active":true,"internal":true,"range":[0,1000],"next":0}]}"#.into())
+    .core(&core)
+    .ord(&ord)
+    .run_and_extract_stdout();
+
+  assert_eq!(core.descriptors().len(), 2);
+
+  core.mine_blocks(1);
+
+  assert_eq!(
+    CommandBuilder::new("wallet balance")
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<Balance>()
+      .cardinal,
+    50 * COIN_VALUE,
+  );
+
+  CommandBuilder::new("wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc")
+    .core(&core)
+    .ord(&ord)
+    .expected_exit_code(1)
+    .expected_stderr(
+      "error: wallet is watch-only, use `--unsigned-psbt` to write unsigned PSBTs instead\n",
+    )
+    .run_and_extract_stdout();
+
+  let tempdir = TempDir::new().unwrap();
+
+  let psbt = tempdir.path().join("send.psbt");
+
+  CommandBuilder::new(format!(
+    "wallet send --fee-rate 1 --unsigned-psbt {} bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+    psbt.display(),
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  assert!(psbt.exists());
+  assert!(core.mempool().is_empty());
+
+  let txid = core.mine_blocks(1)[0].txdata[0].compute_txid();
+
+  let send = CommandBuilder::new(format!(
+    "wallet send --fee-rate 1 --unsigned-psbt {} bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {txid}:0:0",
+    tempdir.path().join("satpoint.psbt").display(),
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  // fee is estimated with a `wpkh` witness, the heaviest in the wallet, and
+  // not a 64-byte Schnorr signature, which would give a fee of 142
+  assert_eq!(send.fee, 153);
+
+  CommandBuilder::new(format!("wallet sign {}", psbt.display()))
+    .core(&core)
+    .ord(&ord)
+    .expected_exit_code(1)
+    .expected_stderr("error: wallet is watch-only and cannot sign PSBTs\n")
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn restore_watch_only_rejects_private_descriptors() {
+  let core = mockcore::spawn();
+
+  CommandBuilder::new("wallet restore --from descriptor --watch-only")
+    .stdin(r#"{"wallet_name":"foo","descriptors":[{"desc":"tr([c0b9536d/86'/1'/0']tprv8fXhtVjj3vb7kgxKuiWXzcUsur44gbLbbtwxL4HKmpzkBNoMrYqbQhMe7MWhrZjLFc9RBpTRYZZkrS8HH1Q3SmD5DkfpjKqtd97q1JWfqzr/0/*)#dweuu0ww","timestamp":1706047839,"active":true,"internal":false,"range":[0,1000],"next":1}]}"#.into())
+    .core(&core)
+    .expected_exit_code(1)
+    .stderr_regex("error: watch-only wallet descriptor `tr\\(.*\\)#dweuu0ww` is not a public descriptor\n.*")
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn restore_multisig_from_descriptors() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn(&core);
+
+  CommandBuilder::new("wallet restore --from descriptor")
+    .stdin(r#"{"wallet_name":"foo","descriptors":[{"desc":"wsh(sortedmulti(2,tprv8ZgxMBicQKsPdDdJFAqvG3mt4VqsVV125X4vsor5NxK366upt6qvovLQqaCi5SJiCE1aLkt3HtxsnTpzeGu27kPC5RUCr4h3oPBPYnAvhdE/0/*,tpubD6NzVbkrYhZ4Y3qD9tjwWFRv4AdyPdscx4wKXiywhgTXUeytmGtsEMiSJaXs9kqyYdPaKQv9tir5J2cDg2Fm3vaudETvLADYLLY4Vb7kMU4/0/*,tpubD6NzVbkrYhZ4XAhKE5b3VVtF4kipaPAEwhcF7e54zMkPoGh5C5rZFnpV4PMfB8gHhgqEK6hQZynZWbQWLiR2hhMohdfoBSzxG6NfD8F58rE/0/*))","timestamp":0,"active":true,"internal":false,"range":[0,1000],"next":0},{"desc":"tr(tpubD6NzVbkrYhZ4Wgf68pWWfTRzdXMoepBvepfiAKtNoE7RvbAbWVfWzQxH1jbkfNk3iJ9zR65Yw6u3B2QZzpkMSTN4y8Lfm1t44HbpZX7efhZ/1/*,multi_a(2,tprv8ZgxMBicQKsPdDdJFAqvG3mt4VqsVV125X4vsor5NxK366upt6qvovLQqaCi5SJiCE1aLkt3HtxsnTpzeGu27kPC5RUCr4h3oPBPYnAvhdE/2/*,tpubD6NzVbkrYhZ4Y3qD9tjwWFRv4AdyPdscx4wKXiywhgTXUeytmGtsEMiSJaXs9kqyYdPaKQv9tir5J2cDg2Fm3vaudETvLADYLLY4Vb7kMU4/1/*))","timestamp":0,"active":true,"internal":true,"range":[0,1000],"next":0}]}"#.into())
+    .core(&core)
+    .ord(&ord)
+    .run_and_extract_stdout();
+
+  assert_eq!(core.descriptors().len(), 2);
+
+  core.mine_blocks(1);
+
+  assert_eq!(
+    CommandBuilder::new("wallet balance")
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<Balance>()
+      .cardinal,
+    50 * COIN_VALUE,
+  );
+
+  CommandBuilder::new("wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc")
+    .core(&core)
+    .ord(&ord)
+    .expected_exit_code(1)
+    .expected_stderr(
+      "error: wallet cannot fully sign transaction, use `--unsigned-psbt` to write unsigned PSBTs and `ord wallet sign` to add signatures from each co-signer\n",
+    )
+    .run_and_extract_stdout();
+
+  assert!(core.mempool().is_empty());
+}
+
+#[test]
+fn restore_watch_only_multisig_from_public_descriptors() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn(&core);
+
+  CommandBuilder::new("wallet restore --from descriptor --watch-only")
+    .stdin(r#"{"wallet_name":"foo","descriptors":[{"desc":"wsh(sortedmulti(2,tpubD6NzVbkrYhZ4Wgf68pWWfTRzdXMoepBvepfiAKtNoE7RvbAbWVfWzQxH1jbkfNk3iJ9zR65Yw6u3B2QZzpkMSTN4y8Lfm1t44HbpZX7efhZ/0/*,tpubD6NzVbkrYhZ4Y3qD9tjwWFRv4AdyPdscx4wKXiywhgTXUeytmGtsEMiSJaXs9kqyYdPaKQv9tir5J2cDg2Fm3vaudETvLADYLLY4Vb7kMU4/0/*,tpubD6NzVbkrYhZ4XAhKE5b3VVtF4kipaPAEwhcF7e54zMkPoGh5C5rZFnpV4PMfB8gHhgqEK6hQZynZWbQWLiR2hhMohdfoBSzxG6NfD8F58rE/0/*))","timestamp":0,"active":true,"internal":false,"range":[0,1000],"next":0}]}"#.into())
+    .core(&core)
+    .ord(&ord)
+    .run_and_extract_stdout();
+
+  core.mine_blocks(1);
+
+  assert_eq!(
+    CommandBuilder::new("wallet balance")
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<Balance>()
+      .cardinal,
+    50 * COIN_VALUE,
+  );
+}
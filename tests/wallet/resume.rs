@@ -276,3 +276,125 @@ fn commitment_output_is_locked() {
     vout: 0
   }));
 }
+
+#[test]
+fn wallet_resume_inscription_batch() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  // accept the commit transaction, but fail to broadcast the reveal
+  core.state().broadcast_limit = Some(1);
+
+  CommandBuilder::new("wallet inscribe --fee-rate 1 --file foo.txt")
+    .temp_dir(tempdir.clone())
+    .write("foo.txt", "FOO")
+    .core(&core)
+    .ord(&ord)
+    .stderr_regex("error: Failed to send reveal transaction: .*Run `ord wallet resume` to retry.*")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  core.state().broadcast_limit = None;
+
+  assert_eq!(core.mempool().len(), 1);
+
+  let commit = core.mempool()[0].compute_txid();
+
+  let pending = CommandBuilder::new("wallet pending")
+    .temp_dir(tempdir.clone())
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<ord::subcommand::wallet::pending::PendingListOutput>();
+
+  assert!(pending.etchings.is_empty());
+  assert_eq!(pending.batches.len(), 1);
+  assert_eq!(pending.batches[0].commit, commit);
+
+  let output = CommandBuilder::new("wallet resume")
+    .temp_dir(tempdir.clone())
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<ord::subcommand::wallet::resume::ResumeOutput>();
+
+  assert!(output.etchings.is_empty());
+  assert_eq!(output.batches.len(), 1);
+  assert_eq!(output.batches[0].commit, commit);
+  assert!(output.batches[0].reveal_broadcast);
+
+  assert_eq!(core.mempool().len(), 2);
+  assert_eq!(core.mempool()[1].compute_txid(), output.batches[0].reveal);
+
+  core.mine_blocks(1);
+
+  ord.assert_response_regex(
+    format!("/inscription/{}", output.batches[0].inscriptions[0].id),
+    ".*<dt>content length</dt>\\s*<dd>3 bytes</dd>.*",
+  );
+
+  let pending = CommandBuilder::new("wallet pending")
+    .temp_dir(tempdir)
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<ord::subcommand::wallet::pending::PendingListOutput>();
+
+  assert!(pending.batches.is_empty());
+  assert!(pending.etchings.is_empty());
+}
+
+#[test]
+fn wallet_resume_keeps_inscription_batch_if_commit_fails_to_broadcast() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  core.state().broadcast_limit = Some(1);
+
+  CommandBuilder::new("wallet inscribe --fee-rate 1 --file foo.txt")
+    .temp_dir(tempdir.clone())
+    .write("foo.txt", "FOO")
+    .core(&core)
+    .ord(&ord)
+    .stderr_regex("error: Failed to send reveal transaction: .*Run `ord wallet resume` to retry.*")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  // drop the commit transaction from the mempool, and fail to broadcast it again
+  let commit = core.mempool()[0].compute_txid();
+  core.state().mempool.clear();
+  core.state().broadcast_limit = Some(0);
+
+  CommandBuilder::new("wallet resume")
+    .temp_dir(tempdir.clone())
+    .core(&core)
+    .ord(&ord)
+    .stderr_regex(format!(
+      "error: failed to send commit transaction {commit}.*broadcast limit reached.*"
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  core.state().broadcast_limit = None;
+
+  let output = CommandBuilder::new("wallet resume")
+    .temp_dir(tempdir)
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<ord::subcommand::wallet::resume::ResumeOutput>();
+
+  assert_eq!(output.batches.len(), 1);
+  assert_eq!(output.batches[0].commit, commit);
+  assert!(output.batches[0].reveal_broadcast);
+
+  assert_eq!(core.mempool().len(), 2);
+}
@@ -0,0 +1,143 @@
+use {super::*, ord::wallet::batch::CommitOutput};
+
+fn commit_only(core: &mockcore::Handle, ord: &TestServer, tempdir: &Arc<TempDir>) -> CommitOutput {
+  CommandBuilder::new("wallet inscribe --fee-rate 1 --file foo.txt --commit-only")
+    .temp_dir(tempdir.clone())
+    .write("foo.txt", "FOO")
+    .core(core)
+    .ord(ord)
+    .run_and_deserialize_output::<CommitOutput>()
+}
+
+fn fund(core: &mockcore::Handle, commit: &CommitOutput, value: u64) -> Txid {
+  let txid = core.broadcast_tx(TransactionTemplate {
+    inputs: &[(1, 0, 0, Default::default())],
+    output_values: &[value],
+    recipient: Some(
+      commit
+        .address
+        .clone()
+        .require_network(Network::Bitcoin)
+        .unwrap(),
+    ),
+    ..default()
+  });
+
+  core.mine_blocks(1);
+
+  txid
+}
+
+#[test]
+fn externally_funded_commit_can_be_revealed() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  let commit = commit_only(&core, &ord, &tempdir);
+
+  assert!(core.mempool().is_empty());
+  assert!(commit.amount > 10_000);
+
+  let txid = fund(&core, &commit, commit.amount);
+
+  let reveal = CommandBuilder::new(format!("wallet reveal --txid {txid}"))
+    .temp_dir(tempdir.clone())
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Batch>();
+
+  assert_eq!(reveal.commit, txid);
+  assert!(reveal.reveal_broadcast);
+  assert_eq!(reveal.total_fees, commit.amount - 10_000);
+
+  let reveal_tx = &core.mempool()[0];
+  assert_eq!(reveal_tx.compute_txid(), reveal.reveal);
+  assert_eq!(reveal_tx.output.len(), 1);
+
+  core.mine_blocks(1);
+
+  ord.assert_response_regex(
+    format!("/inscription/{}", reveal.inscriptions[0].id),
+    ".*<h1>Inscription 0</h1>.*",
+  );
+
+  CommandBuilder::new(format!("wallet reveal --txid {txid}"))
+    .temp_dir(tempdir.clone())
+    .core(&core)
+    .ord(&ord)
+    .expected_exit_code(1)
+    .expected_stderr(format!(
+      "error: transaction {txid} does not pay to any pending commit address\n"
+    ))
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn excess_commit_amount_is_returned_as_change() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  let commit = commit_only(&core, &ord, &tempdir);
+
+  let txid = fund(&core, &commit, commit.amount + 100_000);
+
+  let reveal = CommandBuilder::new(format!("wallet reveal --txid {txid}"))
+    .temp_dir(tempdir.clone())
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Batch>();
+
+  let reveal_tx = &core.mempool()[0];
+  assert_eq!(reveal_tx.output.len(), 2);
+  assert_eq!(reveal_tx.output[0].value.to_sat(), 10_000);
+  assert!(reveal.total_fees < commit.amount);
+  assert_eq!(
+    reveal_tx.output[1].value.to_sat(),
+    commit.amount + 100_000 - 10_000 - reveal.total_fees,
+  );
+}
+
+#[test]
+fn underfunded_commit_is_not_revealed() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  let commit = commit_only(&core, &ord, &tempdir);
+
+  let txid = fund(&core, &commit, commit.amount - 1);
+
+  CommandBuilder::new(format!("wallet reveal --txid {txid}"))
+    .temp_dir(tempdir.clone())
+    .core(&core)
+    .ord(&ord)
+    .expected_exit_code(1)
+    .expected_stderr(format!(
+      "error: commit output {txid}:0 contains {} but reveal transaction requires {}\n",
+      Amount::from_sat(commit.amount - 1),
+      Amount::from_sat(commit.amount),
+    ))
+    .run_and_extract_stdout();
+
+  assert!(core.mempool().is_empty());
+}
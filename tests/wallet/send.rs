@@ -633,6 +633,81 @@ fn send_btc_does_not_send_locked_utxos() {
     .run_and_extract_stdout();
 }
 
+#[test]
+fn send_btc_does_not_send_protected_rare_sats() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  let coinbase_tx = &core.mine_blocks(1)[0].txdata[0];
+  let outpoint = OutPoint::new(coinbase_tx.compute_txid(), 0);
+
+  CommandBuilder::new(
+    "--index-sats wallet --protect-rarity uncommon send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+  )
+  .core(&core)
+  .ord(&ord)
+  .expected_exit_code(1)
+  .expected_stderr("error: not enough cardinal utxos\n")
+  .run_and_extract_stdout();
+
+  assert!(core.get_locked().contains(&outpoint));
+
+  CommandBuilder::new(
+    "--index-sats wallet --protect-rarity uncommon --spend-protected-sats send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+  )
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  assert!(!core.get_locked().contains(&outpoint));
+  assert_eq!(core.mempool()[0].input[0].previous_output, outpoint);
+}
+
+#[test]
+fn protect_rarity_requires_rarer_sats() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new(
+    "--index-sats wallet --protect-rarity rare send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+  )
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  assert!(core.get_locked().is_empty());
+}
+
+#[test]
+fn protect_rarity_requires_sat_index() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new(
+    "wallet --protect-rarity uncommon send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+  )
+  .core(&core)
+  .ord(&ord)
+  .expected_exit_code(1)
+  .expected_stderr("error: `--protect-rarity` requires index created with `--index-sats` flag\n")
+  .run_and_extract_stdout();
+
+  assert!(core.mempool().is_empty());
+}
+
 #[test]
 fn send_dry_run() {
   let core = mockcore::spawn();
@@ -1171,3 +1246,147 @@ fn error_messages_use_spaced_runes() {
     .expected_stderr("error: rune `FOO` has not been etched\n")
     .run_and_extract_stdout();
 }
+
+#[test]
+fn send_sat_range() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let output = CommandBuilder::new(
+    "--index-sats wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 5000001000-5000002000",
+  )
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  assert_eq!(
+    output.outgoing,
+    Outgoing::SatRange {
+      start: Sat(5_000_001_000),
+      end: Sat(5_000_002_000),
+    }
+  );
+
+  core.mine_blocks(1);
+
+  let sat_ranges = |vout: u32| {
+    ord
+      .json_request(format!("/output/{}:{vout}", output.txid))
+      .json::<api::Output>()
+      .unwrap()
+      .sat_ranges
+      .unwrap()
+  };
+
+  assert_eq!(sat_ranges(0), [(5_000_000_000, 5_000_001_000)]);
+  assert_eq!(sat_ranges(1), [(5_000_001_000, 5_000_002_000)]);
+  assert_eq!(sat_ranges(2)[0].0, 5_000_002_000);
+
+  let tx = &core.tx_by_id(output.txid);
+
+  assert_eq!(
+    tx.output[1].script_pubkey,
+    "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"
+      .parse::<Address<NetworkUnchecked>>()
+      .unwrap()
+      .assume_checked()
+      .script_pubkey()
+  );
+}
+
+#[test]
+fn send_sat_range_pads_dust_change() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(2);
+
+  let output = CommandBuilder::new(
+    "--index-sats wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 5000000100-5000000600",
+  )
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  core.mine_blocks(1);
+
+  let sat_ranges = |vout: u32| {
+    ord
+      .json_request(format!("/output/{}:{vout}", output.txid))
+      .json::<api::Output>()
+      .unwrap()
+      .sat_ranges
+      .unwrap()
+  };
+
+  assert_eq!(
+    sat_ranges(0).last().unwrap(),
+    &(5_000_000_000, 5_000_000_100)
+  );
+  assert_eq!(sat_ranges(0).len(), 2);
+  assert_eq!(sat_ranges(1), [(5_000_000_100, 5_000_000_600)]);
+}
+
+#[test]
+fn send_sat_range_containing_inscription_fails() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let (inscription, txid) = inscribe(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let sat = sats(&core, &ord)
+    .into_iter()
+    .find(|sat| sat.output.txid == txid)
+    .unwrap()
+    .sat;
+
+  CommandBuilder::new(format!(
+    "--index-sats wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {}-{}",
+    sat.n(),
+    sat.n() + 1000,
+  ))
+  .core(&core)
+  .ord(&ord)
+  .expected_stderr(format!(
+    "error: sat range {}-{} contains inscription {inscription}\n",
+    sat.n(),
+    sat.n() + 1000,
+  ))
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn send_sat_range_not_in_wallet_fails() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new(
+    "--index-sats wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 0-1000",
+  )
+  .core(&core)
+  .ord(&ord)
+  .expected_stderr("error: sat range 0-1000 not found in a single wallet output\n")
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
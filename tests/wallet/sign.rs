@@ -0,0 +1,388 @@
+use {
+  super::*,
+  base64::Engine,
+  bitcoin::{
+    absolute::LockTime,
+    psbt::{raw::ProprietaryKey, Psbt},
+    transaction::Version,
+    Amount, ScriptBuf, Transaction, TxIn, TxOut,
+  },
+  ord::{
+    subcommand::wallet::{broadcast, sign},
+    wallet::offline::PsbtContents,
+  },
+};
+
+#[test]
+fn unsigned_send_can_be_signed_and_broadcast() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let (inscription, _) = inscribe(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let tempdir = TempDir::new().unwrap();
+
+  let psbt = tempdir.path().join("send.psbt");
+
+  let send = CommandBuilder::new(format!(
+    "wallet send --fee-rate 1 --unsigned-psbt {} bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {inscription}",
+    psbt.display(),
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  assert!(core.mempool().is_empty());
+
+  assert_eq!(
+    fs::read_to_string(&psbt).unwrap(),
+    format!("{}\n", send.psbt),
+  );
+
+  let sign = CommandBuilder::new(format!("wallet sign {}", psbt.display()))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<sign::Output>();
+
+  assert!(sign.complete);
+  assert_eq!(sign.psbts.len(), 1);
+
+  let broadcast = CommandBuilder::new(format!("wallet broadcast {}", psbt.display()))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<broadcast::Output>();
+
+  assert_eq!(broadcast.txids, [send.txid]);
+  assert_eq!(core.mempool()[0].compute_txid(), send.txid);
+
+  core.mine_blocks(1);
+
+  ord.assert_response_regex(
+    format!("/inscription/{inscription}"),
+    format!(
+      ".*<dt>location</dt>
+  <dd><a class=monospace href=/satpoint/{}:0:0>.*",
+      send.txid
+    ),
+  );
+}
+
+#[test]
+fn unsigned_inscribe_can_be_signed_and_broadcast() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let tempdir = TempDir::new().unwrap();
+
+  let unsigned = tempdir.path().join("inscribe.psbt");
+  let signed = tempdir.path().join("signed.psbt");
+
+  let batch = CommandBuilder::new(format!(
+    "wallet inscribe --fee-rate 1 --file foo.txt --unsigned-psbt {}",
+    unsigned.display(),
+  ))
+  .write("foo.txt", "FOO")
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Batch>();
+
+  assert!(!batch.reveal_broadcast);
+  assert!(core.mempool().is_empty());
+
+  CommandBuilder::new(format!(
+    "wallet sign {} --output {}",
+    unsigned.display(),
+    signed.display(),
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<sign::Output>();
+
+  let broadcast = CommandBuilder::new(format!("wallet broadcast {}", signed.display()))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<broadcast::Output>();
+
+  assert_eq!(broadcast.txids, [batch.commit, batch.reveal]);
+
+  core.mine_blocks(1);
+
+  ord.assert_response_regex(
+    format!("/inscription/{}", batch.inscriptions[0].id),
+    ".*<h1>Inscription 0</h1>.*",
+  );
+}
+
+#[test]
+fn psbt_which_sends_unexpected_inscription_is_rejected() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let (inscription, reveal) = inscribe(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let psbt = Psbt::from_unsigned_tx(Transaction {
+    version: Version(2),
+    lock_time: LockTime::ZERO,
+    input: vec![TxIn {
+      previous_output: OutPoint {
+        txid: reveal,
+        vout: 0,
+      },
+      ..default()
+    }],
+    output: Vec::new(),
+  })
+  .unwrap();
+
+  let tempdir = TempDir::new().unwrap();
+
+  let path = tempdir.path().join("tx.psbt");
+
+  fs::write(
+    &path,
+    base64::engine::general_purpose::STANDARD.encode(psbt.serialize()),
+  )
+  .unwrap();
+
+  for subcommand in ["sign", "broadcast"] {
+    CommandBuilder::new(format!("wallet {subcommand} {}", path.display()))
+      .core(&core)
+      .ord(&ord)
+      .expected_stderr(format!(
+        "error: PSBT input {reveal}:0 contains inscription {inscription} which PSBT was not created to send\n"
+      ))
+      .expected_exit_code(1)
+      .run_and_extract_stdout();
+  }
+
+  assert!(core.mempool().is_empty());
+}
+
+#[test]
+fn psbt_which_sends_inscription_to_unexpected_output_is_rejected() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let (inscription, reveal) = inscribe(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let mut psbt = Psbt::from_unsigned_tx(Transaction {
+    version: Version(2),
+    lock_time: LockTime::ZERO,
+    input: vec![TxIn {
+      previous_output: OutPoint {
+        txid: reveal,
+        vout: 0,
+      },
+      ..default()
+    }],
+    output: vec![
+      TxOut {
+        value: Amount::ZERO,
+        script_pubkey: ScriptBuf::new(),
+      },
+      TxOut {
+        value: Amount::from_sat(10_000),
+        script_pubkey: ScriptBuf::new(),
+      },
+    ],
+  })
+  .unwrap();
+
+  psbt.proprietary.insert(
+    ProprietaryKey {
+      prefix: b"ord".to_vec(),
+      subtype: 0,
+      key: Vec::new(),
+    },
+    serde_json::to_vec(&PsbtContents {
+      inscriptions: [(inscription, Some(0))].into(),
+      ..default()
+    })
+    .unwrap(),
+  );
+
+  let tempdir = TempDir::new().unwrap();
+
+  let path = tempdir.path().join("tx.psbt");
+
+  fs::write(
+    &path,
+    base64::engine::general_purpose::STANDARD.encode(psbt.serialize()),
+  )
+  .unwrap();
+
+  for subcommand in ["sign", "broadcast"] {
+    CommandBuilder::new(format!("wallet {subcommand} {}", path.display()))
+      .core(&core)
+      .ord(&ord)
+      .expected_stderr(format!(
+        "error: PSBT sends inscription {inscription} to output 1 but was created to send it to output 0\n"
+      ))
+      .expected_exit_code(1)
+      .run_and_extract_stdout();
+  }
+
+  assert!(core.mempool().is_empty());
+}
+
+#[test]
+fn multisig_send_is_signed_by_each_co_signer() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  let descriptors = |key: usize| {
+    let mut keys = [
+      "tpubD6NzVbkrYhZ4Wgf68pWWfTRzdXMoepBvepfiAKtNoE7RvbAbWVfWzQxH1jbkfNk3iJ9zR65Yw6u3B2QZzpkMSTN4y8Lfm1t44HbpZX7efhZ",
+      "tpubD6NzVbkrYhZ4Y3qD9tjwWFRv4AdyPdscx4wKXiywhgTXUeytmGtsEMiSJaXs9kqyYdPaKQv9tir5J2cDg2Fm3vaudETvLADYLLY4Vb7kMU4",
+      "tpubD6NzVbkrYhZ4XAhKE5b3VVtF4kipaPAEwhcF7e54zMkPoGh5C5rZFnpV4PMfB8gHhgqEK6hQZynZWbQWLiR2hhMohdfoBSzxG6NfD8F58rE",
+    ];
+
+    keys[key] = [
+      "tprv8ZgxMBicQKsPdDdJFAqvG3mt4VqsVV125X4vsor5NxK366upt6qvovLQqaCi5SJiCE1aLkt3HtxsnTpzeGu27kPC5RUCr4h3oPBPYnAvhdE",
+      "tprv8ZgxMBicQKsPeaoRGF5M6qmoV983EJgiNmLYFCweHQf8eAj88t5H3s6a8T3zuKKKiaAYJxzYqeU7i4BHPH9AtQ9bxeiC1Z8BSuHogZuRMVr",
+    ][key];
+
+    format!(
+      r#"{{"wallet_name":"foo","descriptors":[{{"desc":"wsh(sortedmulti(2,{}/0/*,{}/0/*,{}/0/*))","timestamp":0,"active":true,"internal":false,"range":[0,1000],"next":0}},{{"desc":"wsh(sortedmulti(2,{}/1/*,{}/1/*,{}/1/*))","timestamp":0,"active":true,"internal":true,"range":[0,1000],"next":0}}]}}"#,
+      keys[0], keys[1], keys[2], keys[0], keys[1], keys[2],
+    )
+  };
+
+  for (name, key) in [("alice", 0), ("bob", 1)] {
+    CommandBuilder::new(format!("wallet --name {name} restore --from descriptor"))
+      .stdin(descriptors(key).into())
+      .core(&core)
+      .ord(&ord)
+      .run_and_extract_stdout();
+  }
+
+  core.mine_blocks(1);
+
+  let tempdir = TempDir::new().unwrap();
+
+  let psbt = tempdir.path().join("send.psbt");
+
+  let send = CommandBuilder::new(format!(
+    "wallet --name alice send --fee-rate 1 --unsigned-psbt {} bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+    psbt.display(),
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  let sign = CommandBuilder::new(format!("wallet --name alice sign {}", psbt.display()))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<sign::Output>();
+
+  assert!(!sign.complete);
+
+  CommandBuilder::new(format!("wallet --name alice broadcast {}", psbt.display()))
+    .core(&core)
+    .ord(&ord)
+    .expected_exit_code(1)
+    .expected_stderr(format!(
+      "error: PSBT 0 in `{}` is not fully signed\n",
+      psbt.display()
+    ))
+    .run_and_extract_stdout();
+
+  let sign = CommandBuilder::new(format!("wallet --name bob sign {}", psbt.display()))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<sign::Output>();
+
+  assert!(sign.complete);
+
+  let broadcast = CommandBuilder::new(format!("wallet --name alice broadcast {}", psbt.display()))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<broadcast::Output>();
+
+  assert_eq!(broadcast.txids, [send.txid]);
+  assert_eq!(core.mempool()[0].compute_txid(), send.txid);
+}
+
+#[test]
+fn unsigned_rune_send_can_be_signed_and_broadcast() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-runes", "--regtest"], &[]);
+
+  create_wallet(&core, &ord);
+
+  etch(&core, &ord, Rune(RUNE));
+
+  let tempdir = TempDir::new().unwrap();
+
+  let psbt = tempdir.path().join("send.psbt");
+
+  let send = CommandBuilder::new(format!(
+    "--regtest --index-runes wallet send --fee-rate 1 --unsigned-psbt {} bcrt1qs758ursh4q9z627kt3pp5yysm78ddny6txaqgw 777:{}",
+    psbt.display(),
+    Rune(RUNE),
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  CommandBuilder::new(format!(
+    "--regtest --index-runes wallet sign {}",
+    psbt.display()
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<sign::Output>();
+
+  let broadcast = CommandBuilder::new(format!(
+    "--regtest --index-runes wallet broadcast {}",
+    psbt.display()
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<broadcast::Output>();
+
+  assert_eq!(broadcast.txids, [send.txid]);
+
+  core.mine_blocks(1);
+
+  let balances = CommandBuilder::new("--regtest --index-runes balances")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<ord::subcommand::balances::Output>();
+
+  assert_eq!(
+    balances.runes[&SpacedRune::new(Rune(RUNE), 0)]
+      .values()
+      .map(|pile| pile.amount)
+      .collect::<std::collections::BTreeSet<u128>>(),
+    [223, 777].into(),
+  );
+}
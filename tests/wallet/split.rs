@@ -0,0 +1,229 @@
+use {super::*, ord::subcommand::wallet::split};
+
+const RECIPIENT: &str = "bcrt1qs758ursh4q9z627kt3pp5yysm78ddny6txaqgw";
+
+fn splitfile(outputs: &[u128]) -> String {
+  let mut splitfile = "outputs:\n".to_string();
+
+  for amount in outputs {
+    splitfile.push_str(&format!(
+      "- address: {RECIPIENT}\n  runes:\n    {}: {amount}\n",
+      Rune(RUNE)
+    ));
+  }
+
+  splitfile
+}
+
+fn recipient_balances(core: &mockcore::Handle, ord: &TestServer) -> Vec<u128> {
+  let balances = CommandBuilder::new("--regtest --index-runes balances")
+    .core(core)
+    .ord(ord)
+    .run_and_deserialize_output::<ord::subcommand::balances::Output>();
+
+  let recipient = RECIPIENT
+    .parse::<Address<NetworkUnchecked>>()
+    .unwrap()
+    .assume_checked()
+    .script_pubkey();
+
+  let mut amounts = Vec::new();
+
+  for (outpoint, pile) in &balances.runes[&SpacedRune::new(Rune(RUNE), 0)] {
+    let transaction = core.tx_by_id(outpoint.txid);
+
+    if transaction.output[usize::try_from(outpoint.vout).unwrap()].script_pubkey == recipient {
+      amounts.push(pile.amount);
+    }
+  }
+
+  amounts.sort();
+
+  amounts
+}
+
+#[test]
+fn split_sends_runes_to_many_outputs() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-runes", "--regtest"], &[]);
+
+  create_wallet(&core, &ord);
+
+  etch(&core, &ord, Rune(RUNE));
+
+  let output =
+    CommandBuilder::new("--regtest --index-runes wallet split --fee-rate 1 --splits splits.yaml")
+      .write("splits.yaml", splitfile(&[100, 200, 300]))
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<split::Output>();
+
+  assert_eq!(output.transactions.len(), 1);
+  assert_eq!(output.transactions[0].outputs, 3);
+  assert_eq!(output.fee, output.transactions[0].fee);
+
+  core.mine_blocks(1);
+
+  assert_eq!(recipient_balances(&core, &ord), [100, 200, 300]);
+
+  let balance = CommandBuilder::new("--regtest --index-runes wallet balance")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Balance>();
+
+  assert_eq!(
+    balance.runes.unwrap()[&SpacedRune::new(Rune(RUNE), 0)],
+    "400".parse().unwrap(),
+  );
+}
+
+#[test]
+fn split_chunks_outputs_across_transactions() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-runes", "--regtest"], &[]);
+
+  create_wallet(&core, &ord);
+
+  etch(&core, &ord, Rune(RUNE));
+
+  let amounts = (1..=40).collect::<Vec<u128>>();
+
+  let output =
+    CommandBuilder::new("--regtest --index-runes wallet split --fee-rate 1 --splits splits.yaml")
+      .write("splits.yaml", splitfile(&amounts))
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<split::Output>();
+
+  assert!(output.transactions.len() > 1);
+
+  assert_eq!(
+    output
+      .transactions
+      .iter()
+      .map(|transaction| transaction.outputs)
+      .sum::<usize>(),
+    40,
+  );
+
+  assert_eq!(
+    output
+      .transactions
+      .iter()
+      .map(|transaction| transaction.fee)
+      .sum::<u64>(),
+    output.fee,
+  );
+
+  let mempool = core.mempool();
+
+  assert_eq!(mempool.len(), output.transactions.len());
+
+  for (transaction, split) in mempool.iter().zip(&output.transactions) {
+    assert_eq!(transaction.compute_txid(), split.txid);
+    assert!(transaction.output[0].script_pubkey.len() <= 82);
+  }
+
+  core.mine_blocks(1);
+
+  assert_eq!(recipient_balances(&core, &ord), amounts);
+}
+
+#[test]
+fn split_dry_run_estimates_cost() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-runes", "--regtest"], &[]);
+
+  create_wallet(&core, &ord);
+
+  etch(&core, &ord, Rune(RUNE));
+
+  let output = CommandBuilder::new(
+    "--regtest --index-runes wallet split --dry-run --fee-rate 10 --splits splits.yaml",
+  )
+  .write("splits.yaml", splitfile(&(1..=40).collect::<Vec<u128>>()))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<split::Output>();
+
+  assert!(output.transactions.len() > 1);
+  assert!(output.fee > 0);
+  assert!(core.mempool().is_empty());
+}
+
+#[test]
+fn split_with_insufficient_balance_fails() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-runes", "--regtest"], &[]);
+
+  create_wallet(&core, &ord);
+
+  etch(&core, &ord, Rune(RUNE));
+
+  CommandBuilder::new("--regtest --index-runes wallet split --fee-rate 1 --splits splits.yaml")
+    .write("splits.yaml", splitfile(&[600, 600]))
+    .core(&core)
+    .ord(&ord)
+    .expected_exit_code(1)
+    .expected_stderr("error: insufficient `AAAAAAAAAAAAA` balance, only 1000\u{A0}¢ in wallet\n")
+    .run_and_extract_stdout();
+
+  assert!(core.mempool().is_empty());
+}
+
+#[test]
+fn split_runes_out_of_id_order() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-runes", "--regtest"], &[]);
+
+  create_wallet(&core, &ord);
+
+  // runes are ordered by name in split files, so the later-etched rune, with
+  // the greater id, comes first
+  etch(&core, &ord, Rune(RUNE + 1));
+  etch(&core, &ord, Rune(RUNE));
+
+  let output =
+    CommandBuilder::new("--regtest --index-runes wallet split --fee-rate 1 --splits splits.yaml")
+      .write(
+        "splits.yaml",
+        format!(
+          "outputs:\n- address: {RECIPIENT}\n  runes:\n    {}: 100\n    {}: 200\n- address: {RECIPIENT}\n  runes:\n    {}: 300\n",
+          Rune(RUNE),
+          Rune(RUNE + 1),
+          Rune(RUNE),
+        ),
+      )
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<split::Output>();
+
+  assert_eq!(output.transactions.len(), 1);
+  assert_eq!(output.transactions[0].outputs, 2);
+
+  core.mine_blocks(1);
+
+  assert_eq!(recipient_balances(&core, &ord), [100, 300]);
+
+  let balance = CommandBuilder::new("--regtest --index-runes wallet balance")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Balance>()
+    .runes
+    .unwrap();
+
+  assert_eq!(
+    balance[&SpacedRune::new(Rune(RUNE), 0)],
+    "600".parse().unwrap(),
+  );
+
+  assert_eq!(
+    balance[&SpacedRune::new(Rune(RUNE + 1), 0)],
+    "800".parse().unwrap(),
+  );
+}
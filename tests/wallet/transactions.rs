@@ -65,3 +65,186 @@ fn transactions_with_limit() {
 
   assert_eq!(output.len(), 1);
 }
+
+#[test]
+fn transactions_show_inscriptions_created_received_and_sent() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let (inscription, reveal) = inscribe(&core, &ord);
+
+  let send = CommandBuilder::new(format!(
+    "wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 {inscription}"
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<ord::subcommand::wallet::send::Output>()
+  .txid;
+
+  core.mine_blocks(1);
+
+  let output = CommandBuilder::new(format!("wallet transactions --inscription {inscription}"))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<Output>>();
+
+  assert_eq!(output.len(), 2);
+
+  let reveal = output
+    .iter()
+    .find(|entry| entry.transaction == reveal)
+    .unwrap();
+
+  assert_eq!(reveal.inscriptions_created, [inscription]);
+  assert!(reveal.inscriptions_sent.is_empty());
+
+  let send = output
+    .iter()
+    .find(|entry| entry.transaction == send)
+    .unwrap();
+
+  assert!(send.inscriptions_created.is_empty());
+  assert!(send.inscriptions_received.is_empty());
+  assert_eq!(send.inscriptions_sent, [inscription]);
+  assert!(send.unknown_outputs.is_empty());
+  assert_eq!(
+    send.counterparties,
+    ["bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"
+      .parse::<Address<NetworkUnchecked>>()
+      .unwrap()]
+    .into_iter()
+    .collect(),
+  );
+  assert!(send.fee.unwrap() > 0);
+
+  let output = CommandBuilder::new("wallet transactions --after 2000-01-01T00:00:00Z")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<Output>>();
+
+  assert!(output.is_empty());
+}
+
+#[test]
+fn transactions_show_runes_etched_and_sent() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+  let ord = TestServer::spawn_with_server_args(&core, &["--regtest", "--index-runes"], &[]);
+
+  create_wallet(&core, &ord);
+
+  let etched = etch(&core, &ord, Rune(RUNE));
+
+  let send = CommandBuilder::new(
+    "--chain regtest --index-runes wallet send --fee-rate 1 bcrt1qs758ursh4q9z627kt3pp5yysm78ddny6txaqgw 100:AAAAAAAAAAAAA",
+  )
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<ord::subcommand::wallet::send::Output>()
+  .txid;
+
+  core.mine_blocks(1);
+
+  let output =
+    CommandBuilder::new("--regtest --index-runes wallet transactions --rune AAAAAAAAAAAAA")
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<Vec<Output>>();
+
+  assert_eq!(output.len(), 2);
+
+  let rune = SpacedRune {
+    rune: Rune(RUNE),
+    spacers: 0,
+  };
+
+  let reveal = output
+    .iter()
+    .find(|entry| entry.transaction == etched.output.reveal)
+    .unwrap();
+
+  assert_eq!(reveal.rune_etched, Some(rune));
+
+  let send = output
+    .iter()
+    .find(|entry| entry.transaction == send)
+    .unwrap();
+
+  assert_eq!(
+    send.runes_sent,
+    [(rune, "100".parse().unwrap())].into_iter().collect(),
+  );
+  assert_eq!(
+    send.runes_received,
+    [(rune, "900".parse().unwrap())].into_iter().collect(),
+  );
+}
+
+#[test]
+fn transactions_show_spent_outputs_as_unknown() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+  let ord = TestServer::spawn_with_server_args(&core, &["--regtest", "--index-runes"], &[]);
+
+  create_wallet(&core, &ord);
+
+  etch(&core, &ord, Rune(RUNE));
+
+  let send = |amount| {
+    let txid = CommandBuilder::new(format!(
+      "--chain regtest --index-runes wallet send --fee-rate 1 bcrt1qs758ursh4q9z627kt3pp5yysm78ddny6txaqgw {amount}:AAAAAAAAAAAAA"
+    ))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<ord::subcommand::wallet::send::Output>()
+    .txid;
+
+    core.mine_blocks(1);
+
+    txid
+  };
+
+  let first = send(100);
+  let second = send(200);
+
+  let output = CommandBuilder::new("--regtest --index-runes wallet transactions")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<Output>>();
+
+  let rune = SpacedRune {
+    rune: Rune(RUNE),
+    spacers: 0,
+  };
+
+  let first = output
+    .iter()
+    .find(|entry| entry.transaction == first)
+    .unwrap();
+
+  assert_eq!(
+    first.runes_sent,
+    [(rune, "100".parse().unwrap())].into_iter().collect(),
+  );
+  assert!(first.runes_received.is_empty());
+  assert_eq!(
+    first.unknown_outputs,
+    [OutPoint {
+      txid: first.transaction,
+      vout: 1
+    }],
+  );
+
+  let second = output
+    .iter()
+    .find(|entry| entry.transaction == second)
+    .unwrap();
+
+  assert_eq!(
+    second.runes_received,
+    [(rune, "700".parse().unwrap())].into_iter().collect(),
+  );
+  assert!(second.unknown_outputs.is_empty());
+}
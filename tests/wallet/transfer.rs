@@ -0,0 +1,187 @@
+use {super::*, ord::subcommand::wallet::transfer, std::collections::BTreeSet};
+
+const ALICE: &str = "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4";
+const BOB: &str = "bc1qcqgs2pps4u4yedfyl5pysdjjncs8et5utseepv";
+
+fn inscriptions(core: &mockcore::Handle, ord: &TestServer, n: usize) -> Vec<InscriptionId> {
+  create_wallet(core, ord);
+
+  core.mine_blocks(1);
+
+  (0..n)
+    .map(|_| {
+      let (inscription, _) = inscribe(core, ord);
+      core.mine_blocks(1);
+      inscription
+    })
+    .collect()
+}
+
+fn location(ord: &TestServer, inscription: InscriptionId) -> api::Inscription {
+  ord
+    .json_request(format!("/inscription/{inscription}"))
+    .json::<api::Inscription>()
+    .unwrap()
+}
+
+#[test]
+fn transfer_sends_inscriptions_in_one_transaction() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  let inscriptions = inscriptions(&core, &ord, 3);
+
+  let output = CommandBuilder::new(format!(
+    "wallet transfer --fee-rate 1 --destination {ALICE} --transfers transfers.yaml {} {}",
+    inscriptions[0], inscriptions[1],
+  ))
+  .write(
+    "transfers.yaml",
+    format!(
+      "transfers:\n- outgoing: {}\n  destination: {BOB}\n",
+      inscriptions[2]
+    ),
+  )
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<transfer::Output>();
+
+  assert_eq!(output.transactions.len(), 1);
+
+  let transaction = &output.transactions[0];
+
+  assert_eq!(
+    transaction.outgoing,
+    inscriptions
+      .iter()
+      .map(|inscription| Outgoing::InscriptionId(*inscription))
+      .collect::<Vec<Outgoing>>(),
+  );
+
+  assert_eq!(output.fee, transaction.fee);
+
+  assert_eq!(core.mempool().len(), 1);
+  assert_eq!(core.mempool()[0].compute_txid(), transaction.txid);
+
+  core.mine_blocks(1);
+
+  let mut outputs = BTreeSet::new();
+
+  for (inscription, destination) in inscriptions.iter().zip([ALICE, ALICE, BOB]) {
+    let info = location(&ord, *inscription);
+
+    assert_eq!(info.satpoint.outpoint.txid, transaction.txid);
+    assert_eq!(info.satpoint.offset, 0);
+    assert_eq!(info.address.unwrap(), destination);
+
+    outputs.insert(info.satpoint.outpoint);
+  }
+
+  assert_eq!(outputs.len(), 3);
+}
+
+#[test]
+fn transfer_splits_inscriptions_into_batches() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  let inscriptions = inscriptions(&core, &ord, 3);
+
+  let output = CommandBuilder::new(format!(
+    "wallet transfer --fee-rate 1 --batch-size 2 --destination {ALICE} {} {} {}",
+    inscriptions[0], inscriptions[1], inscriptions[2],
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<transfer::Output>();
+
+  assert_eq!(output.transactions.len(), 2);
+  assert_eq!(output.transactions[0].outgoing.len(), 2);
+  assert_eq!(output.transactions[1].outgoing.len(), 1);
+
+  assert_eq!(
+    output.fee,
+    output.transactions[0].fee + output.transactions[1].fee,
+  );
+
+  assert_eq!(core.mempool().len(), 2);
+
+  core.mine_blocks(1);
+
+  for (i, inscription) in inscriptions.iter().enumerate() {
+    assert_eq!(
+      location(&ord, *inscription).satpoint.outpoint.txid,
+      output.transactions[i / 2].txid,
+    );
+  }
+}
+
+#[test]
+fn transfer_dry_run_does_not_broadcast() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  let inscriptions = inscriptions(&core, &ord, 2);
+
+  let output = CommandBuilder::new(format!(
+    "wallet transfer --fee-rate 1 --dry-run --destination {ALICE} {} {}",
+    inscriptions[0], inscriptions[1],
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<transfer::Output>();
+
+  assert_eq!(output.transactions.len(), 1);
+  assert!(output.fee > 0);
+  assert!(core.mempool().is_empty());
+
+  assert_eq!(
+    core.get_locked(),
+    inscriptions
+      .iter()
+      .map(|inscription| location(&ord, *inscription).satpoint.outpoint)
+      .collect::<BTreeSet<OutPoint>>(),
+  );
+}
+
+#[test]
+fn transfer_requires_destination() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  let inscriptions = inscriptions(&core, &ord, 1);
+
+  CommandBuilder::new(format!("wallet transfer --fee-rate 1 {}", inscriptions[0]))
+    .core(&core)
+    .ord(&ord)
+    .expected_stderr(format!(
+      "error: sending {} requires `--destination`\n",
+      inscriptions[0]
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn transfer_refuses_to_send_amounts() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new(format!(
+    "wallet transfer --fee-rate 1 --destination {ALICE} 1btc"
+  ))
+  .core(&core)
+  .ord(&ord)
+  .expected_stderr("error: `ord wallet transfer` can only send inscriptions, satpoints, and sats\n")
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}